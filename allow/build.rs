@@ -16,7 +16,23 @@ fn emit_can_check_doc_attributes() {
 #[rustversion::not(since(1.54))]
 fn emit_can_check_doc_attributes() {}
 
+// Declare our custom `cfg` names, so that rustc 1.80+ doesn't report them as unexpected
+// (`unexpected_cfgs` lint). `has_rustdoc_lints` is not emitted yet.
+#[rustversion::since(1.80)]
+fn emit_check_cfg() {
+    for name in &[
+        "unstable_feature",
+        "can_check_doc_attributes",
+        "has_rustdoc_lints",
+    ] {
+        println!("cargo:rustc-check-cfg=cfg({})", name);
+    }
+}
+#[rustversion::not(since(1.80))]
+fn emit_check_cfg() {}
+
 fn main() {
+    emit_check_cfg();
     emit_unstable_feature();
     emit_can_check_doc_attributes();
 }
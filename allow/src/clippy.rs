@@ -633,3 +633,19 @@ export!(
 export_since!(1.50, zero_sized_map_values);
 // zero_width_space renamed in 1.49 to invisible_characters
 export!(zst_offset);
+
+// Lint groups. See `groups` feature.
+#[cfg(feature = "groups")]
+export!(
+    all,
+    cargo,
+    complexity,
+    correctness,
+    nursery,
+    pedantic,
+    perf,
+    restriction,
+    style
+);
+#[cfg(feature = "groups")]
+export_since!(1.57, suspicious);
@@ -0,0 +1,15 @@
+//! Ready-made aliases for the recurring reasons to suppress lints. Rather than each team building
+//! its own prelude-like module of aliases, import these (or copy and adjust them).
+//!
+//! Each module groups aliases by the intent; each alias is named by what it tolerates, like
+//! `#[allow::intent::tests::unwrap]` or `#[allow::intent::ffi::c_type_names]`. They are re-exports of
+//! [`allow_prefixed`](https://docs.rs/allow_prefixed) aliases, so they accept the same parameters,
+//! like `reason = "..."`.
+//!
+//! Aliases of `clippy::` lints exist only with `clippy` cargo feature (or with the feature of the
+//! lint's category, like `clippy_restriction`); the others only with `rustc` feature.
+
+pub mod ffi;
+pub mod generated;
+pub mod legacy;
+pub mod tests;
@@ -0,0 +1,31 @@
+//! Foreign function interface (FFI) boundaries: C names and C types, and unsafe functions whose
+//! safety contract is the foreign library's.
+
+/// A Rust type in an `extern "C" fn` definition that C treats as opaque (like a callback's context).
+/// Alias to `#[allow(improper_ctypes_definitions)]`.
+#[cfg(feature = "rustc")]
+#[rustversion::since(1.46)]
+pub use allow_prefixed::improper_ctypes_definitions as ctypes_definitions;
+
+/// A type in an `extern` block declaration that the foreign side treats as opaque. Alias to
+/// `#[allow(improper_ctypes)]`.
+#[cfg(feature = "rustc")]
+pub use allow_prefixed::improper_ctypes as ctypes;
+
+/// Types named as in C (like `size_t` or `FILE`). Alias to `#[allow(non_camel_case_types)]`.
+#[cfg(feature = "rustc")]
+pub use allow_prefixed::non_camel_case_types as c_type_names;
+
+/// Functions, parameters or fields named as in C (like `SDL_Init`). Alias to
+/// `#[allow(non_snake_case)]`.
+#[cfg(feature = "rustc")]
+pub use allow_prefixed::non_snake_case as c_fn_names;
+
+/// Statics named as in C (like `errno`). Alias to `#[allow(non_upper_case_globals)]`.
+#[cfg(feature = "rustc")]
+pub use allow_prefixed::non_upper_case_globals as c_static_names;
+
+/// Unsafe wrappers whose safety contract is documented by the foreign library, rather than by a
+/// `# Safety` section. Alias to `#[allow(clippy::missing_safety_doc)]`.
+#[cfg(feature = "clippy_style")]
+pub use allow_prefixed::clippy_missing_safety_doc as safety_in_foreign_docs;
@@ -0,0 +1,39 @@
+//! Generated code (by build scripts, `macro_rules!` or code generators), which follows its source
+//! (a schema, a protocol, a table) rather than Rust conventions, and which nobody edits by hand.
+
+/// Generated items that the crate doesn't use (all of them). Alias to `#[allow(dead_code)]`.
+#[cfg(feature = "rustc")]
+pub use allow_prefixed::dead_code as unused_items;
+
+/// Generated items without documentation. Alias to `#[allow(missing_docs)]`.
+#[cfg(feature = "rustc")]
+pub use allow_prefixed::missing_docs as undocumented;
+
+/// Types named after their source (like `http_request`). Alias to
+/// `#[allow(non_camel_case_types)]`.
+#[cfg(feature = "rustc")]
+pub use allow_prefixed::non_camel_case_types as type_names;
+
+/// Functions or fields named after their source (like `getHTTPRequest`). Alias to
+/// `#[allow(non_snake_case)]`.
+#[cfg(feature = "rustc")]
+pub use allow_prefixed::non_snake_case as fn_names;
+
+/// Statics named after their source. Alias to `#[allow(non_upper_case_globals)]`.
+#[cfg(feature = "rustc")]
+pub use allow_prefixed::non_upper_case_globals as static_names;
+
+/// Casts generated uniformly for all types, some of them trivial. Alias to
+/// `#[allow(trivial_casts)]`.
+#[cfg(feature = "rustc")]
+pub use allow_prefixed::trivial_casts as casts;
+
+/// Numeric casts generated uniformly for all types, some of them trivial. Alias to
+/// `#[allow(trivial_numeric_casts)]`.
+#[cfg(feature = "rustc")]
+pub use allow_prefixed::trivial_numeric_casts as numeric_casts;
+
+/// Fully qualified paths (generated to be robust against the consumer's imports). Alias to
+/// `#[allow(unused_qualifications)]`.
+#[cfg(feature = "rustc")]
+pub use allow_prefixed::unused_qualifications as qualified_paths;
@@ -0,0 +1,25 @@
+//! Compatibility with legacy APIs: names and items that stay as they are, because changing them
+//! would break existing users (or because they mirror an older API).
+
+/// Using (or implementing) deprecated items, until the migration. Alias to
+/// `#[allow(deprecated)]`.
+#[cfg(feature = "rustc")]
+pub use allow_prefixed::deprecated as deprecated_api;
+
+/// Types keeping their published names. Alias to `#[allow(non_camel_case_types)]`.
+#[cfg(feature = "rustc")]
+pub use allow_prefixed::non_camel_case_types as type_names;
+
+/// Functions, parameters or fields keeping their published names. Alias to
+/// `#[allow(non_snake_case)]`.
+#[cfg(feature = "rustc")]
+pub use allow_prefixed::non_snake_case as fn_names;
+
+/// Statics keeping their published names. Alias to `#[allow(non_upper_case_globals)]`.
+#[cfg(feature = "rustc")]
+pub use allow_prefixed::non_upper_case_globals as static_names;
+
+/// Identifiers that are keywords in newer editions (like `async` or `dyn`). Alias to
+/// `#[allow(keyword_idents)]`.
+#[cfg(feature = "rustc")]
+pub use allow_prefixed::keyword_idents as keyword_names;
@@ -0,0 +1,18 @@
+//! Test-only shortcuts: a test may panic, because a panic is how it fails. Apply these to test
+//! modules or functions (and with `cfg(test)` parameter on code shared with non-test builds).
+
+/// Tests unwrap. Alias to `#[allow(clippy::unwrap_used)]`.
+#[cfg(feature = "clippy_restriction")]
+pub use allow_prefixed::clippy_unwrap_used as unwrap;
+
+/// Tests call `expect(...)`. Alias to `#[allow(clippy::expect_used)]`.
+#[cfg(feature = "clippy_restriction")]
+pub use allow_prefixed::clippy_expect_used as expect;
+
+/// Tests index (and slice) directly. Alias to `#[allow(clippy::indexing_slicing)]`.
+#[cfg(feature = "clippy_restriction")]
+pub use allow_prefixed::clippy_indexing_slicing as indexing;
+
+/// Tests call `panic!(...)`. Alias to `#[allow(clippy::panic)]`.
+#[cfg(feature = "clippy_restriction")]
+pub use allow_prefixed::clippy_panic as panic;
@@ -21,9 +21,47 @@
         test_unstable_lint // https://doc.rust-lang.org/nightly/unstable-book/language-features/test-unstable-lint.html
     )
 )]
+//#[cfg(feature = "clippy")]
 //pub mod clippy;
+//#[cfg(feature = "rustc")]
 //pub mod rustc;
+#[cfg(feature = "rustdoc")]
 pub mod rustdoc;
 
+pub mod tool_attributes;
+
+pub mod intent;
+
 // Users can choose to access prefixless lints through `rustc::`, or from the top level.
+//#[cfg(feature = "rustc")]
 //pub use rustc::*;
+
+/// Suppress lints on individual fields or variants. See
+/// [`allow_prefixed::members`](macro@allow_prefixed::members).
+pub use allow_prefixed::members;
+
+/// Suppress lints on individual methods of an `impl` or a `trait` block. See
+/// [`allow_prefixed::methods`](macro@allow_prefixed::methods).
+pub use allow_prefixed::methods;
+
+/// Suppress a lint on one expression. See [`allow_prefixed::expr`](macro@allow_prefixed::expr).
+pub use allow_prefixed::expr;
+
+/// Suppress a lint on statement(s). See [`allow_prefixed::stmt`](macro@allow_prefixed::stmt).
+pub use allow_prefixed::stmt;
+
+/// Apply aliases to each of a group of items. See
+/// [`allow_prefixed::items`](macro@allow_prefixed::items).
+pub use allow_prefixed::items;
+
+/// Suppress a lint of any tool (like a Dylint library), or a lint that has no alias. See
+/// [`allow_prefixed::tool_lint`](macro@allow_prefixed::tool_lint).
+pub use allow_prefixed::tool_lint;
+
+/// Suppress the lints of an intent configured by your crate. See
+/// [`allow_prefixed::intent`](macro@allow_prefixed::intent).
+pub use allow_prefixed::intent;
+
+/// Reject raw `#[allow(...)]` (and `#[expect(...)]`) in favor of aliases. See
+/// [`allow_prefixed::strict`](macro@allow_prefixed::strict).
+pub use allow_prefixed::strict;
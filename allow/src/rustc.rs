@@ -14,7 +14,7 @@
 pub use allow_prefixed::{
     // absolute_paths_not_starting_with_crate was in edition 2015 only (and we require 2018+).
     box_pointers,
-    // elided_lifetimes_in_paths - at crate level only
+    elided_lifetimes_in_paths,
     explicit_outlives_requirements,
 };
 #[rustversion::nightly]
@@ -47,7 +47,7 @@ pub use allow_prefixed::{
 pub use allow_prefixed::{
     multiple_supertrait_upcastable,
     must_not_suspend,
-    // non_ascii_idents - at crate level only
+    // non_ascii_idents - at crate level only (see the end)
     non_exhaustive_omitted_patterns
 };
 #[rustfmt::skip]
@@ -55,24 +55,26 @@ pub use allow_prefixed::{
     noop_method_call,
     pointer_structural_match,
     rust_2021_incompatible_closure_captures,
-    // rust_2021_prefixes_incompatible_syntax - at crate level only
+    // rust_2021_prefixes_incompatible_syntax - at crate level only (see the end)
     rust_2021_prelude_collisions,
     single_use_lifetimes,
     trivial_casts,
     trivial_numeric_casts,
     unreachable_pub,
     unsafe_code,
+    audited_unsafe_code,
 };
 #[rustversion::since(1.52)]
 #[rustfmt::skip]
 pub use allow_prefixed::{
-    unsafe_op_in_unsafe_fn
+    unsafe_op_in_unsafe_fn,
+    audited_unsafe_op_in_unsafe_fn
 };
 #[rustfmt::skip]
 pub use allow_prefixed::{
     // unstable_features - deprecated
     //
-    // unused_crate_dependencies - at crate level only
+    // unused_crate_dependencies - at crate level only (see the end)
     unused_extern_crates,
     unused_import_braces,
     unused_lifetimes,
@@ -101,7 +103,7 @@ pub use allow_prefixed::{
     byte_slice_in_packed_struct_with_derive,
     clashing_extern_declarations,
     coherence_leak_check,
-    // confusable_idents - at crate level only
+    // confusable_idents - at crate level only (see the end)
     const_evaluatable_unchecked,
     const_item_mutation,
     dead_code,
@@ -138,7 +140,7 @@ pub use allow_prefixed::{
     late_bound_lifetime_arguments,
     legacy_derive_helpers,
     map_unit_fn,
-    // mixed_script_confusables - at crate level only
+    // mixed_script_confusables - at crate level only (see the end)
     named_arguments_used_positionally,
     no_mangle_generic_items,
     non_camel_case_types,
@@ -170,7 +172,7 @@ pub use allow_prefixed::{
     trivial_bounds,
     type_alias_bounds,
     tyvar_behind_raw_pointer,
-    // uncommon_codepoints - at crate level only
+    // uncommon_codepoints - at crate level only (see the end)
     unconditional_recursion,
 };
 #[rustversion::nightly]
@@ -221,7 +223,7 @@ pub use allow_prefixed::{
     conflicting_repr_hints,
     deprecated_cfg_attr_crate_type_name,
     enum_intrinsics_non_enums,
-    // ill_formed_attribute_input - at crate level only
+    // ill_formed_attribute_input - at crate level only (see the end)
     implied_bounds_entailment,
     incomplete_include,
     ineffective_unstable_trait_impl,
@@ -236,7 +238,7 @@ pub use allow_prefixed::{
     invalid_atomic_ordering,
     invalid_type_param_default,
     let_underscore_lock,
-    // macro_expanded_macro_exports_accessed_by_absolute_paths - at crate level only
+    // macro_expanded_macro_exports_accessed_by_absolute_paths - at crate level only (see the end)
     missing_fragment_specifier,
     mutable_transmutes,
     named_asm_labels,
@@ -256,9 +258,39 @@ pub use allow_prefixed::{
 };
 #[rustfmt::skip]
 pub use allow_prefixed::{
-    text_direction_codepoint_in_comment,
-    text_direction_codepoint_in_literal,
+    // text_direction_codepoint_in_comment - at crate level only (see the end)
+    // text_direction_codepoint_in_literal - at crate level only (see the end)
     unconditional_panic,
-    // unknown_crate_types - at crate level only
+    // unknown_crate_types - at crate level only (see the end)
     useless_deprecated,
 };
+
+// Crate level-only lints. Their macros only report an error that explains how to allow the lint (at
+// the crate root) - except as an inner attribute at the crate root with `nightly` feature.
+#[rustfmt::skip]
+pub use allow_prefixed::{
+    confusable_idents,
+    ill_formed_attribute_input,
+    macro_expanded_macro_exports_accessed_by_absolute_paths,
+    mixed_script_confusables,
+    non_ascii_idents,
+    rust_2021_prefixes_incompatible_syntax,
+    text_direction_codepoint_in_comment,
+    text_direction_codepoint_in_literal,
+    uncommon_codepoints,
+    unknown_crate_types,
+    unused_crate_dependencies,
+};
+
+// Lint groups. See `groups` feature.
+#[cfg(feature = "groups")]
+#[rustfmt::skip]
+pub use allow_prefixed::{
+    nonstandard_style,
+    rust_2018_compatibility,
+    rust_2018_idioms,
+    unused,
+};
+#[cfg(feature = "groups")]
+#[rustversion::since(1.66)]
+pub use allow_prefixed::let_underscore;
@@ -0,0 +1,20 @@
+//! Tool attributes other than lints: Clippy's configuration attributes (like
+//! `#[clippy::msrv = "..."]`), re-exported from [`allow_prefixed`](../allow_prefixed) crate. Import
+//! them under names of your intentions, like
+//! `use allow::tool_attributes::clippy_msrv as supports_old_rust;`.
+//!
+//! Clippy's attributes that Clippy of the current Rust version doesn't have pass the code through
+//! as-is.
+//!
+//! [`rustfmt_skip`] is no alias: rustfmt doesn't expand attribute macros. It reports an error that
+//! says to use `#[rustfmt::skip]` itself.
+
+pub use allow_prefixed::rustfmt_skip;
+
+#[cfg(feature = "clippy")]
+#[rustfmt::skip]
+pub use allow_prefixed::{
+    clippy_cognitive_complexity,
+    clippy_has_significant_drop,
+    clippy_msrv,
+};
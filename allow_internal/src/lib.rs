@@ -11,12 +11,17 @@ mod proc_builder;
 /// Generate the code that invokes `generate_allow_attribute_macro_internal` macro, and
 /// as a result it defines an attribute macro for the given lint.
 ///
-/// @TODO Move this doc to the macro: Param `pass_through` indicates whether the result attribute macro should just pass through its
-/// input without injecting `#[allow(lint-name-here)]`. Used for removed/deprecated lints - for
-/// backwards compatibility.
+/// The generated attribute macro passes its input through as-is (without injecting
+/// `#[allow(lint-name-here)]`) if the lint doesn't exist yet, or anymore (`not_yet` or `not_anymore`
+/// in `properties`), for the Rust version that builds `allow_prefixed` (which is the consumer's).
+/// Otherwise rustc would report the injected lint as unknown (or removed).
+///
+/// Param `lint_url` is the URL of the lint's documentation. The generated macro links to it from
+/// the consumer's documentation (if given `document = true`).
 fn pass_through_deprecated_attrib_macro(
     lint_prefix: Option<&str>,
     properties: AllowMacroProperties,
+    lint_url: &str,
     doc: &str,
 ) -> TokenStream {
     // @TODO  cfg: no/nightly, silent/scream _past_dummies, fixed_toolchains_conservative on
@@ -69,7 +74,7 @@ fn pass_through_deprecated_attrib_macro(
     ));
     let exclamation = proc_builder::get_punct_joint('!');
 
-    let mut generate_internal_params = Vec::with_capacity(9);
+    let mut generate_internal_params = Vec::with_capacity(13);
 
     if let Some(lint_prefix) = &lint_prefix {
         // `lint_prefix` will be checked later. [TokenTree::clone] is documented to be cheap.
@@ -95,10 +100,28 @@ fn pass_through_deprecated_attrib_macro(
     generate_internal_params.push(proc_builder::get_punct_alone(','));
 
     generate_internal_params.push(TokenTree::Ident(Ident::new(
-        if false/*TODO pass_through*/ { "true" } else { "false" },
+        // Pass through (inject nothing) if the lint doesn't exist (yet or anymore) for the Rust
+        // version that is building `allow_prefixed` (which is the same as the consumer's).
+        if properties.not_yet || properties.not_anymore {
+            "true"
+        } else {
+            "false"
+        },
         Span::call_site(),
     )));
     generate_internal_params.push(proc_builder::get_punct_alone(','));
+    if lint_prefix.is_none() {
+        // Whether the (rustc) lint is deny-by-default. (For `clippy::` lints, `allow_prefixed`
+        // determines it from the members of `clippy::correctness` group.)
+        let deny_by_default = matches!(properties.default, Some(LintDefault::Deny));
+        generate_internal_params.push(TokenTree::Ident(Ident::new(
+            if deny_by_default { "true" } else { "false" },
+            Span::call_site(),
+        )));
+        generate_internal_params.push(proc_builder::get_punct_alone(','));
+    }
+    generate_internal_params.push(TokenTree::Literal(Literal::string(lint_url)));
+    generate_internal_params.push(proc_builder::get_punct_alone(','));
     generate_internal_params.push(TokenTree::Literal(Literal::string(doc)));
 
     let generate_internal_params_parens = TokenTree::Group(Group::new(
@@ -120,7 +143,9 @@ fn pass_through_deprecated_attrib_macro(
 /// Like [`generate_allow_attribute_macro_prefixed!`], but generate a macro for a given
 /// standard (prefixless) `rustc` lint. The macro name itself will be the same as the lint name.
 #[proc_macro]
-pub fn generate_allow_attribute_macro_standard(lint_name_and_the_rest: TokenStream) -> TokenStream {
+pub fn generate_allow_attribute_macro_standard(
+    _lint_name_and_the_rest: TokenStream,
+) -> TokenStream {
     TokenStream::new()
     //TODO
     //
@@ -194,6 +219,9 @@ impl Display for LintDefault {
     }
 }
 
+/// Appended to the documentation of aliases for deny-by-default lints.
+const DENY_BY_DEFAULT_NOTICE: &str = "\n\n**Deny-by-default lint.** Suppressing it is almost always a red flag: the code is most likely wrong. If you opt in with `require_acknowledge_deny = true` under `[package.metadata.allow]` in your `Cargo.toml`, then this alias warns unless you give it `acknowledge_deny = true` and `reason = \"...\"`.";
+
 /// Properties of our target generated attribute macros (to be used, and potentially aliased, by
 /// users), except for the prefix. The field names reflect the "full" parameters (right of
 /// `ALL_PARAMS`) accepted by macro by example [`allow_prefixed::any`].
@@ -201,9 +229,12 @@ struct AllowMacroProperties {
     lint_name_token_tree: TokenTree,
     lint_name: String,
     default: Option<LintDefault>, // for rustc (standard) only
+    #[allow(dead_code)] // Parsed (and so validated), but not used yet.
     deprecated_msg: String,
     since_major_minor: String,
+    #[allow(dead_code)] // Parsed (and so validated), but not used yet.
     until_major_minor: String,
+    #[allow(dead_code)] // Parsed (and so validated), but not used yet.
     nightly: bool,
     not_yet: bool,
     not_anymore: bool,
@@ -215,42 +246,80 @@ impl AllowMacroProperties {
     }
 }
 
+/// Return the proc macro's output, whether we've succeeded, or whether we've failed with a
+/// `compile_error!`. (Our parsing functions return such an error, already a
+/// [`compile_error!`](proc_builder::compile_error) located at the offending token, so the caller
+/// only passes it on.)
+fn output_or_error(result: Result<TokenStream, TokenStream>) -> TokenStream {
+    match result {
+        Ok(output) => output,
+        Err(error) => error,
+    }
+}
+
+/// Describe a token for an error message. Unlike the [`Debug`] of [`TokenTree`], this doesn't
+/// include spans.
+fn describe(token_tree: &TokenTree) -> String {
+    match token_tree {
+        TokenTree::Group(_) => format!("a group `{}`", token_tree),
+        TokenTree::Ident(_) => format!("an identifier `{}`", token_tree),
+        TokenTree::Punct(_) => format!("a punctuation `{}`", token_tree),
+        TokenTree::Literal(_) => format!("a literal {}", token_tree),
+    }
+}
+
 fn parse_value(
     iter: &mut impl Iterator<Item = TokenTree>,
     expect_comma_afterwards: bool,
     description: &str,
-) -> TokenTree {
-    let value = iter.next();
-    let value = value.unwrap_or_else(|| {
-        panic!("Expecting {}, but reached an end of input.", description);
-    });
+) -> Result<TokenTree, TokenStream> {
+    let value = iter.next().ok_or_else(|| {
+        proc_builder::compile_error(
+            &format!("Expecting {}, but reached an end of input.", description),
+            Span::call_site(),
+        )
+    })?;
     if expect_comma_afterwards {
-        let comma = iter.next().unwrap_or_else(|| {
-            panic!(
-                "Expecting a comma after {}, but reached an end of input.",
-                description
+        let comma = iter.next().ok_or_else(|| {
+            proc_builder::compile_error(
+                &format!(
+                    "Expecting a comma after {}, but reached an end of input.",
+                    description
+                ),
+                value.span(),
             )
-        });
+        })?;
         if !matches!(&comma, TokenTree::Punct(p) if p.as_char()==',') {
-            panic!("Expecting a comma, but received {:?}.", comma);
+            return Err(proc_builder::compile_error(
+                &format!(
+                    "Expecting a comma after {}, but received {}.",
+                    description,
+                    describe(&comma)
+                ),
+                comma.span(),
+            ));
         }
     }
-    value
+    Ok(value)
 }
 
 fn parse_literal(
     iter: &mut impl Iterator<Item = TokenTree>,
     expect_comma_afterwards: bool,
     description: &str,
-) -> String {
-    let value = parse_value(iter, expect_comma_afterwards, description);
+) -> Result<String, TokenStream> {
+    let value = parse_value(iter, expect_comma_afterwards, description)?;
     if let TokenTree::Literal(literal) = &value {
-        literal.to_string()
+        Ok(literal.to_string())
     } else {
-        panic!(
-            "Expecting {} as a literal, but received {:#?} instead.",
-            description, value
-        )
+        Err(proc_builder::compile_error(
+            &format!(
+                "Expecting {} as a literal, but received {} instead.",
+                description,
+                describe(&value)
+            ),
+            value.span(),
+        ))
     }
 }
 
@@ -261,47 +330,60 @@ fn parse_ident(
     iter: &mut impl Iterator<Item = TokenTree>,
     expect_comma_afterwards: bool,
     description: &str,
-) -> (String, TokenTree) {
-    let token_tree = parse_value(iter, expect_comma_afterwards, description);
+) -> Result<(String, TokenTree), TokenStream> {
+    let token_tree = parse_value(iter, expect_comma_afterwards, description)?;
     if let TokenTree::Ident(ident) = &token_tree {
-        (ident.to_string(), token_tree)
+        Ok((ident.to_string(), token_tree))
     } else {
-        panic!(
-            "Expecting {} as an Ident, but received {:#?} instead.",
-            description, token_tree
-        )
+        Err(proc_builder::compile_error(
+            &format!(
+                "Expecting {} as an Ident, but received {} instead.",
+                description,
+                describe(&token_tree)
+            ),
+            token_tree.span(),
+        ))
     }
 }
 
-fn assert_no_more_tokens(token_tree_iter: &mut impl Iterator<Item = TokenTree>) {
-    let mut token_trees = token_tree_iter.peekable();
-    assert!(
-        token_trees.peek().is_none(),
-        "Expecting no more tokens, but received: {:?}.",
-        token_trees.collect::<Vec<_>>()
-    );
+fn assert_no_more_tokens(
+    token_tree_iter: &mut impl Iterator<Item = TokenTree>,
+) -> Result<(), TokenStream> {
+    if let Some(extra) = token_tree_iter.next() {
+        Err(proc_builder::compile_error(
+            &format!(
+                "Expecting no more tokens, but received {}.",
+                describe(&extra)
+            ),
+            extra.span(),
+        ))
+    } else {
+        Ok(())
+    }
 }
 
-/// Return `token_tree` if it's a non-group token. Otherwise, assert that it's a group with no
+/// Return `token_tree` if it's a non-group token. Otherwise, check that it's a group with no
 /// delimiter, containing exactly one token (sub)tree, and return that (sub)tree.
-fn token_unwrap_undelimited_group_if_any(token_tree: TokenTree) -> TokenTree {
+fn token_unwrap_undelimited_group_if_any(token_tree: TokenTree) -> Result<TokenTree, TokenStream> {
     if let TokenTree::Group(group) = token_tree {
+        if group.delimiter() != Delimiter::None {
+            return Err(proc_builder::compile_error(
+                &format!(
+                    "Received a group. Expecting the delimiter to be \"None\", but it was: {:?}.",
+                    group.delimiter()
+                ),
+                group.span(),
+            ));
+        }
         let mut iter = group.stream().into_iter();
-        assert_eq!(
-            group.delimiter(),
-            Delimiter::None,
-            "Received a group. Expecting the delimiter to be \"None\", but it was: {:#?}.",
-            group.delimiter()
-        );
-
         if let Some(token_tree) = iter.next() {
-            assert_no_more_tokens(&mut iter);
-            token_tree
+            assert_no_more_tokens(&mut iter)?;
+            Ok(token_tree)
         } else {
-            panic!("Received a group with a correct delimiter (\"None\"). Expecting exactly one item, but the group was empty.")
+            Err(proc_builder::compile_error("Received a group with a correct delimiter (\"None\"). Expecting exactly one item, but the group was empty.", group.span()))
         }
     } else {
-        token_tree
+        Ok(token_tree)
     }
 }
 
@@ -309,27 +391,34 @@ fn parse_literal_bool(
     iter: &mut impl Iterator<Item = TokenTree>,
     expect_comma_afterwards: bool,
     description: &str,
-) -> bool {
-    let value = parse_value(iter, expect_comma_afterwards, description);
-    let value = token_unwrap_undelimited_group_if_any(value);
+) -> Result<bool, TokenStream> {
+    let value = parse_value(iter, expect_comma_afterwards, description)?;
+    let value = token_unwrap_undelimited_group_if_any(value)?;
 
-    if let TokenTree::Ident(ident) = value {
+    if let TokenTree::Ident(ident) = &value {
         let ident = ident.to_string();
         if ident == "true" {
-            true
+            Ok(true)
         } else if ident == "false" {
-            false
+            Ok(false)
         } else {
-            panic!(
-                "Expecting {} as a bool literal, but received {}.",
-                description, ident
-            )
+            Err(proc_builder::compile_error(
+                &format!(
+                    "Expecting {} as a bool literal, but received {}.",
+                    description, ident
+                ),
+                value.span(),
+            ))
         }
     } else {
-        panic!(
-            "Expecting {} as a bool literal (Ident), but received {:#?} instead.",
-            description, value
-        )
+        Err(proc_builder::compile_error(
+            &format!(
+                "Expecting {} as a bool literal (Ident), but received {} instead.",
+                description,
+                describe(&value)
+            ),
+            value.span(),
+        ))
     }
 }
 
@@ -339,37 +428,48 @@ fn parse_literal_bool(
 fn parse_properties(
     token_trees: &mut impl Iterator<Item = TokenTree>,
     is_rustc: bool,
-) -> AllowMacroProperties {
-    let (lint_name, lint_name_token_tree) = parse_ident(token_trees, true, "lint name");
+) -> Result<AllowMacroProperties, TokenStream> {
+    let (lint_name, lint_name_token_tree) = parse_ident(token_trees, true, "lint name")?;
 
-    let (default, _) = parse_ident(token_trees, true, "default");
+    let (default, default_token_tree) = parse_ident(token_trees, true, "default")?;
     let default = if is_rustc {
         match default.parse::<LintDefault>() {
             Ok(default) => Some(default),
-            Err(found) => panic!("Expecting a (rustc) lint default, but found: {}.", found),
+            Err(found) => {
+                return Err(proc_builder::compile_error(
+                    &format!(
+                        "Expecting a (rustc) lint default (allowed, warn or deny), but found: {}.",
+                        found
+                    ),
+                    default_token_tree.span(),
+                ))
+            }
         }
     } else {
-        assert!(
-            default == "_",
-            "Expecting a (clippy|rustdoc) lint default to be an underscore _, but found: {}.",
-            default
-        );
+        if default != "_" {
+            return Err(proc_builder::compile_error(
+                &format!(
+                    "Expecting a (clippy|rustdoc) lint default to be an underscore _, but found: {}.",
+                    default
+                ),
+                default_token_tree.span(),
+            ));
+        }
         None
     };
     let deprecated_msg = parse_literal(
         token_trees,
         true,
         "deprecated (message, if other than default)",
-    );
-    let since_major_minor = parse_literal(token_trees, true, "since_major_minor");
-    let nightly = parse_literal_bool(token_trees, true, "nightly");
-    let until_major_minor = parse_literal(token_trees, true, "until_major_minor");
-    let not_yet = parse_literal_bool(token_trees, true, "not_yet");
-    let not_anymore = parse_literal_bool(token_trees, false, "not_anymore");
-
-    let mut token_trees = token_trees.peekable();
-    assert_no_more_tokens(&mut token_trees);
-    AllowMacroProperties {
+    )?;
+    let since_major_minor = parse_literal(token_trees, true, "since_major_minor")?;
+    let nightly = parse_literal_bool(token_trees, true, "nightly")?;
+    let until_major_minor = parse_literal(token_trees, true, "until_major_minor")?;
+    let not_yet = parse_literal_bool(token_trees, true, "not_yet")?;
+    let not_anymore = parse_literal_bool(token_trees, false, "not_anymore")?;
+
+    assert_no_more_tokens(token_trees)?;
+    Ok(AllowMacroProperties {
         lint_name_token_tree,
         lint_name,
         default,
@@ -379,7 +479,7 @@ fn parse_properties(
         nightly,
         not_yet,
         not_anymore,
-    }
+    })
 }
 
 /// Generate the documentation text and the whole target attribute macro to allow relevant
@@ -388,7 +488,10 @@ fn parse_properties(
 /// to macro_rules [`::allow_prefixed::any_with_nightly_as_bool`] after it accepts `ALL_PARAMS, clippy`.
 #[proc_macro]
 pub fn doc_and_attrib_macro_clippy(input: TokenStream) -> TokenStream {
-    let properties = parse_properties(&mut input.clone().into_iter(), false);
+    let properties = match parse_properties(&mut input.into_iter(), false) {
+        Ok(properties) => properties,
+        Err(error) => return error,
+    };
     // emit [doc = "..."]
     // - rustc:
     //   https://doc.rust-lang.org/nightly/rustc/lints/listing/(allowed|warn|deny)-by-default.html
@@ -421,17 +524,21 @@ pub fn doc_and_attrib_macro_clippy(input: TokenStream) -> TokenStream {
             properties.since_major_minor
         )
     };
+    let lint_url = format!("{}{}", clippy_base, properties.lint_name);
     let doc = format!(
-        "Alias to `#[allow(clippy::{})]`. See {}/{}.",
-        properties.lint_name, clippy_base, properties.lint_name
+        "Alias to `#[allow(clippy::{})]`. See {}.",
+        properties.lint_name, lint_url
     );
-    pass_through_deprecated_attrib_macro(Some("clippy"), properties, &doc)
+    pass_through_deprecated_attrib_macro(Some("clippy"), properties, &lint_url, &doc)
 }
 
 /// Like [`doc_and_attrib_macro_clippy`], but for `rustc` ("standard", prefixless) lints.
 #[proc_macro]
 pub fn doc_and_attrib_macro_rustc(input: TokenStream) -> TokenStream {
-    let properties = parse_properties(&mut input.clone().into_iter(), true);
+    let properties = match parse_properties(&mut input.into_iter(), true) {
+        Ok(properties) => properties,
+        Err(error) => return error,
+    };
     let rustc_base = "https://doc.rust-lang.org/nightly/rustc/lints/listing";
 
     let mut lint_name_with_hyphens = String::with_capacity(properties.lint_name.len());
@@ -442,14 +549,46 @@ pub fn doc_and_attrib_macro_rustc(input: TokenStream) -> TokenStream {
             .map(|c| if c == '_' { '-' } else { c }),
     );
 
-    assert!(properties.default.is_some(), "Allow macro definition for rustc (\"standard\", prefixless) lint {} require default applicability. And this should have been checked already.", properties.lint_name);
-    let default = properties.default.as_ref().unwrap();
+    let default = match &properties.default {
+        Some(default) => default,
+        None => return proc_builder::compile_error(
+            &format!("Allow macro definition for rustc (\"standard\", prefixless) lint {} require default applicability. And this should have been checked already.", properties.lint_name),
+            properties.lint_name_token_tree.span(),
+        ),
+    };
+
+    let lint_url = format!(
+        "{}/{}-by-default.html#{}",
+        rustc_base, default, lint_name_with_hyphens
+    );
+    let mut doc = format!(
+        "Alias to `#[allow({})]`. See {}.",
+        properties.lint_name, lint_url
+    );
+    if let LintDefault::Deny = default {
+        doc.push_str(DENY_BY_DEFAULT_NOTICE);
+    }
+    pass_through_deprecated_attrib_macro(None, properties, &lint_url, &doc)
+}
 
+/// Like [`doc_and_attrib_macro_clippy`], but for `rustdoc::` lints.
+#[proc_macro]
+pub fn doc_and_attrib_macro_rustdoc(input: TokenStream) -> TokenStream {
+    let properties = match parse_properties(&mut input.into_iter(), false) {
+        Ok(properties) => properties,
+        Err(error) => return error,
+    };
+    // Unlike rustc and clippy lints, rustdoc lints have one listing page only (per version), and
+    // its anchors use underscores.
+    let lint_url = format!(
+        "https://doc.rust-lang.org/nightly/rustdoc/lints.html#{}",
+        properties.lint_name
+    );
     let doc = format!(
-        "Alias to `#[allow({})]`. See {}/{}-by-default.html#{}.",
-        properties.lint_name, rustc_base, default, lint_name_with_hyphens
+        "Alias to `#[allow(rustdoc::{})]`. See {}.",
+        properties.lint_name, lint_url
     );
-    pass_through_deprecated_attrib_macro(Some("clippy"), properties, &doc)
+    pass_through_deprecated_attrib_macro(Some("rustdoc"), properties, &lint_url, &doc)
 }
 
 /// Generate code like: `#[allow(prefix::lint_name)] const _: () = ();`. Use it together with
@@ -468,74 +607,45 @@ pub fn doc_and_attrib_macro_rustc(input: TokenStream) -> TokenStream {
 pub fn check_that_prefixed_lint_exists(
     prefix_and_lint_name_without_double_colon: TokenStream,
 ) -> TokenStream {
+    output_or_error(check_that_prefixed_lint_exists_impl(
+        &mut prefix_and_lint_name_without_double_colon.into_iter(),
+    ))
+}
+
+fn check_that_prefixed_lint_exists_impl(
+    prefix_and_lint_name_without_double_colon: &mut impl Iterator<Item = TokenTree>,
+) -> Result<TokenStream, TokenStream> {
     // The `const _` is to check that the lint prefix & path is valid (thanks to
     // `#![deny(unknown_lints)]` in `lib.rs` or `allow_prefixed` crate.
     //
     // For a similar, but simplified version, see also `macro_rules! standard_lint` in
     // `allow_prefixed` crate.
-    let mut prefix_and_lint_name_without_double_colon =
-        prefix_and_lint_name_without_double_colon.into_iter();
-
-    let prefix = prefix_and_lint_name_without_double_colon
-        .next()
-        .unwrap_or_else(|| {
-            panic!("Expecting a lint prefix (Identifier), but reached the end of the input.")
-        });
-    let prefix = if let TokenTree::Ident(prefix) = prefix {
-        prefix.to_string()
-    } else {
-        panic!(
-            "Expecting a TokenTree::Ident(prefix), but received {:?}.",
-            prefix
-        );
-    };
-
-    let comma = prefix_and_lint_name_without_double_colon
-        .next()
-        .unwrap_or_else(|| {
-            panic!("Expecting a lint prefix (Identifier), but reached the end of the input.")
-        });
-    if !matches!(&comma, TokenTree::Punct(p) if p.as_char()==',') {
-        panic!(
-            "Expecting a comma (a TokenTree::Punct), but received {:?}.",
-            comma
-        );
-    }
-
-    let name = prefix_and_lint_name_without_double_colon
-        .next()
-        .unwrap_or_else(|| {
-            panic!("Expecting a lint name (Identifier), but reached the end of the input.")
-        });
+    let (prefix, _) = parse_ident(
+        prefix_and_lint_name_without_double_colon,
+        true,
+        "a lint prefix",
+    )?;
+    let (name, name_token_tree) = parse_ident(
+        prefix_and_lint_name_without_double_colon,
+        false,
+        "a lint name",
+    )?;
+    assert_no_more_tokens(prefix_and_lint_name_without_double_colon)?;
     // `span` must NOT be `Span::call_site()`. See https://github.com/rust-lang/rust/issues/109881.
-    let (name, span) = if let TokenTree::Ident(name) = name {
-        (name.to_string(), name.span())
-    } else {
-        panic!(
-            "Expecting a TokenTree::Ident(lint_name), but received {:?}.",
-            name
-        );
-    };
-
-    let mut token_streams = Vec::with_capacity(9);
-
-    token_streams.push(proc_builder::get_hash());
-    token_streams.push(proc_builder::brackets_allow_lint_parts(
-        &prefix, &name, span,
-    ));
-    token_streams.push(TokenStream::from(proc_builder::get_ident_tree("const")));
-    token_streams.push(TokenStream::from(proc_builder::get_ident_tree("_")));
-    token_streams.push(TokenStream::from(proc_builder::get_colon_alone()));
-
-    token_streams.push(TokenStream::from(proc_builder::get_parens(
-        TokenStream::new(),
-    )));
-    token_streams.push(TokenStream::from(proc_builder::get_punct_alone('=')));
-    token_streams.push(TokenStream::from(proc_builder::get_parens(
-        TokenStream::new(),
-    )));
-    token_streams.push(TokenStream::from(proc_builder::get_punct_alone(';')));
-    auxiliary::token_streams_to_stream(&token_streams)
+    let span = name_token_tree.span();
+
+    let token_streams = [
+        proc_builder::get_hash(),
+        proc_builder::brackets_allow_lint_parts(&prefix, &name, span)?,
+        TokenStream::from(proc_builder::get_ident_tree("const")),
+        TokenStream::from(proc_builder::get_ident_tree("_")),
+        TokenStream::from(proc_builder::get_colon_alone()),
+        TokenStream::from(proc_builder::get_parens(TokenStream::new())),
+        TokenStream::from(proc_builder::get_punct_alone('=')),
+        TokenStream::from(proc_builder::get_parens(TokenStream::new())),
+        TokenStream::from(proc_builder::get_punct_alone(';')),
+    ];
+    Ok(auxiliary::token_streams_to_stream(&token_streams))
 }
 
 //----------------
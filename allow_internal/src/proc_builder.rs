@@ -1,4 +1,4 @@
-use proc_macro::{Delimiter, Group, Ident, Punct, Spacing, Span, TokenStream, TokenTree};
+use proc_macro::{Delimiter, Group, Ident, Literal, Punct, Spacing, Span, TokenStream, TokenTree};
 
 use crate::auxiliary;
 
@@ -51,9 +51,16 @@ pub fn get_parens(enclosed_stream: TokenStream) -> TokenTree {
 ///
 /// Param `span` must NOT be `Span::call_site()`, but it MUST come from the consumer's code. See
 /// https://github.com/rust-lang/rust/issues/109881.
-pub fn brackets_allow_lint_parts(prefix_str: &str, lint_str: &str, span: Span) -> TokenStream {
+///
+/// Return an error (located at `span`) if `prefix_str` is neither empty, nor `clippy`, nor
+/// `rustdoc`.
+pub fn brackets_allow_lint_parts(
+    prefix_str: &str,
+    lint_str: &str,
+    span: Span,
+) -> Result<TokenStream, TokenStream> {
     let prefix_lint = {
-        let lint = TokenTree::Ident(Ident::new(lint_str, span.clone()));
+        let lint = TokenTree::Ident(Ident::new(lint_str, span));
         if prefix_str.is_empty() {
             auxiliary::token_trees_to_stream(&[lint])
             //TokenStream::from_iter([lint])
@@ -61,7 +68,15 @@ pub fn brackets_allow_lint_parts(prefix_str: &str, lint_str: &str, span: Span) -
             let mut prefix = match prefix_str {
                 "clippy" => get_clippy(),
                 "rustdoc" => get_rustdoc(),
-                _ => panic!("Unsupported prefix: {}.", prefix_str),
+                _ => {
+                    return Err(compile_error(
+                        &format!(
+                            "Unsupported lint prefix `{}` (of lint `{}`). Expecting `clippy` or `rustdoc`.",
+                            prefix_str, lint_str
+                        ),
+                        span,
+                    ))
+                }
             };
             prefix.set_span(span);
             auxiliary::token_trees_to_stream(&[prefix, get_colon_joint(), get_colon_alone(), lint])
@@ -73,8 +88,29 @@ pub fn brackets_allow_lint_parts(prefix_str: &str, lint_str: &str, span: Span) -
 
     let allow_parens_lint_path = auxiliary::token_trees_to_stream(&[get_allow(), parens_lint_path]);
 
-    TokenStream::from(TokenTree::Group(Group::new(
+    Ok(TokenStream::from(TokenTree::Group(Group::new(
         Delimiter::Bracket,
         allow_parens_lint_path,
-    )))
+    ))))
+}
+
+/// [`TokenStream`] consisting of `compile_error!("...");` with the given message. All its tokens are
+/// located at `span`, so that the error points to the offending token (rather than "proc macro
+/// panicked" pointing to the whole macro invocation).
+pub fn compile_error(message: &str, span: Span) -> TokenStream {
+    let mut message = TokenTree::Literal(Literal::string(message));
+    message.set_span(span);
+    let mut parens_message = get_parens(TokenStream::from(message));
+    parens_message.set_span(span);
+
+    let mut tokens = [
+        get_ident_tree("compile_error"),
+        get_punct_joint('!'),
+        parens_message,
+        get_punct_alone(';'),
+    ];
+    for token in tokens.iter_mut() {
+        token.set_span(span);
+    }
+    auxiliary::token_trees_to_stream(&tokens)
 }
@@ -29,6 +29,65 @@ fn emit_attributes_can_invoke_macros() {
 #[rustversion::not(since(1.54))]
 fn emit_attributes_can_invoke_macros() {}
 
+// Whether `#[allow(lint, reason = "...")]` is stable. Since
+// https://releases.rs/docs/1.81.0/#language.
+#[rustversion::since(1.81)]
+fn emit_can_give_lint_reason() {
+    println!("cargo:rustc-cfg=can_give_lint_reason");
+}
+#[rustversion::not(since(1.81))]
+fn emit_can_give_lint_reason() {}
+
+// Declare our custom `cfg` names (emitted above and below), so that rustc 1.80+ doesn't report them
+// as unexpected (`unexpected_cfgs` lint).
+#[rustversion::since(1.80)]
+fn emit_check_cfg() {
+    for name in &[
+        "unstable_feature",
+        "floating_toolchain",
+        "can_check_doc_attributes",
+        "attributes_can_invoke_macros",
+        "can_give_lint_reason",
+    ] {
+        println!("cargo:rustc-check-cfg=cfg({})", name);
+    }
+}
+#[rustversion::not(since(1.80))]
+fn emit_check_cfg() {}
+
+// The version of rustc, like `1.80.1` or `1.82.0-nightly`, for parameter `until_rust` of the
+// generated macros. (Our proc macros are built by the same rustc as the consumer crate.) Based on
+// `$RUSTC --version`, which prints like `rustc 1.80.1 (3f5fd8dd4 2024-08-06)`. Without it
+// `until_rust` couldn't work, hence we fail the build (rather than silently keep suppressing).
+fn emit_rustc_version() {
+    let rustc = std::env::var_os("RUSTC").unwrap_or_else(|| "rustc".into());
+    let output = std::process::Command::new(&rustc)
+        .arg("--version")
+        .output()
+        .unwrap_or_else(|error| {
+            panic!(
+                "Can't run `{} --version` (for `until_rust` parameters): {}",
+                rustc.to_string_lossy(),
+                error
+            )
+        });
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    match stdout.split_whitespace().nth(1) {
+        Some(version) if output.status.success() => {
+            println!("cargo:rustc-env=ALLOW_PREFIXED_RUSTC_VERSION={}", version)
+        }
+        _ => panic!(
+            "Can't get the version of rustc (for `until_rust` parameters) from `{} --version`. It printed: {}{}",
+            rustc.to_string_lossy(),
+            stdout,
+            String::from_utf8_lossy(&output.stderr)
+        ),
+    }
+}
+
+// If the toolchain is not floating (it's at a fixed version/date), or if we can't tell (like
+// without `rustup`), we are permissive: we let the user's code build, even if they use macros not
+// available for the newer versions.
 fn emit_floating_toolchain() {
     let toolchain = cargo_toolchain::get_active_toolchain();
     if let Ok(toolchain) = toolchain {
@@ -36,16 +95,543 @@ fn emit_floating_toolchain() {
             println!("cargo:rustc-cfg=floating_toolchain");
         }
     }
-    //@TODO print the error, if invoked with some flag or feature.
-    //
-    // Otherwise: The toolchain is either at a fixed version/date, or unknown. (TODO if we implement
-    // the flag/feature: Since the user didn't pass a flag/feature), we are permissive and we let
-    // the user's code build, even if they use macros not available for the newer versions.
+}
+
+// Aliases for lints of other tools (Dylint libraries, or `#![register_tool(...)]` tools), from a
+// lint list file at the path in `ALLOW_PREFIXED_TOOL_LINTS` environment variable (if set). Each
+// line of that file is a lint path like `our_lints::no_blocking_in_async`, optionally followed by
+// the URL of the lint's documentation. Empty lines and lines starting with `#` are ignored.
+//
+// For each lint we generate `generate_allow_attribute_macro_tool!(...)`, which defines an alias
+// named like `our_lints_no_blocking_in_async`, into `$OUT_DIR/tool_lints.rs`. lib.rs includes it.
+// The names of those aliases go into `$OUT_DIR/tool_lint_aliases.rs` (see src/strict.rs).
+//
+// Such a name may collide with the name of one of our other macros (`macro_names`, see
+// `emit_lint_table`), like `unused::results` with the alias of `unused_results`, or with another
+// lint of the file, like `a_b::c` with `a::b_c`. Then we fail the build.
+fn emit_tool_lints(macro_names: &[String]) {
+    println!("cargo:rerun-if-changed=build.rs");
+    println!("cargo:rerun-if-env-changed=ALLOW_PREFIXED_TOOL_LINTS");
+    let mut generated = String::new();
+    // (lint path, alias) of the lints so far.
+    let mut aliases: Vec<(String, String)> = Vec::new();
+    if let Some(path) = std::env::var_os("ALLOW_PREFIXED_TOOL_LINTS") {
+        let path = std::path::PathBuf::from(path);
+        println!("cargo:rerun-if-changed={}", path.display());
+        let content = std::fs::read_to_string(&path).unwrap_or_else(|error| {
+            panic!(
+                "Can't read lint list file {} (from ALLOW_PREFIXED_TOOL_LINTS): {}",
+                path.display(),
+                error
+            )
+        });
+        for (index, line) in content.lines().enumerate() {
+            let line = line.trim();
+            if line.is_empty() || line.starts_with('#') {
+                continue;
+            }
+            let mut parts = line.split_whitespace();
+            let lint_path = parts.next().unwrap();
+            let url = parts.next().unwrap_or("");
+            let (prefix, lint) = match tool_lint_path(lint_path) {
+                Some(prefix_lint) if parts.next().is_none() => prefix_lint,
+                _ => panic!(
+                    "Invalid line {} of lint list file {} (from ALLOW_PREFIXED_TOOL_LINTS): `{}`. Expecting `tool::lint_name`, optionally followed by a URL.",
+                    index + 1,
+                    path.display(),
+                    line
+                ),
+            };
+            let alias = format!("{}_{}", prefix, lint);
+            if macro_names.contains(&alias) {
+                panic!(
+                    "Lint `{}::{}` (line {} of lint list file {}, from ALLOW_PREFIXED_TOOL_LINTS) would have alias `{}`, but allow_prefixed has a macro with that name already. Use `#[allow_prefixed::tool_lint({}::{})]` for this lint instead.",
+                    prefix,
+                    lint,
+                    index + 1,
+                    path.display(),
+                    alias,
+                    prefix,
+                    lint
+                );
+            }
+            if let Some((other, _)) = aliases
+                .iter()
+                .find(|(_, other_alias)| *other_alias == alias)
+            {
+                panic!(
+                    "Lints `{}` and `{}::{}` (line {} of lint list file {}, from ALLOW_PREFIXED_TOOL_LINTS) would have the same alias `{}`. Use `#[allow_prefixed::tool_lint(...)]` for one of them instead.",
+                    other,
+                    prefix,
+                    lint,
+                    index + 1,
+                    path.display(),
+                    alias
+                );
+            }
+            aliases.push((format!("{}::{}", prefix, lint), alias.clone()));
+            let mut doc = format!("Alias to `#[allow({}::{})]`.", prefix, lint);
+            if !url.is_empty() {
+                doc.push_str(&format!(" See [the lint's documentation]({}).", url));
+            }
+            generated.push_str(&format!(
+                "generate_allow_attribute_macro_tool!({}, {}, {}, {:?}, {:?});\n",
+                prefix, lint, alias, url, doc
+            ));
+        }
+    }
+    let out_dir = std::path::PathBuf::from(std::env::var_os("OUT_DIR").unwrap());
+    std::fs::write(out_dir.join("tool_lints.rs"), generated).unwrap();
+    let names: Vec<&String> = aliases.iter().map(|(_, alias)| alias).collect();
+    std::fs::write(
+        out_dir.join("tool_lint_aliases.rs"),
+        format!("&{:?}\n", names),
+    )
+    .unwrap();
+}
+
+// Split `tool::lint_name` into the tool and the lint name, if both are identifiers (and the tool is
+// not one of rustc's, for which we have the aliases already).
+fn tool_lint_path(lint_path: &str) -> Option<(&str, &str)> {
+    let is_ident = |s: &str| {
+        s.chars()
+            .next()
+            .map_or(false, |c| c.is_ascii_alphabetic() || c == '_')
+            && s.chars().all(|c| c.is_ascii_alphanumeric() || c == '_')
+    };
+    let mut parts = lint_path.splitn(2, "::");
+    let prefix = parts.next()?;
+    let lint = parts.next()?;
+    if is_ident(prefix) && is_ident(lint) && prefix != "clippy" && prefix != "rustdoc" {
+        Some((prefix, lint))
+    } else {
+        None
+    }
+}
+
+// Whether cargo feature `name` (of this crate, like `rustc` or `clippy_pedantic`) is enabled. A
+// feature enabled by another one (like `clippy_pedantic` by `clippy`) counts, too.
+fn feature(name: &str) -> bool {
+    std::env::var_os(format!("CARGO_FEATURE_{}", name.to_uppercase())).is_some()
+}
+
+// A token of src/lints.rs or src/lib.rs, as far as `emit_lint_table` needs it: a word (an
+// identifier, a keyword or a number, like `unsafe_code`, `pub` or `1.95`), a literal (a string
+// literal with its quotes, or a character literal), any other punctuation character, or a group of
+// tokens in `(...)`, `[...]` or `{...}` (with its opening delimiter).
+enum Token {
+    Word(String),
+    Literal(String),
+    Punct(char),
+    Group(char, Vec<Token>),
+}
+
+impl Token {
+    fn is_punct(&self, c: char) -> bool {
+        matches!(self, Token::Punct(punct) if *punct == c)
+    }
+
+    fn is_word(&self, word: &str) -> bool {
+        matches!(self, Token::Word(this) if this == word)
+    }
+}
+
+// Read `source` (the content of `file`) as tokens, like rustc does: skip whitespace and comments
+// (including doc comments), and nest the tokens in delimiters into groups. Fail the build on
+// unbalanced delimiters, or on an unterminated comment or literal.
+fn tokenize(source: &str, file: &str) -> Vec<Token> {
+    let chars: Vec<char> = source.chars().collect();
+    // The groups being read, the outermost first: (opening delimiter, tokens so far).
+    let mut groups: Vec<(char, Vec<Token>)> = vec![(' ', Vec::new())];
+    let mut index = 0;
+    while index < chars.len() {
+        let c = chars[index];
+        let next = chars.get(index + 1).copied();
+        let token = if c.is_whitespace() {
+            index += 1;
+            continue;
+        } else if c == '/' && next == Some('/') {
+            while index < chars.len() && chars[index] != '\n' {
+                index += 1;
+            }
+            continue;
+        } else if c == '/' && next == Some('*') {
+            let end = (index + 2..chars.len().saturating_sub(1))
+                .find(|&end| chars[end] == '*' && chars[end + 1] == '/')
+                .unwrap_or_else(|| panic!("Unterminated comment in {}.", file));
+            index = end + 2;
+            continue;
+        } else if c == '"' {
+            let mut end = index + 1;
+            while chars.get(end) != Some(&'"') {
+                end += if chars.get(end) == Some(&'\\') { 2 } else { 1 };
+                if end >= chars.len() {
+                    panic!("Unterminated string literal in {}.", file);
+                }
+            }
+            let literal = chars[index..=end].iter().collect();
+            index = end + 1;
+            Token::Literal(literal)
+        } else if c == '\'' && (next == Some('\\') || chars.get(index + 2) == Some(&'\'')) {
+            // A character literal, like `'x'` or `'\''` (otherwise it's a lifetime, like `'a`).
+            let mut end = index + if next == Some('\\') { 3 } else { 2 };
+            while chars.get(end) != Some(&'\'') {
+                end += 1;
+                if end >= chars.len() {
+                    panic!("Unterminated character literal in {}.", file);
+                }
+            }
+            let literal = chars[index..=end].iter().collect();
+            index = end + 1;
+            Token::Literal(literal)
+        } else if c.is_alphanumeric() || c == '_' {
+            let mut end = index;
+            while end < chars.len()
+                && (chars[end].is_alphanumeric()
+                    || chars[end] == '_'
+                    // The dot of a version, like `1.95` (but not of `x.y`, or of a range `0..1`).
+                    || chars[end] == '.'
+                        && c.is_ascii_digit()
+                        && chars.get(end + 1).map_or(false, char::is_ascii_digit))
+            {
+                end += 1;
+            }
+            let word = chars[index..end].iter().collect();
+            index = end;
+            Token::Word(word)
+        } else if matches!(c, '(' | '[' | '{') {
+            groups.push((c, Vec::new()));
+            index += 1;
+            continue;
+        } else if let Some(open) = [')', ']', '}']
+            .iter()
+            .position(|&close| close == c)
+            .map(|position| ['(', '[', '{'][position])
+        {
+            match groups.pop() {
+                Some((opened, tokens)) if opened == open && !groups.is_empty() => {
+                    index += 1;
+                    Token::Group(open, tokens)
+                }
+                _ => panic!("Unbalanced `{}` (at character {}) in {}.", c, index, file),
+            }
+        } else {
+            index += 1;
+            Token::Punct(c)
+        };
+        groups.last_mut().unwrap().1.push(token);
+    }
+    match groups.pop() {
+        Some((' ', tokens)) if groups.is_empty() => tokens,
+        Some((open, _)) => panic!("Unclosed `{}` in {}.", open, file),
+        None => unreachable!(),
+    }
+}
+
+// The source text of `tokens` (one argument of a declaration, like `unsafe_code`, `1.95` or
+// `"..."`), without whitespace.
+fn text(tokens: &[Token]) -> String {
+    tokens
+        .iter()
+        .map(|token| match token {
+            Token::Word(text) | Token::Literal(text) => text.clone(),
+            Token::Punct(c) => c.to_string(),
+            Token::Group(open, tokens) => {
+                let close = match open {
+                    '(' => ')',
+                    '[' => ']',
+                    _ => '}',
+                };
+                format!("{}{}{}", open, text(tokens), close)
+            }
+        })
+        .collect()
+}
+
+// A declaration in src/lints.rs: a macro invocation `name!(argument, ...);`, with the name, the
+// arguments (as text), and the words in trailing brackets (if any): the groups of a standard lint or
+// the category of a `clippy::` lint, like `[unused]` or `[pedantic]`, or the groups of `clippy::all`.
+struct Declaration {
+    name: String,
+    arguments: Vec<String>,
+    bracketed: Vec<String>,
+}
+
+// The declarations in src/lints.rs, skipping their attributes (like `#[cfg(feature = "clippy")]`).
+// Fail the build on anything else at the top level of that file.
+fn declarations(source: &str) -> Vec<Declaration> {
+    let tokens = tokenize(source, "src/lints.rs");
+    let mut declarations = Vec::new();
+    let mut index = 0;
+    while index < tokens.len() {
+        match &tokens[index..] {
+            [Token::Punct('#'), Token::Group('[', _), ..] => index += 2,
+            [Token::Word(name), bang, Token::Group('(', inner), semicolon, ..]
+                if bang.is_punct('!') && semicolon.is_punct(';') =>
+            {
+                let mut arguments: Vec<&[Token]> = inner
+                    .split(|token| token.is_punct(','))
+                    .filter(|argument| !argument.is_empty())
+                    .collect();
+                let mut bracketed = Vec::new();
+                if let (true, Some([Token::Group('[', words)])) =
+                    (arguments.len() > 1, arguments.last())
+                {
+                    bracketed = words
+                        .split(|token| token.is_punct(','))
+                        .map(text)
+                        .collect();
+                    arguments.pop();
+                }
+                declarations.push(Declaration {
+                    name: name.clone(),
+                    arguments: arguments.into_iter().map(text).collect(),
+                    bracketed,
+                });
+                index += 4;
+            }
+            _ => panic!(
+                "Unexpected `{}` at the top level of src/lints.rs. Expecting only declarations, like `standard_lint!(lint_name);` (with any attributes).",
+                text(&tokens[index..index + 1])
+            ),
+        }
+    }
+    declarations
+}
+
+// Table of the lints that this crate knows, and of their aliases (if generated), for the lint paths
+// in the consumer's configuration and for suggesting aliases. From the declarations in src/lints.rs
+// (like `standard_lint_allowed!(unsafe_code);` or
+// `prefixed_lint_allowed!(clippy, unwrap_used, [restriction]);`, see `declarations`), into
+// `$OUT_DIR/lint_table.rs`. The table ignores the Rust versions of the lints, but it has only the
+// aliases that the enabled cargo features generate (see `feature`). It also tells whether each lint
+// is deny-by-default. See src/lint_table.rs.
+//
+// The groups (like `unused` or `clippy::pedantic`, and `warnings`) are in the table, too. Their lint
+// paths, with the lint paths of their members, also go into `$OUT_DIR/lint_groups.rs`.
+//
+// We also derive the members of lint groups, into `$OUT_DIR/lint_group_members.rs`: from the groups
+// that a standard lint declaration ends with (like `standard_lint!(unused_mut, [unused]);`), and
+// from the categories of `clippy::` lints. The groups themselves are declared like
+// `standard_lint_group!(unused, 1.45);` or `clippy_lint_group!(pedantic, clippy_pedantic, 1.45);`.
+//
+// The names of the aliases that are not in the table (of the tool attributes, and the audited
+// aliases) go into `$OUT_DIR/other_aliases.rs` (see src/strict.rs).
+//
+// Return the names of all our macros (the aliases of the lints, of the groups and of the tool
+// attributes, and the other macros in src/lib.rs, like `members`), for `emit_tool_lints`.
+fn emit_lint_table() -> Vec<String> {
+    println!("cargo:rerun-if-changed=src/lib.rs");
+    println!("cargo:rerun-if-changed=src/lints.rs");
+    // Macros other than aliases (like `members`): `pub fn name` at the top level of src/lib.rs.
+    let lib = tokenize(
+        &std::fs::read_to_string("src/lib.rs").unwrap(),
+        "src/lib.rs",
+    );
+    let mut macro_names: Vec<String> = lib
+        .windows(3)
+        .filter_map(|window| match window {
+            [public, function, Token::Word(name)]
+                if public.is_word("pub") && function.is_word("fn") =>
+            {
+                Some(name.clone())
+            }
+            _ => None,
+        })
+        .collect();
+    // Aliases of the tool attributes, and the audited aliases.
+    let mut other_aliases: Vec<String> = Vec::new();
+    // `warnings` is a group of whatever lints warn. It has no alias (see src/lints.rs).
+    let mut generated = String::from("&[\n    (\"warnings\", None, false),\n");
+    // (alias of the group, its members), in the order of the group declarations.
+    let mut groups: Vec<(String, Vec<String>)> = Vec::new();
+    // (alias of a group, member lint name), in any order.
+    let mut memberships: Vec<(String, String)> = Vec::new();
+    // (lint path of a group, like `unused` or `clippy::pedantic`, aliases of the groups whose members
+    // it has) - that's itself, except for `clippy::all`.
+    let mut group_paths: Vec<(String, Vec<String>)> = Vec::new();
+    let lints = std::fs::read_to_string("src/lints.rs").unwrap();
+    for declaration in declarations(&lints) {
+        let bracketed: Vec<&str> = declaration.bracketed.iter().map(String::as_str).collect();
+        let arguments: Vec<&str> = declaration.arguments.iter().map(String::as_str).collect();
+        // (lint path, alias - the name of its macro, if any, whether that macro is generated with
+        // the enabled cargo features). See `feature`.
+        let (lint_path, alias, alias_generated) =
+            match (declaration.name.as_str(), arguments.as_slice()) {
+                ("standard_lint_group", [group, _]) => {
+                    group_paths.push((group.to_string(), vec![group.to_string()]));
+                    groups.push((group.to_string(), Vec::new()));
+                    (
+                        group.to_string(),
+                        Some(group.to_string()),
+                        feature("groups") && feature("rustc"),
+                    )
+                }
+                // Not `clippy::all`, which lists its members (groups).
+                ("clippy_lint_group", [group, alias, _]) if bracketed.is_empty() => {
+                    group_paths.push((format!("clippy::{}", group), vec![alias.to_string()]));
+                    groups.push((alias.to_string(), Vec::new()));
+                    (
+                        format!("clippy::{}", group),
+                        Some(alias.to_string()),
+                        feature("groups") && feature(alias),
+                    )
+                }
+                ("clippy_lint_group", [group, alias, _]) => {
+                    group_paths.push((
+                        format!("clippy::{}", group),
+                        bracketed
+                            .iter()
+                            .map(|member| format!("clippy_{}", member))
+                            .collect(),
+                    ));
+                    let all_generated = feature("groups")
+                        && bracketed
+                            .iter()
+                            .all(|member| feature(&format!("clippy_{}", member)));
+                    generated.push_str(&format!(
+                        "    (\"clippy::{}\", {:?}, false),\n",
+                        group,
+                        Some(alias).filter(|_| all_generated)
+                    ));
+                    macro_names.push(alias.to_string());
+                    continue;
+                }
+                ("standard_lint", [lint])
+                | ("standard_lint_versioned", [_, lint])
+                | ("standard_lint_nightly", [lint]) => (lint.to_string(), None, false),
+                // Its macro only gives guidance, so it's not an alias to suggest.
+                ("standard_lint_crate_level", [lint]) => {
+                    macro_names.push(lint.to_string());
+                    (lint.to_string(), None, false)
+                }
+                ("standard_lint_allowed", [lint])
+                | ("standard_lint_allowed_from", [lint, ..])
+                | ("standard_lint_allowed_from_to", [lint, ..])
+                | ("standard_lint_allowed_nightly", [lint])
+                | ("standard_lint_warn", [lint])
+                | ("standard_lint_warn_from", [lint, ..])
+                | ("standard_lint_denied", [lint])
+                | ("standard_lint_denied_from", [lint, ..]) => {
+                    (lint.to_string(), Some(lint.to_string()), feature("rustc"))
+                }
+                ("prefixed_lint", [prefix, lint])
+                | ("prefixed_lint_versioned", [_, prefix, lint])
+                | ("prefixed_lint_nightly", [prefix, lint]) => {
+                    (format!("{}::{}", prefix, lint), None, false)
+                }
+                // Under the cargo feature of the lint's category, like `clippy_restriction`.
+                ("prefixed_lint_allowed", [prefix, lint])
+                | ("prefixed_lint_allowed_from", [prefix, lint, _]) => (
+                    format!("{}::{}", prefix, lint),
+                    Some(format!("{}_{}", prefix, lint)),
+                    bracketed
+                        .iter()
+                        .all(|category| feature(&format!("{}_{}", prefix, category))),
+                ),
+                // Aliases of tool attributes (other than lints), and the audited aliases, which are
+                // not aliases of lints.
+                ("generate_tool_attribute_macro", [_, _, alias, ..])
+                | ("generate_audited_allow_attribute_macro_standard", [_, alias, ..]) => {
+                    macro_names.push(alias.to_string());
+                    if !other_aliases.iter().any(|other| other == alias) {
+                        other_aliases.push(alias.to_string());
+                    }
+                    continue;
+                }
+                ("rustdoc", [lint, ..]) => (
+                    format!("rustdoc::{}", lint),
+                    Some(format!("rustdoc_{}", lint)),
+                    feature("rustdoc"),
+                ),
+                _ => continue,
+            };
+        // Standard deny-by-default lints, and `clippy::correctness` (see src/deny_by_default.rs).
+        let deny_by_default = declaration.name.starts_with("standard_lint_denied")
+            || (lint_path.starts_with("clippy::") && bracketed.contains(&"correctness"));
+        let (prefix, lint) = match lint_path.find("::") {
+            Some(colons) => (&lint_path[..colons + 2], &lint_path[colons + 2..]),
+            None => ("", lint_path.as_str()),
+        };
+        for group in &bracketed {
+            // Group aliases are like the aliases of lints: `unused`, `clippy_pedantic`.
+            let group = format!("{}{}", prefix.replace("::", "_"), group);
+            memberships.push((group, lint.to_string()));
+        }
+        generated.push_str(&format!(
+            "    ({:?}, {:?}, {}),\n",
+            lint_path,
+            alias.as_ref().filter(|_| alias_generated),
+            deny_by_default
+        ));
+        // Even if not generated, so that the tool lint aliases don't depend on cargo features.
+        macro_names.extend(alias);
+    }
+    generated.push_str("]\n");
+    let out_dir = std::path::PathBuf::from(std::env::var_os("OUT_DIR").unwrap());
+    std::fs::write(out_dir.join("lint_table.rs"), generated).unwrap();
+    std::fs::write(
+        out_dir.join("other_aliases.rs"),
+        format!("&{:?}\n", other_aliases),
+    )
+    .unwrap();
+
+    let mut generated = String::from(
+        "/// Invoke `$callback!(...)` with the members of the given lint group (by the alias of the group,\n\
+         /// like `unused` or `clippy_pedantic`) appended as `[member, ...]`. Generated by build.rs.\n\
+         macro_rules! lint_group_members {\n",
+    );
+    for (group, members) in &mut groups {
+        for (member_of, lint) in &memberships {
+            if member_of == group {
+                members.push(lint.clone());
+            }
+        }
+        if members.is_empty() {
+            panic!(
+                "Lint group `{}` has no members. Mark them in their declarations in src/lints.rs.",
+                group
+            );
+        }
+        members.sort();
+        members.dedup();
+        generated.push_str(&format!(
+            "    ({}, $callback:ident!($($args:tt)*)) => {{\n        $callback!($($args)*, [{}]);\n    }};\n",
+            group,
+            members.join(", ")
+        ));
+    }
+    generated.push_str("}\n");
+    std::fs::write(out_dir.join("lint_group_members.rs"), generated).unwrap();
+
+    let mut generated = String::from("&[\n");
+    for (group_path, member_groups) in &group_paths {
+        let prefix = if group_path.starts_with("clippy::") {
+            "clippy::"
+        } else {
+            ""
+        };
+        let members: Vec<String> = groups
+            .iter()
+            .filter(|(group, _)| member_groups.contains(group))
+            .flat_map(|(_, members)| members.iter())
+            .map(|member| format!("{}{}", prefix, member))
+            .collect();
+        generated.push_str(&format!("    ({:?}, &{:?}),\n", group_path, members));
+    }
+    generated.push_str("]\n");
+    std::fs::write(out_dir.join("lint_groups.rs"), generated).unwrap();
+    macro_names
 }
 
 fn main() {
+    emit_check_cfg();
     emit_unstable_feature();
     emit_floating_toolchain();
     emit_can_check_doc_attributes();
     emit_attributes_can_invoke_macros();
+    emit_can_give_lint_reason();
+    emit_rustc_version();
+    let macro_names = emit_lint_table();
+    emit_tool_lints(&macro_names);
 }
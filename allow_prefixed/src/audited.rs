@@ -0,0 +1,83 @@
+//! Audited variants of aliases for `unsafe_code` and `unsafe_op_in_unsafe_fn`, like
+//! `#[audited_unsafe_code(safety = "...")]`. They require a safety justification. They inject the
+//! allow like the plain aliases, and they append the justification to the item's documentation (as
+//! `#[doc = "..."]`), so that the generated docs list every audited unsafe region and its reasoning.
+
+use proc_macro::{Literal, Punct, Spacing, Span, TokenStream, TokenTree};
+
+use crate::auxiliary;
+use crate::params::{self, ParseResult};
+use crate::proc_builder::{self, LintProperties};
+
+/// The whole output of an audited alias (named `macro_name`) for (standard) lint `lint_str`. Take
+/// (and validate) `safety = "..."` out of `given_attrs`. Then pass the rest of `given_attrs`, and
+/// `item` with the justification appended to its documentation, to
+/// [`proc_builder::allow_attribute_macro_output`].
+pub fn allow_attribute_macro_output(
+    macro_name: &str,
+    lint_str: &str,
+    lint: LintProperties,
+    given_attrs: TokenStream,
+    item: TokenStream,
+) -> TokenStream {
+    let (safety, given_attrs) = match take_safety(macro_name, given_attrs) {
+        Ok(result) => result,
+        Err(error) => return auxiliary::token_streams_to_stream(&[error, item]),
+    };
+    let item = if lint.pass_through {
+        item
+    } else {
+        let span = proc_builder::consumer_span(&item);
+        let doc = format!(
+            "**SAFETY** (suppression of lint `{}`): {}",
+            lint_str,
+            params::string_value(&safety)
+        );
+        proc_builder::append_doc_paragraph(item, &doc, span)
+    };
+    proc_builder::allow_attribute_macro_output(macro_name, "", lint_str, lint, given_attrs, item)
+}
+
+/// Take parameter `safety = "..."` (required, non-empty) out of `given_attrs`. Return its value,
+/// and the rest of `given_attrs`.
+fn take_safety(macro_name: &str, given_attrs: TokenStream) -> ParseResult<(Literal, TokenStream)> {
+    let mut safety = None;
+    let mut rest = Vec::new();
+    for param in params::split_by_commas(given_attrs) {
+        match param.first() {
+            Some(TokenTree::Ident(name)) if name.to_string() == "safety" => {
+                if safety.is_some() {
+                    return Err(params::error(
+                        macro_name,
+                        "Duplicate parameter `safety`.",
+                        name.span(),
+                    ));
+                }
+                let value = params::parse_string_value(macro_name, &param)?;
+                if params::string_value(&value).trim().is_empty() {
+                    return Err(params::error(
+                        macro_name,
+                        "Parameter `safety` must not be empty. Justify why the unsafe code is sound.",
+                        value.span(),
+                    ));
+                }
+                safety = Some(value);
+            }
+            Some(_) => {
+                if !rest.is_empty() {
+                    rest.push(TokenTree::Punct(Punct::new(',', Spacing::Alone)));
+                }
+                rest.extend(param);
+            }
+            None => {}
+        }
+    }
+    match safety {
+        Some(safety) => Ok((safety, auxiliary::token_trees_to_stream(&rest))),
+        None => Err(params::error(
+            macro_name,
+            "Expecting `safety = \"...\"`: a justification why the unsafe code is sound.",
+            Span::call_site(),
+        )),
+    }
+}
@@ -0,0 +1,781 @@
+//! Configuration of the consumer crate (the one being compiled): `[package.metadata.allow]` in its
+//! `Cargo.toml`, and/or the same keys at the top level of `allow.toml` next to it. Cargo sets
+//! `CARGO_MANIFEST_DIR` for rustc, and hence for our proc macros, at expansion time. So the
+//! configuration is per crate.
+//!
+//! We have no dependencies (other than `rustversion`), so we read `Cargo.toml` with a minimal TOML
+//! reader. It understands tables, dotted keys, strings, booleans, arrays and inline tables. It skips
+//! other values (numbers, dates) - we don't need them. In `Cargo.toml` it reads only what may be
+//! ours (`[package.metadata.allow]`, or keys like `metadata.allow` under `[package]`). It skips
+//! anything else without interpreting it, so any TOML that it doesn't understand elsewhere in
+//! `Cargo.toml` doesn't matter.
+
+use proc_macro::{Span, TokenStream};
+use std::cell::{Cell, RefCell};
+use std::path::PathBuf;
+use std::rc::Rc;
+use std::time::SystemTime;
+
+use crate::lint_table;
+use crate::policy;
+use crate::proc_builder;
+
+/// The TOML table (path) that holds our configuration in `Cargo.toml`.
+const TABLE: [&str; 3] = ["package", "metadata", "allow"];
+
+/// Our own configuration file (next to `Cargo.toml`). It has the same keys as [`TABLE`], but at the
+/// top level.
+const ALLOW_TOML: &str = "allow.toml";
+
+/// The configuration files (in the manifest directory), and the TOML table in each that holds our
+/// configuration.
+const FILES: [(&str, &[&str]); 2] = [("Cargo.toml", &TABLE), (ALLOW_TOML, &[])];
+
+/// A loaded configuration (`None` if malformed - then we've reported it already), with the manifest
+/// directory, and the modification times of [`FILES`] (`None` if missing), and of the policy file
+/// (if any), when we loaded it.
+type Loaded = (PathBuf, Vec<Option<SystemTime>>, Option<Rc<Config>>);
+
+thread_local! {
+    /// Configurations loaded so far, so that we don't read and parse the files on each use of an
+    /// alias. Keyed by the manifest directory (a proc macro server, like rust-analyzer's, may expand
+    /// for several crates), and by the modification times (it may outlive changes of the files).
+    static LOADED: RefCell<Vec<Loaded>> = RefCell::new(Vec::new());
+}
+
+/// Configuration from `[package.metadata.allow]`.
+#[derive(Default)]
+pub struct Config {
+    /// Keys of metadata that the aliases accept, like `owner` or `ticket`. From
+    /// `metadata = ["owner", "ticket", ...]`. See [`metadata`](crate::metadata).
+    pub metadata_keys: Vec<String>,
+    /// Pairs of (lint path, metadata keys that each suppression of that lint has to have). From
+    /// `[package.metadata.allow.required_metadata]` with entries like `unsafe_code = ["owner"]`.
+    pub required_metadata: Vec<(String, Vec<String>)>,
+    /// Whether each alias use has to have a non-empty `reason = "..."`. From `require_reason = true`.
+    pub require_reason: bool,
+    /// Whether aliases for deny-by-default lints warn unless they have `acknowledge_deny = true`
+    /// and a reason. From `require_acknowledge_deny = true`. See
+    /// [`deny_by_default`](crate::deny_by_default).
+    pub require_acknowledge_deny: bool,
+    /// Named intents for `#[intent(name)]`. From `[package.metadata.allow.intents.<name>]`. See
+    /// [`intents`](crate::intents).
+    pub intents: Vec<Intent>,
+    /// The files that we've read any configuration from, and the [`policy`](crate::policy) file (if
+    /// any), for [`proc_builder::track_files`](crate::proc_builder::track_files).
+    pub files: Vec<PathBuf>,
+    /// Whether a use of a macro has included [`files`](Config::files) already. Cargo needs them
+    /// only once per crate. See [`Config::track`].
+    pub tracked: Cell<bool>,
+}
+
+/// A named intent: lints suppressed together, like `#[intent(ffi_boundary)]`.
+pub struct Intent {
+    /// From the key, like `ffi_boundary` in `[package.metadata.allow.intents.ffi_boundary]`.
+    pub name: String,
+    /// Lint paths, like `improper_ctypes_definitions` or `clippy::missing_safety_doc`. From
+    /// `lints = [...]`.
+    pub lints: Vec<String>,
+    /// Whether each use has to have a non-empty `reason = "..."` (of its own, not just the
+    /// description). From `require_reason = true`.
+    pub require_reason: bool,
+    /// What the intent is for. It serves as the reason if a use doesn't give one: It satisfies the
+    /// crate's `require_reason = true`, but not the intent's own. From `description = "..."`.
+    pub description: Option<String>,
+}
+
+impl Config {
+    /// Metadata keys required for `lint_path` (like `unsafe_code` or `clippy::indexing_slicing`).
+    pub fn required_metadata(&self, lint_path: &str) -> &[String] {
+        self.required_metadata
+            .iter()
+            .find(|(lint, _)| lint == lint_path)
+            .map_or(&[][..], |(_, keys)| keys.as_slice())
+    }
+
+    /// The files that the current use of a macro should include (see
+    /// [`proc_builder::includes`](crate::proc_builder::includes)), for uses that always have a place
+    /// for them: [`files`](Config::files) if no use has included them yet (and then mark them as
+    /// included), otherwise none.
+    pub fn track(&self) -> &[PathBuf] {
+        if self.tracked.replace(true) {
+            &[]
+        } else {
+            &self.files
+        }
+    }
+
+    /// The intent named `name`, if configured.
+    pub fn intent(&self, name: &str) -> Option<&Intent> {
+        self.intents.iter().find(|intent| intent.name == name)
+    }
+
+    /// The intent named `name`, created (with no lints) if not configured yet.
+    fn intent_mut(&mut self, name: &str) -> &mut Intent {
+        match self.intents.iter().position(|intent| intent.name == name) {
+            Some(index) => &mut self.intents[index],
+            None => {
+                self.intents.push(Intent {
+                    name: name.to_owned(),
+                    lints: Vec::new(),
+                    require_reason: false,
+                    description: None,
+                });
+                self.intents.last_mut().unwrap()
+            }
+        }
+    }
+}
+
+/// Load the configuration of the crate being compiled (from its `Cargo.toml`, and from its
+/// `allow.toml`, if any). Return the default configuration if there's no configuration, or if we
+/// don't know the crate's manifest (when not built by cargo). If the configuration (or `Cargo.toml`
+/// itself) is malformed, return an error (`compile_error!` at `span`).
+///
+/// Loaded once per crate (until the files change). See [`LOADED`]. So we report a malformed
+/// configuration once, rather than at each use of an alias: Later uses get an empty error (and pass
+/// their items through as-is), since the crate fails to compile anyway.
+pub fn load(span: Span) -> Result<Rc<Config>, TokenStream> {
+    let dir = match std::env::var_os("CARGO_MANIFEST_DIR") {
+        Some(dir) => PathBuf::from(dir),
+        None => return Ok(Rc::new(Config::default())),
+    };
+    let policy_file = policy::file(&dir).filter(|path| path.is_file());
+    let modified: Vec<Option<SystemTime>> = FILES
+        .iter()
+        .map(|(file, _)| dir.join(file))
+        .chain(policy_file.clone())
+        .map(|path| {
+            std::fs::metadata(path)
+                .and_then(|metadata| metadata.modified())
+                .ok()
+        })
+        .collect();
+    LOADED.with(|loaded| {
+        let mut loaded = loaded.borrow_mut();
+        if let Some((_, _, config)) = loaded.iter().find(|(loaded_dir, loaded_modified, _)| {
+            *loaded_dir == dir && *loaded_modified == modified
+        }) {
+            return config.clone().ok_or_else(TokenStream::new);
+        }
+        let config = load_uncached(&dir).map(|mut config| {
+            config.files.extend(policy_file);
+            Rc::new(config)
+        });
+        loaded.retain(|(loaded_dir, _, _)| *loaded_dir != dir);
+        loaded.push((dir, modified, config.clone().ok()));
+        config.map_err(|message| proc_builder::compile_error(&message, span))
+    })
+}
+
+/// Like [`load`], but read and parse the files in `dir` each time.
+fn load_uncached(dir: &std::path::Path) -> Result<Config, String> {
+    let mut config = Config::default();
+    for (file, table) in FILES.iter() {
+        let path = dir.join(file);
+        let content = match std::fs::read_to_string(&path) {
+            Ok(content) => content,
+            Err(_) => continue,
+        };
+        let found = parse_into(&mut config, &content, table).map_err(|message| {
+            if table.is_empty() {
+                format!("Invalid {}: {}", file, message)
+            } else {
+                format!("Invalid `[{}]` in {}: {}", table.join("."), file, message)
+            }
+        })?;
+        // A `Cargo.toml` without our table doesn't affect us, so it needs no tracking. An
+        // `allow.toml` is all ours, so even an empty one does (for when it gets settings).
+        if found || table.is_empty() {
+            config.files.push(path);
+        }
+    }
+    Ok(config)
+}
+
+/// Parse `content` of `Cargo.toml` into our configuration.
+#[cfg(test)]
+fn parse(content: &str) -> Result<Config, String> {
+    let mut config = Config::default();
+    parse_into(&mut config, content, &TABLE).map(|_| config)
+}
+
+/// Parse our configuration under `table` in `content` (of a TOML file) into `config`. Return
+/// whether there's any (even if `table` is empty).
+fn parse_into(config: &mut Config, content: &str, table: &[&str]) -> Result<bool, String> {
+    let mut required_metadata_line = None;
+    // Only entries under `table` (not its parents, like `metadata = { docs = ... }` under
+    // `[package]`).
+    let entries: Vec<Entry> = Reader::new(content)
+        .entries(table)?
+        .into_iter()
+        .filter(|entry| entry.path.len() >= table.len() && entry.path[..table.len()] == *table)
+        .collect();
+    let found = !entries.is_empty();
+    for entry in entries {
+        let key: Vec<&str> = entry.path[table.len()..]
+            .iter()
+            .map(String::as_str)
+            .collect();
+        let line = entry.line;
+        let at_line = |message: &str| format!("line {}: {}", line, message);
+        match (key.as_slice(), entry.value) {
+            (["metadata"], Value::Array(keys)) => {
+                config.metadata_keys =
+                    strings(keys).ok_or_else(|| at_line("Expecting an array of strings."))?;
+            }
+            (["required_metadata", lint], Value::Array(keys)) => {
+                let keys =
+                    strings(keys).ok_or_else(|| at_line("Expecting an array of strings."))?;
+                config.required_metadata.push(((*lint).to_owned(), keys));
+                required_metadata_line = Some(line);
+            }
+            (["require_reason"], Value::Boolean(require_reason)) => {
+                config.require_reason = require_reason;
+            }
+            ([], _) => return Err(at_line("Expecting a table.")),
+            (["require_acknowledge_deny"], Value::Boolean(require_acknowledge_deny)) => {
+                config.require_acknowledge_deny = require_acknowledge_deny;
+            }
+            (["intents", name, "lints"], Value::Array(lints)) => {
+                let lints =
+                    strings(lints).ok_or_else(|| at_line("Expecting an array of lint paths."))?;
+                if let Some(unknown) = lints.iter().find(|lint| !lint_table::is_known(lint)) {
+                    return Err(at_line(&format!(
+                        "Unknown lint `{}` in intent `{}`.",
+                        unknown, name
+                    )));
+                }
+                if lints.iter().any(|lint| lint == "warnings") {
+                    return Err(at_line(&format!(
+                        "`warnings` (in intent `{}`) covers all lints that warn, so it would suppress anything. Name the lints (or lint groups) instead.",
+                        name
+                    )));
+                }
+                config.intent_mut(name).lints = lints;
+            }
+            (["intents", name, "require_reason"], Value::Boolean(require_reason)) => {
+                config.intent_mut(name).require_reason = require_reason;
+            }
+            (["intents", name, "description"], Value::String(description)) => {
+                config.intent_mut(name).description = Some(description);
+            }
+            (["require_reason"], _)
+            | (["require_acknowledge_deny"], _)
+            | (["intents", _, "require_reason"], _) => {
+                return Err(at_line("Expecting `true` or `false`."))
+            }
+            (["metadata"], _) | (["required_metadata", _], _) => {
+                return Err(at_line("Expecting an array of strings."))
+            }
+            (["intents", _, "lints"], _) => return Err(at_line("Expecting an array of lint paths.")),
+            (["intents", _, "description"], _) => return Err(at_line("Expecting a string.")),
+            _ => {
+                return Err(at_line(&format!(
+                    "Unknown key `{}`. Expecting `metadata`, `required_metadata.<lint>`, `require_reason`, `require_acknowledge_deny` or `intents.<name>.lints` (with optional `intents.<name>.require_reason` and `intents.<name>.description`).",
+                    key.join(".")
+                )))
+            }
+        }
+    }
+    for (lint, keys) in &config.required_metadata {
+        for key in keys {
+            if !config.metadata_keys.contains(key) {
+                return Err(format!(
+                    "line {}: Metadata key `{}` required for lint `{}` is not listed in `metadata`.",
+                    required_metadata_line.unwrap_or_default(),
+                    key,
+                    lint
+                ));
+            }
+        }
+    }
+    if let Some(intent) = config.intents.iter().find(|intent| intent.lints.is_empty()) {
+        return Err(format!("Intent `{}` has no `lints`.", intent.name));
+    }
+    Ok(found)
+}
+
+/// Key-value pairs in `content` (of a TOML file), flattened. For other files that we read with our
+/// TOML reader, like the [`policy`](crate::policy) file.
+pub fn entries(content: &str) -> Result<Vec<Entry>, String> {
+    Reader::new(content).entries(&[])
+}
+
+/// Whether the key (or the table) at `path` may hold a part of table `wanted`: either is a prefix of
+/// the other (like `package.metadata` or `package.metadata.allow.intents` for
+/// `package.metadata.allow`).
+fn is_relevant(path: &[String], wanted: &[&str]) -> bool {
+    path.iter().zip(wanted).all(|(part, wanted)| part == wanted)
+}
+
+/// Values of `array`, if all are strings.
+pub fn strings(array: Vec<Value>) -> Option<Vec<String>> {
+    array
+        .into_iter()
+        .map(|value| match value {
+            Value::String(string) => Some(string),
+            _ => None,
+        })
+        .collect()
+}
+
+/// A TOML value, as far as we need it.
+pub enum Value {
+    String(String),
+    Boolean(bool),
+    Array(Vec<Value>),
+    /// An inline table: pairs of (dotted key, value).
+    Table(Vec<(Vec<String>, Value)>),
+    /// A number, a date...
+    Other,
+}
+
+/// A key-value pair, with the full path of its key (including the table), flattened out of any
+/// inline tables.
+pub struct Entry {
+    pub path: Vec<String>,
+    pub value: Value,
+    /// 1-based.
+    pub line: usize,
+}
+
+/// A minimal TOML reader. See https://toml.io/en/v1.0.0.
+struct Reader {
+    chars: Vec<char>,
+    pos: usize,
+    /// 1-based.
+    line: usize,
+}
+
+impl Reader {
+    fn new(content: &str) -> Self {
+        Self {
+            chars: content.chars().collect(),
+            pos: 0,
+            line: 1,
+        }
+    }
+
+    fn peek(&self) -> Option<char> {
+        self.chars.get(self.pos).copied()
+    }
+
+    fn peek_is(&self, s: &str) -> bool {
+        s.chars()
+            .enumerate()
+            .all(|(i, c)| self.chars.get(self.pos + i) == Some(&c))
+    }
+
+    fn next(&mut self) -> Option<char> {
+        let c = self.peek()?;
+        self.pos += 1;
+        if c == '\n' {
+            self.line += 1;
+        }
+        Some(c)
+    }
+
+    fn error<T>(&self, message: &str) -> Result<T, String> {
+        Err(format!("line {}: {}", self.line, message))
+    }
+
+    fn expect(&mut self, c: char) -> Result<(), String> {
+        if self.next() == Some(c) {
+            Ok(())
+        } else {
+            self.error(&format!("Expecting `{}`.", c))
+        }
+    }
+
+    /// Skip spaces and tabs. If `newlines`, then skip newlines and comments, too.
+    fn skip_whitespace(&mut self, newlines: bool) {
+        while let Some(c) = self.peek() {
+            match c {
+                ' ' | '\t' | '\r' => {}
+                '\n' if newlines => {}
+                '#' if newlines => self.skip_comment(),
+                _ => return,
+            }
+            self.next();
+        }
+    }
+
+    /// Skip a comment (if any), up to (but excluding) the end of line.
+    fn skip_comment(&mut self) {
+        if self.peek() == Some('#') {
+            while self.peek().map_or(false, |c| c != '\n') {
+                self.next();
+            }
+        }
+    }
+
+    /// Expect the end of line (or of the content), possibly after a comment.
+    fn end_of_line(&mut self) -> Result<(), String> {
+        self.skip_whitespace(false);
+        self.skip_comment();
+        match self.next() {
+            None | Some('\n') => Ok(()),
+            Some(_) => self.error("Expecting the end of line."),
+        }
+    }
+
+    /// The entries under table `wanted` (all of them if it's empty). We skip any other key-value
+    /// pairs without interpreting their values (see [`Reader::skip_entry`]).
+    fn entries(mut self, wanted: &[&str]) -> Result<Vec<Entry>, String> {
+        let mut entries = Vec::new();
+        let mut table = Vec::new();
+        loop {
+            self.skip_whitespace(true);
+            match self.peek() {
+                None => return Ok(entries),
+                Some('[') => {
+                    self.next();
+                    // An array of tables `[[name]]`: None of its entries are ours.
+                    let array = self.peek() == Some('[');
+                    if array {
+                        self.next();
+                    }
+                    self.skip_whitespace(false);
+                    table = self.key()?;
+                    self.expect(']')?;
+                    if array {
+                        self.expect(']')?;
+                    }
+                    self.end_of_line()?;
+                }
+                Some(_) if !is_relevant(&table, wanted) => self.skip_entry()?,
+                Some(_) => {
+                    let line = self.line;
+                    let mut path = table.clone();
+                    path.extend(self.key()?);
+                    self.expect('=')?;
+                    self.skip_whitespace(false);
+                    if !is_relevant(&path, wanted) {
+                        self.skip_entry()?;
+                        continue;
+                    }
+                    let value = self.value()?;
+                    self.end_of_line()?;
+                    flatten(path, value, line, &mut entries);
+                }
+            }
+        }
+    }
+
+    /// Skip (the rest of) a key-value pair that isn't ours, up to the end of its line - or further,
+    /// if its value is an array, an inline table or a string that spans several lines. We don't
+    /// interpret it, other than finding the ends of its strings (which may contain brackets or `#`).
+    fn skip_entry(&mut self) -> Result<(), String> {
+        let mut depth = 0usize;
+        loop {
+            match self.peek() {
+                None => return Ok(()),
+                Some('"') => {
+                    self.basic_string()?;
+                }
+                Some('\'') => {
+                    self.literal_string()?;
+                }
+                Some('#') => self.skip_comment(),
+                Some('[') | Some('{') => {
+                    depth += 1;
+                    self.next();
+                }
+                Some(']') | Some('}') => {
+                    depth = depth.saturating_sub(1);
+                    self.next();
+                }
+                Some('\n') if depth == 0 => {
+                    self.next();
+                    return Ok(());
+                }
+                Some(_) => {
+                    self.next();
+                }
+            }
+        }
+    }
+
+    /// A (possibly dotted) key, followed by any spaces.
+    fn key(&mut self) -> Result<Vec<String>, String> {
+        let mut key = Vec::new();
+        loop {
+            key.push(match self.peek() {
+                Some('"') => self.basic_string()?,
+                Some('\'') => self.literal_string()?,
+                _ => {
+                    let mut part = String::new();
+                    while let Some(c) = self.peek() {
+                        if !(c.is_ascii_alphanumeric() || c == '_' || c == '-') {
+                            break;
+                        }
+                        part.push(c);
+                        self.next();
+                    }
+                    if part.is_empty() {
+                        return self.error("Expecting a key.");
+                    }
+                    part
+                }
+            });
+            self.skip_whitespace(false);
+            if self.peek() != Some('.') {
+                return Ok(key);
+            }
+            self.next();
+            self.skip_whitespace(false);
+        }
+    }
+
+    fn value(&mut self) -> Result<Value, String> {
+        match self.peek() {
+            Some('"') => Ok(Value::String(self.basic_string()?)),
+            Some('\'') => Ok(Value::String(self.literal_string()?)),
+            Some('[') => {
+                self.next();
+                let mut values = Vec::new();
+                loop {
+                    self.skip_whitespace(true);
+                    if self.peek() == Some(']') {
+                        self.next();
+                        return Ok(Value::Array(values));
+                    }
+                    values.push(self.value()?);
+                    self.skip_whitespace(true);
+                    match self.peek() {
+                        Some(',') => {
+                            self.next();
+                        }
+                        Some(']') => {}
+                        _ => return self.error("Expecting `,` or `]`."),
+                    }
+                }
+            }
+            Some('{') => {
+                self.next();
+                let mut pairs = Vec::new();
+                loop {
+                    self.skip_whitespace(false);
+                    if self.peek() == Some('}') {
+                        self.next();
+                        return Ok(Value::Table(pairs));
+                    }
+                    let key = self.key()?;
+                    self.expect('=')?;
+                    self.skip_whitespace(false);
+                    pairs.push((key, self.value()?));
+                    self.skip_whitespace(false);
+                    match self.peek() {
+                        Some(',') => {
+                            self.next();
+                        }
+                        Some('}') => {}
+                        _ => return self.error("Expecting `,` or `}`."),
+                    }
+                }
+            }
+            _ => {
+                let mut scalar = String::new();
+                while let Some(c) = self.peek() {
+                    if c.is_whitespace() || c == ',' || c == ']' || c == '}' || c == '#' {
+                        break;
+                    }
+                    scalar.push(c);
+                    self.next();
+                }
+                match scalar.as_str() {
+                    "" => self.error("Expecting a value."),
+                    "true" => Ok(Value::Boolean(true)),
+                    "false" => Ok(Value::Boolean(false)),
+                    _ => Ok(Value::Other),
+                }
+            }
+        }
+    }
+
+    /// A basic string `"..."`, or a multi-line basic string `"""..."""`. Return its value.
+    fn basic_string(&mut self) -> Result<String, String> {
+        let multi_line = self.peek_is("\"\"\"");
+        let delimiter = if multi_line { "\"\"\"" } else { "\"" };
+        self.pos += delimiter.len();
+        let mut value = String::new();
+        loop {
+            if self.peek_is(delimiter) {
+                self.pos += delimiter.len();
+                return Ok(value);
+            }
+            match self.next() {
+                None => return self.error("Unterminated string."),
+                Some('\n') if !multi_line => return self.error("Unterminated string."),
+                Some('\\') => match self.next() {
+                    Some('"') => value.push('"'),
+                    Some('\\') => value.push('\\'),
+                    Some('n') => value.push('\n'),
+                    Some('t') => value.push('\t'),
+                    Some('r') => value.push('\r'),
+                    // Other escapes (`\u...`, a line ending backslash...): Not needed by us.
+                    Some(c) => {
+                        value.push('\\');
+                        value.push(c);
+                    }
+                    None => return self.error("Unterminated string."),
+                },
+                Some(c) => value.push(c),
+            }
+        }
+    }
+
+    /// A literal string `'...'`, or a multi-line literal string `'''...'''`. Return its value.
+    fn literal_string(&mut self) -> Result<String, String> {
+        let delimiter = if self.peek_is("'''") { "'''" } else { "'" };
+        self.pos += delimiter.len();
+        let mut value = String::new();
+        loop {
+            if self.peek_is(delimiter) {
+                self.pos += delimiter.len();
+                return Ok(value);
+            }
+            match self.next() {
+                None => return self.error("Unterminated string."),
+                Some('\n') if delimiter == "'" => return self.error("Unterminated string."),
+                Some(c) => value.push(c),
+            }
+        }
+    }
+}
+
+/// Add `value` at `path` to `entries`. Flatten any inline tables.
+fn flatten(path: Vec<String>, value: Value, line: usize, entries: &mut Vec<Entry>) {
+    match value {
+        Value::Table(pairs) => {
+            for (key, value) in pairs {
+                let mut inner = path.clone();
+                inner.extend(key);
+                flatten(inner, value, line, entries);
+            }
+        }
+        value => entries.push(Entry { path, value, line }),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{parse, parse_into, Config};
+
+    #[test]
+    fn parse_metadata_schema() {
+        let config = parse(
+            r#"
+[package]
+name = "consumer" # a comment
+version = "0.1.0"
+authors = [
+    "Someone <someone@example.com>", # a comment in an array
+]
+
+[package.metadata.allow]
+metadata = ["owner", 'ticket', "review_by"]
+
+[package.metadata.allow.required_metadata]
+unsafe_code = ["owner", "ticket"]
+"clippy::indexing_slicing" = ["owner"]
+
+[dependencies]
+allow = { version = "0.2", features = ["groups"] }
+"#,
+        )
+        .unwrap();
+        assert_eq!(config.metadata_keys, ["owner", "ticket", "review_by"]);
+        assert_eq!(config.required_metadata("unsafe_code"), ["owner", "ticket"]);
+        assert_eq!(
+            config.required_metadata("clippy::indexing_slicing"),
+            ["owner"]
+        );
+        assert!(config.required_metadata("dead_code").is_empty());
+    }
+
+    #[test]
+    fn parse_inline_table_and_errors() {
+        let config = parse("[package]\nmetadata.allow = { metadata = [\"owner\"] }\n").unwrap();
+        assert_eq!(config.metadata_keys, ["owner"]);
+        assert!(!config.require_reason);
+
+        let config = parse("[package.metadata.allow]\nrequire_reason = true # audited\n").unwrap();
+        assert!(config.require_reason);
+        assert!(parse("[package.metadata.allow]\nrequire_reason = \"yes\"\n").is_err());
+
+        assert!(parse("[package.metadata.allow]\nmetadata = \"owner\"\n").is_err());
+        assert!(parse("[package.metadata.allow]\nmetadate = []\n").is_err());
+        assert!(parse(
+            "[package.metadata.allow]\nmetadata = []\nrequired_metadata.unsafe_code = [\"owner\"]\n"
+        )
+        .is_err());
+        assert!(parse("[package\n").is_err());
+    }
+
+    #[test]
+    fn skip_other_tables() {
+        let content = r#"
+[package]
+name = "consumer"
+metadata.docs.rs = { all-features = true, rustdoc-args = ["--cfg", "docsrs"] }
+
+[package.metadata.release]
+published = 1979-05-27 07:32:00Z
+pre-release-replacements = [ { file = "README.md", search = "v[0-9.]+\"", replace = "{{version}}" } ]
+
+[dependencies]
+allow = { version = "0.2", features = ["groups"] }
+weird = 0x_ff
+"#;
+        let mut config = Config::default();
+        assert!(!parse_into(&mut config, content, &super::TABLE).unwrap());
+
+        let content = format!(
+            "{}\n[package.metadata.allow]\nrequire_reason = true\n",
+            content
+        );
+        let mut config = Config::default();
+        assert!(parse_into(&mut config, &content, &super::TABLE).unwrap());
+        assert!(config.require_reason);
+    }
+
+    #[test]
+    fn parse_intents() {
+        let mut config = Config::default();
+        parse_into(
+            &mut config,
+            r#"
+[intents.ffi_boundary]
+lints = ["improper_ctypes_definitions", "clippy::missing_safety_doc"]
+require_reason = true
+description = "C calls these."
+
+[intents]
+generated.lints = ["dead_code"]
+prototype.lints = ["unused", "clippy::pedantic"]
+"#,
+            &[],
+        )
+        .unwrap();
+        let ffi_boundary = config.intent("ffi_boundary").unwrap();
+        assert_eq!(
+            ffi_boundary.lints,
+            ["improper_ctypes_definitions", "clippy::missing_safety_doc"]
+        );
+        assert!(ffi_boundary.require_reason);
+        assert_eq!(ffi_boundary.description.as_deref(), Some("C calls these."));
+        assert!(!config.intent("generated").unwrap().require_reason);
+        assert_eq!(
+            config.intent("prototype").unwrap().lints,
+            ["unused", "clippy::pedantic"]
+        );
+        assert!(config.intent("legacy").is_none());
+
+        assert!(parse("[package.metadata.allow.intents.x]\nlints = [\"dead_cod\"]\n").is_err());
+        assert!(parse("[package.metadata.allow.intents.x]\nlints = [\"warnings\"]\n").is_err());
+        assert!(parse("[package.metadata.allow.intents.x]\ndescription = \"no lints\"\n").is_err());
+        assert!(parse("[package.metadata.allow.intents.x]\nlints = \"dead_code\"\n").is_err());
+    }
+}
@@ -0,0 +1,71 @@
+//! Deny-by-default lints: rustc's deny-by-default lints (like `arithmetic_overflow`), and Clippy's
+//! `correctness` group. Suppressing one of those is almost always a red flag. If the consumer opts
+//! in with `require_acknowledge_deny = true` (see [`config`](crate::config)), then each such
+//! suppression has to be acknowledged with `acknowledge_deny = true` and a reason.
+
+use proc_macro::{Span, TokenStream};
+
+use crate::config::Config;
+use crate::params::{self, Params};
+use crate::proc_builder;
+
+/// Check parameter `acknowledge_deny` (if any) against whether the lint is `deny_by_default`. Return
+/// an error if the lint is not deny-by-default (so there's nothing to acknowledge).
+///
+/// Otherwise, if `config` requires acknowledgement, but the suppression of a deny-by-default lint
+/// doesn't have `acknowledge_deny = true` and a non-empty reason, return a notice (a `deprecated`
+/// warning). The allow is injected regardless. The caller places the notice (like inside the item -
+/// see [`proc_builder::with_notice`]). If it has no place for it (not `has_place`), return an error
+/// instead.
+pub fn acknowledge_deny(
+    macro_name: &str,
+    lint_path: &str,
+    deny_by_default: bool,
+    params: &Params,
+    config: &Config,
+    has_place: bool,
+    span: Span,
+) -> Result<Option<TokenStream>, TokenStream> {
+    let acknowledged = match &params.acknowledge_deny {
+        Some((name, _)) if !deny_by_default => {
+            return Err(params::error(
+                macro_name,
+                &format!(
+                    "Lint `{}` is not deny-by-default, so there is nothing to acknowledge. Remove `acknowledge_deny`.",
+                    lint_path
+                ),
+                name.span(),
+            ))
+        }
+        Some((_, acknowledged)) => *acknowledged,
+        None => false,
+    };
+    if !deny_by_default || !config.require_acknowledge_deny {
+        return Ok(None);
+    }
+    let has_reason = params.reason.as_ref().map_or(false, |reason| {
+        !params::string_value(reason).trim().is_empty()
+    });
+    if acknowledged && has_reason {
+        return Ok(None);
+    }
+    if !has_place {
+        return Err(params::error(
+            macro_name,
+            &format!(
+                "Lint `{}` is deny-by-default, but a notice about that would have no place on a type alias, on a macro invocation, or on a function or a constant without a body or a value. Acknowledge the suppression with `acknowledge_deny = true` and `reason = \"...\"`.",
+                lint_path
+            ),
+            Span::call_site(),
+        ));
+    }
+    let message = format!(
+        "Lint `{}` (alias `{}`) is deny-by-default: the code is most likely wrong. If you really mean to suppress it, acknowledge that with `acknowledge_deny = true` and `reason = \"...\"` (as configured with `require_acknowledge_deny = true` under `[package.metadata.allow]` in your Cargo.toml).",
+        lint_path, macro_name
+    );
+    Ok(Some(proc_builder::deprecated_notice(
+        "UnacknowledgedDeny",
+        &message,
+        span,
+    )))
+}
@@ -0,0 +1,38 @@
+//! Optional documentation of a suppression, like `#[alias(document = true, reason = "...")]`. Then
+//! the item's documentation says which lint is suppressed (linking to the lint's documentation), and
+//! why. That's for public API items that look unusual because of the suppression (for example,
+//! because of `missing_docs` or `clippy::too_many_arguments`): readers of the docs learn why.
+
+use proc_macro::{Span, TokenStream};
+
+use crate::params::{self, Params};
+use crate::proc_builder;
+
+/// If `params` have `document = true`, then append `#[doc = "..."]` (a paragraph) about the
+/// suppression of `lint_path` (linking to `lint_url`, if known) to the item's outer attributes.
+pub fn with_doc(
+    lint_path: &str,
+    lint_url: Option<&str>,
+    params: &Params,
+    item: TokenStream,
+    span: Span,
+) -> TokenStream {
+    if params.document != Some(true) {
+        return item;
+    }
+    let mut doc = match lint_url {
+        Some(lint_url) => format!("Suppresses lint [`{}`]({})", lint_path, lint_url),
+        None => format!("Suppresses lint `{}`", lint_path),
+    };
+    if let Some(cfg) = &params.cfg {
+        doc.push_str(&format!(" if `cfg({})`", cfg));
+    }
+    match &params.reason {
+        Some(reason) if !params::string_value(reason).trim().is_empty() => {
+            doc.push_str(": ");
+            doc.push_str(&params::string_value(reason));
+        }
+        _ => doc.push('.'),
+    }
+    proc_builder::append_doc_paragraph(item, &doc, span)
+}
@@ -0,0 +1,108 @@
+//! Expiring suppressions: `until_version = "..."` (compared to the version of the consumer crate)
+//! and `until_rust = "..."` (compared to the version of rustc).
+
+use proc_macro::{Span, TokenStream};
+
+use crate::params::{self, Params, ParseResult, Version};
+use crate::proc_builder;
+
+/// Check that `item` has a place for a notice of parameter `until_version` or `until_rust` (if
+/// any): See [`proc_builder::place_notice`]. That excludes type aliases, macro invocations, and
+/// functions and constants without a body or a value (which may be associated items in an `impl` or
+/// a `trait` block, where the notice couldn't be a sibling item).
+pub fn check_placeable(macro_name: &str, params: &Params, item: &TokenStream) -> ParseResult<()> {
+    let given = [
+        ("until_version", params.until_version.as_ref()),
+        ("until_rust", params.until_rust.as_ref()),
+    ];
+    for (name, param) in given.iter() {
+        if let Some((literal, _)) = param {
+            if proc_builder::place_notice(item.clone(), TokenStream::new()).is_none() {
+                return Err(params::error(
+                    macro_name,
+                    &format!(
+                        "Parameter `{}` is not supported on a type alias, on a macro invocation, or on a function or a constant without a body or a value: its notice would have no place there.",
+                        name
+                    ),
+                    literal.span(),
+                ));
+            }
+        }
+    }
+    Ok(())
+}
+
+/// Check parameter `until_version` (if any) against `CARGO_PKG_VERSION` of the crate being
+/// compiled. Cargo sets that for rustc, and hence for our proc macros, at expansion time. If the
+/// crate's version has reached `until_version`, return a notice (a `deprecated` warning), or, with
+/// cargo feature `strict_until_version`, an error. The caller places either inside the item (see
+/// [`proc_builder::with_notice`]).
+///
+/// Return `None` if there's nothing to report, or if we don't know the crate's version (when not
+/// built by cargo).
+pub fn until_version(
+    macro_name: &str,
+    lint_path: &str,
+    params: &Params,
+    span: Span,
+) -> Option<TokenStream> {
+    let (literal, until) = params.until_version.as_ref()?;
+    let crate_version = std::env::var("CARGO_PKG_VERSION").ok()?;
+    let current = Version::parse(&crate_version, true)?;
+    if current < *until {
+        return None;
+    }
+    let message = format!(
+        "Suppression of lint `{}` (alias `{}`) was meant until version {} of this crate, but the crate is at version {} now. Intent: {}. Remove the suppression (or extend its `until_version`).",
+        lint_path,
+        macro_name,
+        until,
+        crate_version,
+        intent(params)
+    );
+    Some(if cfg!(feature = "strict_until_version") {
+        proc_builder::compile_error(&message, literal.span())
+    } else {
+        proc_builder::deprecated_notice("ExpiredSuppression", &message, span)
+    })
+}
+
+/// Check parameter `until_rust` (if any) against the version of rustc (see `emit_rustc_version` in
+/// `build.rs`). If rustc has reached `until_rust`, return a notice (a `deprecated` warning) that the
+/// suppression (a workaround) can be removed. Then the caller doesn't inject the allow anymore, and
+/// it places the notice inside the item (see [`proc_builder::with_notice`]).
+///
+/// A nightly (or beta) of the same version is treated as older than `until_rust` (the fix may not be
+/// in that nightly yet).
+pub fn until_rust(
+    macro_name: &str,
+    lint_path: &str,
+    params: &Params,
+    span: Span,
+) -> Option<TokenStream> {
+    let (_, until) = params.until_rust.as_ref()?;
+    let current = Version::parse(env!("ALLOW_PREFIXED_RUSTC_VERSION"), true)?;
+    if current < *until {
+        return None;
+    }
+    let message = format!(
+        "Suppression of lint `{}` (alias `{}`) was a workaround until Rust {}, and this Rust is at that version or newer. So it doesn't suppress anymore. Intent: {}. Remove the suppression.",
+        lint_path,
+        macro_name,
+        until,
+        intent(params)
+    );
+    Some(proc_builder::deprecated_notice(
+        "ObsoleteSuppression",
+        &message,
+        span,
+    ))
+}
+
+/// The original intent of the suppression, for messages: the reason, if any.
+fn intent(params: &Params) -> String {
+    match &params.reason {
+        Some(reason) => format!("\"{}\"", params::string_value(reason)),
+        None => "(no reason given)".to_owned(),
+    }
+}
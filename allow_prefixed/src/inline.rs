@@ -0,0 +1,217 @@
+//! Suppression on a single expression or statement, like
+//! `expr!(clippy::cast_possible_truncation, x as u8)` or `stmt!(unused_must_use, { ... })`. Attribute
+//! macros can't be applied to expressions or statements on stable Rust, but `#[allow(...)]` itself
+//! can be applied to statements. So these function-like macros put the code in a statement (or a
+//! block) that carries `#[allow(lint_path)]`.
+//!
+//! The lint path may have parameters `cfg(predicate)`, `reason = "..."`, `acknowledge_deny = true`
+//! and metadata, like `expr!(clippy::cast_possible_truncation(reason = "..."), x as u8)`. They are
+//! checked against the consumer's configuration like those of an alias.
+
+use proc_macro::{Delimiter, Group, Punct, Spacing, Span, TokenStream, TokenTree};
+use std::rc::Rc;
+
+use crate::auxiliary;
+use crate::config::{self, Config};
+use crate::deny_by_default;
+use crate::lint_table;
+use crate::members;
+use crate::metadata;
+use crate::params::{self, Params, ParseResult};
+use crate::policy;
+use crate::proc_builder;
+
+/// The whole output of `expr!(lint_path, expression)`: `{ #[allow(lint_path)] (expression) }` (with
+/// [`proc_builder::includes`] of the configuration files, and any notice, first). The attribute applies to the
+/// block's tail expression, which (in parentheses) may be any expression on stable Rust.
+///
+/// The result is a block, hence a value rather than a place: `&expr!(lint_path, array[0])` would
+/// move out of the array, and `expr!(lint_path, x) = 1` is not an assignment. Put the borrow inside
+/// (`expr!(lint_path, &array[0])`), and use `stmt!` for assignments. Temporaries of `expression`
+/// live as long as those of a block's tail expression (until the end of the enclosing statement,
+/// or, since edition 2024, until the end of the block).
+pub fn expr_output(input: TokenStream) -> TokenStream {
+    output_or_error(
+        parse("expr", "clippy::cast_possible_truncation, x as u8", input).map(
+            |(includes, allow, expression)| {
+                let block = auxiliary::token_streams_to_stream(&[
+                    includes,
+                    allow,
+                    TokenStream::from(TokenTree::Group(Group::new(
+                        Delimiter::Parenthesis,
+                        expression,
+                    ))),
+                ]);
+                TokenStream::from(TokenTree::Group(Group::new(Delimiter::Brace, block)))
+            },
+        ),
+    )
+}
+
+/// The whole output of `stmt!(lint_path, code)`. If `code` is one `let` statement, then that
+/// statement carries `#[allow(lint_path)]` (so that its bindings stay in scope). Otherwise
+/// `#[allow(lint_path)] { code }`. Either is followed by [`proc_builder::includes`] of the
+/// configuration files, and any notice.
+pub fn stmt_output(input: TokenStream) -> TokenStream {
+    output_or_error(parse("stmt", "unused_must_use, { ... }", input).map(
+        |(includes, allow, code)| {
+            let tokens: Vec<TokenTree> = code.clone().into_iter().collect();
+            let is_let = match tokens.first() {
+                Some(TokenTree::Ident(ident)) => ident.to_string() == "let",
+                _ => false,
+            };
+            let semicolons: Vec<usize> = tokens
+                .iter()
+                .enumerate()
+                .filter(
+                    |(_, token)| matches!(token, TokenTree::Punct(punct) if punct.as_char() == ';'),
+                )
+                .map(|(index, _)| index)
+                .collect();
+            let statement = match semicolons.as_slice() {
+                [] if is_let => auxiliary::token_streams_to_stream(&[
+                    allow,
+                    code,
+                    TokenStream::from(TokenTree::Punct(Punct::new(';', Spacing::Alone))),
+                ]),
+                [last] if is_let && *last == tokens.len() - 1 => {
+                    auxiliary::token_streams_to_stream(&[allow, code])
+                }
+                _ => auxiliary::token_streams_to_stream(&[
+                    allow,
+                    TokenStream::from(TokenTree::Group(Group::new(Delimiter::Brace, code))),
+                ]),
+            };
+            auxiliary::token_streams_to_stream(&[statement, includes])
+        },
+    ))
+}
+
+/// Return the output, or the error in braces (so that it's valid both as an expression, and as a
+/// statement). The error's `compile_error!(...)` is the block's tail expression (without the
+/// trailing semicolon), so that it doesn't cause a type mismatch, too.
+fn output_or_error(result: ParseResult<TokenStream>) -> TokenStream {
+    match result {
+        Ok(output) => output,
+        Err(error) => {
+            let mut tokens: Vec<TokenTree> = error.into_iter().collect();
+            tokens.pop();
+            TokenStream::from(TokenTree::Group(Group::new(
+                Delimiter::Brace,
+                auxiliary::token_trees_to_stream(&tokens),
+            )))
+        }
+    }
+}
+
+/// Parse `lint_path, code` or `lint_path(parameters), code`. Return [`proc_builder::includes`] of
+/// the configuration files followed by the notice about an unacknowledged deny-by-default lint (if
+/// any - see [`deny_by_default::acknowledge_deny`]), `#[allow(lint_path)]` (or its variations - see
+/// [`proc_builder::brackets_allow_tool_lint_params_parts`]), and `code`.
+fn parse(
+    macro_name: &str,
+    example: &str,
+    input: TokenStream,
+) -> ParseResult<(TokenStream, TokenStream, TokenStream)> {
+    let error = |message: &str, span: Span| {
+        proc_builder::compile_error(
+            &format!(
+                "Macro `{}` (under whatever name you imported it): {}",
+                macro_name, message
+            ),
+            span,
+        )
+    };
+    let malformed = || {
+        error(
+            &format!(
+                "Expecting `{}!(lint_path, code)`, like `{}!({})`.",
+                macro_name, macro_name, example
+            ),
+            Span::call_site(),
+        )
+    };
+    let config = match config::load(Span::call_site()) {
+        Ok(config) => config,
+        // Malformed, and reported already (see `config::load`). An empty block (or an item as-is,
+        // as the attribute macros return then) is no fallback for an expression.
+        Err(error) if error.is_empty() => Rc::new(Config::default()),
+        Err(error) => return Err(error),
+    };
+    let tokens: Vec<TokenTree> = input.into_iter().collect();
+    let (prefix, lint, rest) = match params::split_lint_path(&tokens) {
+        Some(lint_path) => lint_path,
+        None => return Err(malformed()),
+    };
+    let (given_params, rest) = match rest {
+        [TokenTree::Group(group), rest @ ..] if group.delimiter() == Delimiter::Parenthesis => {
+            (group.stream(), rest)
+        }
+        _ => (TokenStream::new(), rest),
+    };
+    let code = match rest {
+        [TokenTree::Punct(comma), code @ ..] if comma.as_char() == ',' && !code.is_empty() => {
+            auxiliary::token_trees_to_stream(code)
+        }
+        _ => return Err(malformed()),
+    };
+    let params = parse_params(macro_name, given_params, &config)?;
+    let lint_path = members::lint_path(&prefix, lint);
+    policy::check(macro_name, &lint_path, tokens[0].span())?;
+    params::check_reason(macro_name, &params, &config)?;
+    metadata::check_required(macro_name, &lint_path, &params, &config)?;
+    let span = proc_builder::consumer_span(&code);
+    // The notice goes into the block (or after the statement), like the includes.
+    let unacknowledged = deny_by_default::acknowledge_deny(
+        macro_name,
+        &lint_path,
+        lint_table::is_deny_by_default(&lint_path),
+        &params,
+        &config,
+        true,
+        span,
+    )?;
+    let allow = auxiliary::token_streams_to_stream(&[
+        proc_builder::get_hash(span),
+        proc_builder::brackets_allow_tool_lint_params_parts(
+            &prefix,
+            &lint.to_string(),
+            &params,
+            span,
+        ),
+    ]);
+    let items = auxiliary::token_streams_to_stream(&[
+        proc_builder::includes(config.track(), span),
+        unacknowledged.unwrap_or_default(),
+    ]);
+    Ok((items, allow, code))
+}
+
+/// Parse the parameters of the lint path. Only `cfg(predicate)`, `reason = "..."`,
+/// `acknowledge_deny = true` and metadata (with keys from `config`) apply to an expression or a
+/// statement.
+fn parse_params(
+    macro_name: &str,
+    given_params: TokenStream,
+    config: &Config,
+) -> ParseResult<Params> {
+    for param in params::split_by_commas(given_params.clone()) {
+        match param.first() {
+            Some(TokenTree::Ident(name))
+                if ["cfg", "reason", "acknowledge_deny"].contains(&name.to_string().as_str())
+                    || config.metadata_keys.contains(&name.to_string()) => {}
+            Some(other) => {
+                return Err(params::error(
+                    macro_name,
+                    &format!(
+                        "Unknown parameter `{}`. Expecting `cfg(predicate)`, `reason = \"...\"`, `acknowledge_deny = true`, or metadata with a key listed in `metadata` under `[package.metadata.allow]` in your Cargo.toml.",
+                        other
+                    ),
+                    other.span(),
+                ))
+            }
+            None => {}
+        }
+    }
+    params::parse(macro_name, given_params, config)
+}
@@ -0,0 +1,194 @@
+//! Intents configured by the consumer crate, like `#[intent(ffi_boundary)]`. Each intent is a named
+//! set of lints (see [`config::Intent`]), declared under `[package.metadata.allow.intents.<name>]`
+//! in `Cargo.toml` (or under `[intents.<name>]` in `allow.toml`). So a team gets composite aliases
+//! without a proc macro crate of its own.
+
+use proc_macro::{Literal, Span, TokenStream, TokenTree};
+
+use crate::auxiliary;
+use crate::config::{self, Config, Intent};
+use crate::deny_by_default;
+use crate::document;
+use crate::expiry;
+use crate::lint_table;
+use crate::members;
+use crate::metadata;
+use crate::params::{self, Params};
+use crate::policy;
+use crate::proc_builder;
+
+/// The whole output of `#[intent(name, params...)]`: `#[allow(lint_path)]` for each lint of intent
+/// `name` (with the same parameters as the aliases accept), followed by `item` (with any notices,
+/// like about unacknowledged deny-by-default lints - see [`deny_by_default::acknowledge_deny`]). If
+/// the intent is not configured, or the parameters are malformed, return an error, followed by the
+/// item as-is.
+pub fn intent_output(given_attrs: TokenStream, item: TokenStream) -> TokenStream {
+    let config = match config::load(Span::call_site()) {
+        Ok(config) => config,
+        Err(error) => return auxiliary::token_streams_to_stream(&[error, item]),
+    };
+    match output(&config, given_attrs, item.clone()) {
+        Ok(output) => output,
+        Err(error) => auxiliary::token_streams_to_stream(&[error, item]),
+    }
+}
+
+fn output(
+    config: &Config,
+    given_attrs: TokenStream,
+    item: TokenStream,
+) -> params::ParseResult<TokenStream> {
+    let tokens: Vec<TokenTree> = given_attrs.into_iter().collect();
+    let (name, given_params) = match tokens.as_slice() {
+        [TokenTree::Ident(name)] => (name, TokenStream::new()),
+        [TokenTree::Ident(name), TokenTree::Punct(comma), rest @ ..] if comma.as_char() == ',' => {
+            (name, auxiliary::token_trees_to_stream(rest))
+        }
+        _ => {
+            return Err(members::error(
+                "intent",
+                "Expecting the intent's name, optionally followed by parameters, like `intent(ffi_boundary, reason = \"...\")`.",
+                Span::call_site(),
+            ))
+        }
+    };
+    let intent = config.intent(&name.to_string()).ok_or_else(|| {
+        members::error(
+            "intent",
+            &unknown_intent(config, &name.to_string()),
+            name.span(),
+        )
+    })?;
+    let mut params = params::parse("intent", given_params, config)?;
+    check_reason(intent, &params, config)?;
+    // The description serves as the reason, unless the use gives its own.
+    if params.reason.is_none() {
+        if let Some(description) = &intent.description {
+            params.reason = Some(Literal::string(description));
+        }
+    }
+    for lint_path in &intent.lints {
+        policy::check("intent", lint_path, name.span())?;
+        metadata::check_required("intent", lint_path, &params, config)?;
+    }
+    expiry::check_placeable("intent", &params, &item)?;
+
+    let span = proc_builder::consumer_span(&item);
+    // Like for `members`: `acknowledge_deny` applies to all the lints, so it's enough that one of
+    // them is deny-by-default.
+    let any_deny_by_default = intent
+        .lints
+        .iter()
+        .any(|lint_path| lint_table::is_deny_by_default(lint_path));
+    let has_place = proc_builder::has_notice_place(&item);
+    let mut notices = Vec::new();
+    for lint_path in &intent.lints {
+        let deny_by_default = lint_table::is_deny_by_default(lint_path);
+        if deny_by_default || !any_deny_by_default {
+            notices.extend(deny_by_default::acknowledge_deny(
+                "intent",
+                lint_path,
+                deny_by_default,
+                &params,
+                config,
+                has_place,
+                span,
+            )?);
+        }
+    }
+    let item = proc_builder::track_files(item, config, span);
+    let item = if notices.is_empty() {
+        item
+    } else {
+        proc_builder::with_notice(item, Some(auxiliary::token_streams_to_stream(&notices)))
+    };
+    // For messages and documentation that are about the whole intent.
+    let lint_paths = intent.lints.join("`, `");
+    let item = proc_builder::with_notice(
+        item,
+        expiry::until_version("intent", &lint_paths, &params, span),
+    );
+    if let Some(obsolete) = expiry::until_rust("intent", &lint_paths, &params, span) {
+        return Ok(proc_builder::with_notice(item, Some(obsolete)));
+    }
+    let mut item = metadata::with_doc(&lint_paths, &params, item, span);
+    let mut allows = Vec::new();
+    for lint_path in &intent.lints {
+        // The configuration has only lint paths, so we don't link to the lints' documentation.
+        item = document::with_doc(lint_path, None, &params, item, span);
+        let (prefix, lint) = match lint_path.find("::") {
+            Some(colons) => (&lint_path[..colons], &lint_path[colons + 2..]),
+            None => ("", &lint_path[..]),
+        };
+        allows.push(proc_builder::get_hash(span));
+        allows.push(proc_builder::brackets_allow_tool_lint_params_parts(
+            prefix, lint, &params, span,
+        ));
+    }
+    allows.push(item);
+    Ok(auxiliary::token_streams_to_stream(&allows))
+}
+
+/// Check the reason in `params`. If `intent` has `require_reason = true`, then the use has to give
+/// a non-empty reason of its own. Otherwise, if the crate has `require_reason = true` (see
+/// [`params::check_reason`]), then the intent's non-empty description satisfies it, too (since it
+/// serves as the reason), unless the use gives a reason of its own.
+fn check_reason(intent: &Intent, params: &Params, config: &Config) -> params::ParseResult<()> {
+    let configured = if intent.require_reason {
+        format!("for intent `{}`", intent.name)
+    } else if config.require_reason {
+        "under `[package.metadata.allow]` in your Cargo.toml".to_owned()
+    } else {
+        return Ok(());
+    };
+    let described = intent
+        .description
+        .as_ref()
+        .map_or(false, |description| !description.trim().is_empty());
+    match &params.reason {
+        Some(reason) if !params::string_value(reason).trim().is_empty() => Ok(()),
+        Some(reason) => Err(members::error(
+            "intent",
+            &format!(
+                "Parameter `reason` must not be empty (as configured with `require_reason = true` {}).",
+                configured
+            ),
+            reason.span(),
+        )),
+        None if described && !intent.require_reason => Ok(()),
+        None if intent.require_reason => Err(members::error(
+            "intent",
+            &format!(
+                "Expecting `reason = \"...\"` (as configured with `require_reason = true` {}).",
+                configured
+            ),
+            Span::call_site(),
+        )),
+        None => Err(members::error(
+            "intent",
+            &format!(
+                "Expecting `reason = \"...\"` (as configured with `require_reason = true` {}), or a `description` of intent `{}` (which serves as the reason).",
+                configured, intent.name
+            ),
+            Span::call_site(),
+        )),
+    }
+}
+
+fn unknown_intent(config: &Config, name: &str) -> String {
+    let mut message = format!("Unknown intent `{}`. ", name);
+    if config.intents.is_empty() {
+        message.push_str("There are no intents configured.");
+    } else {
+        let names: Vec<&str> = config
+            .intents
+            .iter()
+            .map(|intent| intent.name.as_str())
+            .collect();
+        message.push_str(&format!("Configured intents: `{}`.", names.join("`, `")));
+    }
+    message.push_str(
+        " Configure intents under `[package.metadata.allow.intents.<name>]` in your Cargo.toml, or under `[intents.<name>]` in allow.toml next to it.",
+    );
+    message
+}
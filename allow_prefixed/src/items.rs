@@ -0,0 +1,140 @@
+//! Applying attributes (aliases) to a group of items, like
+//! `items! { #[allow_prefixed::dead_code] fn a() {} fn b() {} }`, without creating a module. We
+//! split the items at the top level of the macro's input (without `syn`), and we prepend the
+//! attributes to each of them.
+
+use proc_macro::{Delimiter, Span, TokenStream, TokenTree};
+
+use crate::auxiliary;
+use crate::members::{angle_depth_change, skip_attributes_and_visibility};
+use crate::params::ParseResult;
+use crate::proc_builder;
+
+/// The whole output of `items! { #[alias]... item... }`. The leading attributes (optionally
+/// followed by a semicolon, so that the first item can have attributes of its own) apply to every
+/// item, except for macro invocations (including `macro_rules!` definitions): rustc ignores lint
+/// attributes on those.
+pub fn items_output(input: TokenStream) -> TokenStream {
+    match split(input) {
+        Ok((attributes, items)) => {
+            let mut output = Vec::with_capacity(items.len() * 2);
+            for item in items {
+                if !is_macro_invocation(&item) {
+                    output.push(attributes.clone());
+                }
+                output.push(auxiliary::token_trees_to_stream(&item));
+            }
+            auxiliary::token_streams_to_stream(&output)
+        }
+        Err(error) => error,
+    }
+}
+
+fn error(message: &str, span: Span) -> TokenStream {
+    proc_builder::compile_error(
+        &format!(
+            "Macro `items` (under whatever name you imported it): {}",
+            message
+        ),
+        span,
+    )
+}
+
+/// Split `input` into the leading attributes, and the items.
+fn split(input: TokenStream) -> ParseResult<(TokenStream, Vec<Vec<TokenTree>>)> {
+    let tokens: Vec<TokenTree> = input.into_iter().collect();
+    let mut index = 0;
+    while let [TokenTree::Punct(hash), TokenTree::Group(group), ..] = &tokens[index..] {
+        if hash.as_char() != '#' || group.delimiter() != Delimiter::Bracket {
+            break;
+        }
+        index += 2;
+    }
+    if index == 0 {
+        return Err(error(
+            "Expecting attributes to apply to the items, like `items! { #[allow::unsafe_code] fn a() {} fn b() {} }`.",
+            tokens.first().map_or_else(Span::call_site, TokenTree::span),
+        ));
+    }
+    let attributes = auxiliary::token_trees_to_stream(&tokens[..index]);
+    if let Some(TokenTree::Punct(semicolon)) = tokens.get(index) {
+        if semicolon.as_char() == ';' {
+            index += 1;
+        }
+    }
+    let mut items = Vec::new();
+    while index < tokens.len() {
+        let end = item_end(&tokens[index..]);
+        items.push(tokens[index..index + end].to_vec());
+        index += end;
+    }
+    Ok((attributes, items))
+}
+
+/// The number of tokens of the first item in `tokens`. An item ends with a semicolon at the top
+/// level (outside of angle brackets), or with a brace group (like a function body, or a body of an
+/// `impl` block). However, `const`, `static`, `type` and `use` items end with a semicolon only (since
+/// their braces, like in `const S: Struct = Struct { field: 1 };`, don't end the item).
+pub fn item_end(tokens: &[TokenTree]) -> usize {
+    let start = skip_attributes_and_visibility(tokens);
+    let keyword = match tokens.get(start) {
+        Some(TokenTree::Ident(keyword)) => keyword.to_string(),
+        _ => String::new(),
+    };
+    let semicolon_only = match keyword.as_str() {
+        "static" | "type" | "use" => true,
+        // Not `const fn`, `const unsafe fn`...
+        "const" => match tokens.get(start + 1) {
+            Some(TokenTree::Ident(next)) => {
+                let next = next.to_string();
+                next != "fn" && next != "unsafe" && next != "async" && next != "extern"
+            }
+            _ => true,
+        },
+        _ => false,
+    };
+    let mut depth = 0;
+    for index in start..tokens.len() {
+        let token = &tokens[index];
+        // The expression of a `const` or a `static` may contain `<` (less than), hence we don't
+        // count angle brackets there.
+        if !semicolon_only {
+            depth += angle_depth_change(index.checked_sub(1).map(|i| &tokens[i]), token);
+            if depth > 0 {
+                continue;
+            }
+        }
+        match token {
+            TokenTree::Punct(punct) if punct.as_char() == ';' => return index + 1,
+            TokenTree::Group(group) if group.delimiter() == Delimiter::Brace && !semicolon_only => {
+                // Like `foo! { ... };`.
+                return match tokens.get(index + 1) {
+                    Some(TokenTree::Punct(punct)) if punct.as_char() == ';' => index + 2,
+                    _ => index + 1,
+                };
+            }
+            _ => {}
+        }
+    }
+    tokens.len()
+}
+
+/// Whether `item` is a macro invocation (like `foo!(...);`, `path::foo! { ... }` or `macro_rules!
+/// name { ... }`).
+fn is_macro_invocation(item: &[TokenTree]) -> bool {
+    let mut index = skip_attributes_and_visibility(item);
+    loop {
+        match &item[index..] {
+            [TokenTree::Punct(colon), TokenTree::Punct(_), ..] if colon.as_char() == ':' => {
+                index += 2
+            }
+            [TokenTree::Ident(_), TokenTree::Punct(bang), ..] if bang.as_char() == '!' => {
+                return true
+            }
+            [TokenTree::Ident(_), TokenTree::Punct(colon), ..] if colon.as_char() == ':' => {
+                index += 1
+            }
+            _ => return false,
+        }
+    }
+}
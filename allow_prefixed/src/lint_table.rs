@@ -0,0 +1,116 @@
+//! The lints that this crate knows (regardless of Rust version), and the names of their aliases
+//! (the generated macros) if any - with the enabled cargo features. Generated by ../build.rs from the
+//! lint declarations in lints.rs.
+//! That includes the lint groups (like `unused` or `clippy::pedantic`), and `warnings`.
+//!
+//! We know only `clippy::` and `rustdoc::` tools. Lints of other tools (see [`tools`](crate::tools))
+//! are not in the table.
+
+/// Triples of (lint path, alias name, whether deny-by-default), like
+/// `("clippy::unwrap_used", Some("clippy_unwrap_used"), false)` or
+/// `("clippy::pedantic", Some("clippy_pedantic"), false)`. The alias is `None` if the enabled cargo
+/// features don't generate it (like `clippy_restriction` feature for `clippy_unwrap_used`).
+const LINTS: &[(&str, Option<&str>, bool)] = include!(concat!(env!("OUT_DIR"), "/lint_table.rs"));
+
+/// Pairs of (lint path of a lint group that we know, lint paths of its members), like
+/// `("unused", &["dead_code", ...])` or `("clippy::pedantic", &["clippy::bool_to_int_with_if", ...])`.
+/// The members of `clippy::all` are the members of its groups.
+const GROUPS: &[(&str, &[&str])] = include!(concat!(env!("OUT_DIR"), "/lint_groups.rs"));
+
+/// Whether `lint_path` (like `unsafe_code`, `clippy::unwrap_used` or `clippy::pedantic`) is a lint
+/// (or a lint group) that we know, or a lint of another tool (which we can't check).
+pub fn is_known(lint_path: &str) -> bool {
+    match lint_path.find("::") {
+        Some(colons) if !matches!(&lint_path[..colons], "clippy" | "rustdoc") => true,
+        _ => LINTS.iter().any(|(lint, _, _)| *lint == lint_path),
+    }
+}
+
+/// The alias (the name of the generated macro) for `lint_path`, if any.
+pub fn alias(lint_path: &str) -> Option<&'static str> {
+    LINTS
+        .iter()
+        .find(|(lint, _, _)| *lint == lint_path)
+        .and_then(|(_, alias, _)| *alias)
+}
+
+/// Whether `lint_path` is a lint that we know to be deny-by-default (see
+/// [`deny_by_default`](crate::deny_by_default)).
+pub fn is_deny_by_default(lint_path: &str) -> bool {
+    LINTS
+        .iter()
+        .any(|(lint, _, deny_by_default)| *lint == lint_path && *deny_by_default)
+}
+
+/// Whether `lint_path` (like `unused` or `clippy::pedantic`) is a lint group that we know.
+pub fn is_group(lint_path: &str) -> bool {
+    GROUPS.iter().any(|(group, _)| *group == lint_path)
+}
+
+/// The lint paths of the members of `lint_path` if it's a lint group that we know. Otherwise empty.
+pub fn group_members(lint_path: &str) -> &'static [&'static str] {
+    GROUPS
+        .iter()
+        .find(|(group, _)| *group == lint_path)
+        .map_or(&[], |(_, members)| members)
+}
+
+/// Whether `name` is an alias (a generated macro) for one of the lints that we know.
+pub fn is_alias(name: &str) -> bool {
+    LINTS.iter().any(|(_, alias, _)| *alias == Some(name))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{alias, group_members, is_alias, is_deny_by_default, is_group, is_known};
+
+    #[test]
+    fn known_lints() {
+        assert!(is_known("dead_code"));
+        assert!(is_known("clippy::absurd_extreme_comparisons"));
+        assert!(is_known("our_lints::anything"));
+        assert!(is_known("unused"));
+        assert!(is_known("clippy::pedantic"));
+        assert!(is_known("warnings"));
+        assert!(!is_known("dead_cod"));
+        assert!(!is_known("clippy::unwrap_usd"));
+    }
+
+    #[test]
+    fn aliases() {
+        assert_eq!(alias("dead_code"), Some("dead_code"));
+        assert_eq!(alias("clippy::unwrap_used"), Some("clippy_unwrap_used"));
+        assert_eq!(alias("dead_cod"), None);
+        // Group aliases need cargo feature `groups`.
+        let with_groups = |alias| Some(alias).filter(|_| cfg!(feature = "groups"));
+        assert_eq!(alias("clippy::pedantic"), with_groups("clippy_pedantic"));
+        assert_eq!(alias("clippy::all"), with_groups("clippy_all"));
+        assert_eq!(alias("warnings"), None);
+        assert!(is_alias("clippy_unwrap_used"));
+        assert!(!is_alias("clippy::unwrap_used"));
+    }
+
+    #[test]
+    fn deny_by_default() {
+        assert!(is_deny_by_default("arithmetic_overflow"));
+        assert!(is_deny_by_default("clippy::eq_op"));
+        assert!(!is_deny_by_default("dead_code"));
+        assert!(!is_deny_by_default("clippy::unwrap_used"));
+    }
+
+    #[test]
+    fn groups() {
+        assert!(is_group("unused"));
+        assert!(is_group("clippy::pedantic"));
+        assert!(is_group("clippy::all"));
+        assert!(!is_group("dead_code"));
+        assert!(!is_group("warnings"));
+
+        assert!(group_members("unused").contains(&"unused_variables"));
+        assert!(group_members("clippy::pedantic").contains(&"clippy::doc_markdown"));
+        assert!(group_members("clippy::all").contains(&"clippy::eq_op"));
+        assert!(!group_members("clippy::all").contains(&"clippy::doc_markdown"));
+        assert!(group_members("dead_code").is_empty());
+        assert!(!is_group("clippy::unwrap_used"));
+    }
+}
@@ -0,0 +1,1262 @@
+// The declarations of the lints that this crate knows, of the lint groups, and of the tool
+// attributes (other than lints). Each declaration is a macro invocation, like
+// `standard_lint_allowed!(unsafe_code);` or `prefixed_lint!(clippy, eq_op, [correctness]);`.
+//
+// This file is the single list of them: src/lib.rs includes it (so the macros generate the aliases),
+// and ../build.rs reads it (so it generates the table of the lints, their aliases and the members of
+// the groups - see `emit_lint_table` there). build.rs reads it as tokens, like rustc does: comments,
+// whitespace and line breaks don't matter, but each declaration has to be a plain macro invocation
+// (optionally with attributes like `#[cfg(...)]`) at the top level of this file.
+
+// Tool attributes other than lints: Clippy's configuration attributes. (`rustfmt_skip` in src/lib.rs
+// is no alias - see there.) Before the Clippy version that introduced an attribute, its alias passes
+// the code through as-is (Clippy would reject an unknown `#[clippy::...]` attribute). `#[clippy::cognitive_complexity = "..."]` exists since 1.35 (older than our MSRV).
+#[cfg(feature = "clippy")]
+generate_tool_attribute_macro!(
+    clippy,
+    cognitive_complexity,
+    clippy_cognitive_complexity,
+    String,
+    false,
+    "Alias to `#[clippy::cognitive_complexity = \"...\"]`, like \
+     `#[clippy_cognitive_complexity(\"30\")]`. Import it under a name of your intention."
+);
+#[rustversion::since(1.50)]
+#[cfg(feature = "clippy")]
+generate_tool_attribute_macro!(
+    clippy,
+    msrv,
+    clippy_msrv,
+    String,
+    false,
+    "Alias to `#[clippy::msrv = \"...\"]`, like `#[clippy_msrv(\"1.45\")]`. Import it under a \
+     name of your intention."
+);
+#[rustversion::before(1.50)]
+#[cfg(feature = "clippy")]
+generate_tool_attribute_macro!(
+    clippy,
+    msrv,
+    clippy_msrv,
+    String,
+    true,
+    "Alias to `#[clippy::msrv = \"...\"]`. It passes the code through as-is, because Clippy of \
+     this Rust version doesn't have that attribute."
+);
+#[rustversion::since(1.63)]
+#[cfg(feature = "clippy")]
+generate_tool_attribute_macro!(
+    clippy,
+    has_significant_drop,
+    clippy_has_significant_drop,
+    None,
+    false,
+    "Alias to `#[clippy::has_significant_drop]`. Import it under a name of your intention."
+);
+#[rustversion::before(1.63)]
+#[cfg(feature = "clippy")]
+generate_tool_attribute_macro!(
+    clippy,
+    has_significant_drop,
+    clippy_has_significant_drop,
+    None,
+    true,
+    "Alias to `#[clippy::has_significant_drop]`. It passes the code through as-is, because Clippy \
+     of this Rust version doesn't have that attribute."
+);
+
+
+// @TODO test that e.g. non_existing_std_lint fails TODO compile test that the following fails
+// standard_lint!(wrong_lint);
+
+// MAINTENANCE NOTES
+//
+// 1. When you edit/add comments below, if you have two (or more) successive comments about
+//    different lints, either insert a blank line between those comments, or a line with an empty
+//    `//` comment. That allows us to reformat all comments in VS Code withCtrl+A Alt+Q using
+//    https://marketplace.visualstudio.com/items?itemName=stkb.rewrap.
+
+// You can see supported standard (rustc, prefixless) lints by running `rustc -W help`. Beware that
+// then you need to replace dashes `-` with underscores `_` to get the lint names.
+
+// 1. Based on https://doc.rust-lang.org/nightly/rustc/lints/listing/allowed-by-default.html
+
+// absolute_paths_not_starting_with_crate was in edition 2015 only (and we require 2018+). No alias,
+// but it's a member of a group.
+standard_lint!(absolute_paths_not_starting_with_crate, [rust_2018_compatibility]);
+
+//any!(rustc, box_pointers, allowed, _, 1.45);
+standard_lint_allowed_from_to!(box_pointers, 1.45, 1.81);
+
+standard_lint_allowed!(elided_lifetimes_in_paths, [rust_2018_idioms]);
+standard_lint_allowed!(explicit_outlives_requirements, [rust_2018_idioms]);
+standard_lint_allowed_nightly!(ffi_unwind_calls);
+standard_lint_allowed_nightly!(fuzzy_provenance_casts);
+standard_lint_allowed!(keyword_idents);
+standard_lint!(keyword_idents_2018, [rust_2018_compatibility]);
+standard_lint_allowed!(let_underscore_drop, [let_underscore]);
+standard_lint_nightly!(lossy_provenance_casts);
+standard_lint!(macro_use_extern_crate);
+standard_lint!(meta_variable_misuse);
+standard_lint!(missing_abi);
+standard_lint!(missing_copy_implementations);
+standard_lint!(missing_debug_implementations);
+standard_lint_allowed!(missing_docs);
+standard_lint_nightly!(multiple_supertrait_upcastable);
+standard_lint_nightly!(must_not_suspend);
+standard_lint_crate_level!(non_ascii_idents);
+standard_lint_nightly!(non_exhaustive_omitted_patterns);
+standard_lint_allowed!(noop_method_call);
+standard_lint_allowed_from_to!(pointer_structural_match, 1.45, 1.80);
+standard_lint_allowed!(rust_2021_incompatible_closure_captures);
+standard_lint_allowed!(rust_2021_incompatible_or_patterns);
+standard_lint_crate_level!(rust_2021_prefixes_incompatible_syntax);
+standard_lint_allowed!(rust_2021_prelude_collisions);
+standard_lint_allowed!(single_use_lifetimes);
+standard_lint_allowed!(trivial_casts);
+standard_lint_allowed!(trivial_numeric_casts);
+standard_lint_allowed!(unreachable_pub);
+standard_lint_allowed!(unsafe_code);
+
+standard_lint_allowed_from!(unsafe_op_in_unsafe_fn, 1.52); // According to https://github.com/rust-lang/rust/pull/79208 it was stabilized in 1.52.0
+
+// Audited variants (requiring `safety = "..."`) of the above two.
+#[cfg(feature = "rustc")]
+generate_audited_allow_attribute_macro_standard!(
+    unsafe_code,
+    audited_unsafe_code,
+    false,
+    "https://doc.rust-lang.org/nightly/rustc/lints/listing/allowed-by-default.html#unsafe-code"
+);
+#[rustversion::since(1.52)]
+#[cfg(feature = "rustc")]
+generate_audited_allow_attribute_macro_standard!(
+    unsafe_op_in_unsafe_fn,
+    audited_unsafe_op_in_unsafe_fn,
+    false,
+    "https://doc.rust-lang.org/nightly/rustc/lints/listing/allowed-by-default.html#unsafe-op-in-unsafe-fn"
+);
+#[rustversion::before(1.52)]
+#[cfg(feature = "rustc")]
+generate_audited_allow_attribute_macro_standard!(
+    unsafe_op_in_unsafe_fn,
+    audited_unsafe_op_in_unsafe_fn,
+    true,
+    "https://doc.rust-lang.org/nightly/rustc/lints/listing/allowed-by-default.html#unsafe-op-in-unsafe-fn"
+);
+
+// unstable_features - deprecated
+//
+standard_lint_crate_level!(unused_crate_dependencies);
+standard_lint_allowed!(unused_extern_crates, [rust_2018_idioms, unused]);
+standard_lint_allowed!(unused_import_braces);
+standard_lint_allowed!(unused_lifetimes);
+standard_lint_allowed!(unused_macro_rules, [unused]);
+standard_lint_allowed!(unused_qualifications);
+standard_lint_allowed!(unused_results);
+standard_lint_allowed_from_to!(unused_tuple_struct_fields, 1.45, 1.77);
+standard_lint_allowed!(variant_size_differences);
+
+// 2. Based on https://doc.rust-lang.org/nightly/rustc/lints/listing/warn-by-default.html
+standard_lint_nightly!(ambiguous_glob_reexports);
+standard_lint!(anonymous_parameters, [rust_2018_compatibility]);
+standard_lint!(array_into_iter);
+standard_lint!(asm_sub_register);
+standard_lint!(bad_asm_style);
+standard_lint!(bare_trait_objects, [rust_2018_idioms]);
+standard_lint!(break_with_label_and_loop);
+standard_lint!(byte_slice_in_packed_struct_with_derive);
+standard_lint!(clashing_extern_declarations);
+standard_lint!(coherence_leak_check);
+standard_lint_crate_level!(confusable_idents);
+standard_lint!(const_evaluatable_unchecked);
+standard_lint!(const_item_mutation);
+standard_lint_warn!(dead_code, [unused]);
+standard_lint_warn!(deprecated);
+standard_lint!(deprecated_where_clause_location);
+standard_lint!(deref_into_dyn_supertrait);
+standard_lint!(deref_nullptr);
+standard_lint!(drop_bounds);
+standard_lint!(dropping_copy_types); //@TODO nightly?
+standard_lint!(dropping_references); //@TODO nightly?
+standard_lint!(duplicate_macro_attributes);
+standard_lint!(dyn_drop);
+standard_lint!(ellipsis_inclusive_range_patterns, [rust_2018_idioms]);
+standard_lint!(exported_private_dependencies);
+standard_lint!(for_loops_over_fallibles);
+standard_lint!(forbidden_lint_groups);
+standard_lint!(forgetting_copy_types); //@TODO nightly?
+standard_lint!(forgetting_references); //@TODO nightly?
+standard_lint!(function_item_references);
+standard_lint!(illegal_floating_point_literal_pattern);
+standard_lint_warn!(improper_ctypes);
+standard_lint_warn_from!(improper_ctypes_definitions, 1.46);
+standard_lint!(incomplete_features);
+standard_lint!(indirect_structural_match);
+standard_lint!(inline_no_sanitize);
+standard_lint!(invalid_doc_attributes);
+standard_lint_nightly!(invalid_macro_export_arguments);
+standard_lint!(invalid_value);
+standard_lint!(irrefutable_let_patterns);
+standard_lint!(large_assignments);
+standard_lint!(late_bound_lifetime_arguments);
+standard_lint!(legacy_derive_helpers);
+standard_lint!(map_unit_fn, [unused]);
+standard_lint_crate_level!(mixed_script_confusables);
+standard_lint!(named_arguments_used_positionally);
+standard_lint!(no_mangle_generic_items);
+standard_lint_warn!(non_camel_case_types, [nonstandard_style]);
+standard_lint!(non_fmt_panics);
+standard_lint!(non_shorthand_field_patterns);
+standard_lint_warn!(non_snake_case, [nonstandard_style]);
+standard_lint_warn!(non_upper_case_globals, [nonstandard_style]);
+standard_lint!(nontrivial_structural_match);
+standard_lint!(opaque_hidden_inferred_bound);
+standard_lint!(overlapping_range_endpoints);
+standard_lint!(path_statements, [unused]);
+standard_lint!(private_in_public);
+standard_lint!(redundant_semicolons, [unused]);
+standard_lint!(renamed_and_removed_lints);
+standard_lint!(repr_transparent_external_private_fields);
+standard_lint!(semicolon_in_expressions_from_macros);
+standard_lint!(special_module_name);
+standard_lint!(stable_features);
+standard_lint!(suspicious_auto_trait_impls);
+standard_lint_nightly!(suspicious_double_ref_op);
+standard_lint!(temporary_cstring_as_ptr);
+standard_lint!(trivial_bounds);
+standard_lint!(type_alias_bounds);
+standard_lint!(tyvar_behind_raw_pointer, [rust_2018_compatibility]);
+standard_lint_crate_level!(uncommon_codepoints);
+standard_lint!(unconditional_recursion);
+standard_lint_nightly!(undefined_naked_function_abi);
+standard_lint_nightly!(unexpected_cfgs);
+standard_lint_nightly!(unfulfilled_lint_expectations);
+standard_lint!(ungated_async_fn_track_caller);
+standard_lint!(uninhabited_static);
+standard_lint!(unknown_lints);
+standard_lint!(unnameable_test_items);
+standard_lint!(unreachable_code, [unused]);
+standard_lint!(unreachable_patterns, [unused]);
+standard_lint!(unstable_name_collisions);
+standard_lint!(unstable_syntax_pre_expansion);
+standard_lint!(unsupported_calling_conventions);
+standard_lint!(unused_allocation, [unused]);
+standard_lint!(unused_assignments, [unused]);
+standard_lint!(unused_attributes, [unused]);
+standard_lint!(unused_braces, [unused]);
+standard_lint!(unused_comparisons);
+standard_lint!(unused_doc_comments, [unused]);
+standard_lint!(unused_features, [unused]);
+standard_lint!(unused_imports, [unused]);
+standard_lint!(unused_labels, [unused]);
+standard_lint!(unused_macros, [unused]);
+standard_lint!(unused_must_use, [unused]);
+standard_lint!(unused_mut, [unused]);
+standard_lint!(unused_parens, [unused]);
+standard_lint!(unused_unsafe, [unused]);
+standard_lint!(unused_variables, [unused]);
+standard_lint!(unused_visibilities, [unused]);
+// warnings is a group
+standard_lint!(where_clauses_object_safety);
+standard_lint!(while_true);
+
+// 3. Based on https://doc.rust-lang.org/nightly/rustc/lints/listing/deny-by-default.html - in the
+// same order. Lints that rustc has removed since (like `cenum_impl_drop_cast`) stay as
+// `standard_lint!` (with no alias). See also `acknowledge_deny` parameter.
+standard_lint_denied!(ambiguous_associated_items);
+standard_lint_denied!(arithmetic_overflow);
+standard_lint_denied!(bindings_with_variant_name);
+standard_lint!(cenum_impl_drop_cast);
+standard_lint_denied!(conflicting_repr_hints);
+standard_lint!(deprecated_cfg_attr_crate_type_name);
+standard_lint_denied_from!(enum_intrinsics_non_enums, 1.60);
+standard_lint_crate_level!(ill_formed_attribute_input);
+standard_lint!(implied_bounds_entailment);
+standard_lint_denied_from!(incomplete_include, 1.52);
+standard_lint_denied_from!(ineffective_unstable_trait_impl, 1.52);
+standard_lint_nightly!(invalid_alignment);
+standard_lint_denied_from!(invalid_atomic_ordering, 1.60);
+standard_lint_denied!(invalid_type_param_default);
+standard_lint_denied_from!(let_underscore_lock, 1.66, [let_underscore]);
+standard_lint_crate_level!(macro_expanded_macro_exports_accessed_by_absolute_paths);
+standard_lint!(missing_fragment_specifier);
+standard_lint_denied!(mutable_transmutes);
+standard_lint_denied_from!(named_asm_labels, 1.59);
+standard_lint_denied!(no_mangle_const_items);
+standard_lint!(order_dependent_trait_objects);
+standard_lint_denied!(overflowing_literals);
+standard_lint_denied!(patterns_in_fns_without_body);
+standard_lint!(proc_macro_back_compat);
+standard_lint!(proc_macro_derive_resolution_fallback);
+standard_lint_denied!(pub_use_of_private_extern_crate);
+standard_lint_denied!(soft_unstable);
+standard_lint_nightly!(test_unstable_lint);
+standard_lint_crate_level!(text_direction_codepoint_in_comment);
+standard_lint_crate_level!(text_direction_codepoint_in_literal);
+standard_lint_denied!(unconditional_panic);
+standard_lint_crate_level!(unknown_crate_types);
+standard_lint_denied_from!(useless_deprecated, 1.52);
+
+// Based on https://doc.rust-lang.org/nightly/rustdoc/lints.html - in the same order:
+//
+// According to https://releases.rs/docs/1.52.0/#rustdoc rustdoc:: lints exist since 1.52 (see
+// `macro_rules! rustdoc`):
+rustdoc!(broken_intra_doc_links);
+rustdoc!(private_intra_doc_links);
+rustdoc!(missing_crate_level_docs);
+rustdoc!(missing_doc_code_examples, nightly);
+
+rustdoc!(private_doc_tests);
+rustdoc!(invalid_codeblock_attributes);
+rustdoc!(invalid_html_tags);
+rustdoc!(invalid_rust_codeblocks);
+rustdoc!(bare_urls);
+rustdoc!(unescaped_backticks);
+
+// Based on https://rust-lang.github.io/rust-clippy/index.html for 1.45 to master for nightly 1.71:
+//
+// Each lint is followed by its Clippy lint category (as of Rust 1.95), which controls it with cargo
+// feature `clippy_<category>`. Lints that we added as of Rust 1.95 are declared as since 1.95 (they
+// may exist earlier).
+prefixed_lint_versioned!(1.95, clippy, absolute_paths, [restriction]);
+prefixed_lint!(clippy, absurd_extreme_comparisons, [correctness]);
+
+//prefixed_lint_versioned!(1.64, clippy, alloc_instead_of_core);
+prefixed_lint_allowed_from!(clippy, alloc_instead_of_core, 1.64, [restriction]);
+// @TODO try with "nightly" vs. nightly
+//
+// any!(clippy, alloc_instead_of_core, "", "", "nightly");
+
+prefixed_lint_versioned!(1.69, clippy, allow_attributes, [restriction]);
+prefixed_lint_versioned!(1.61, clippy, allow_attributes_without_reason, [restriction]);
+prefixed_lint_versioned!(1.68, clippy, almost_complete_range, [suspicious]);
+prefixed_lint!(clippy, almost_swapped, [correctness]);
+prefixed_lint!(clippy, approx_constant, [correctness]);
+prefixed_lint_versioned!(1.95, clippy, arbitrary_source_item_ordering, [restriction]);
+prefixed_lint_versioned!(1.95, clippy, arc_with_non_send_sync, [suspicious]);
+prefixed_lint_versioned!(1.64, clippy, arithmetic_side_effects, [restriction]);
+prefixed_lint!(clippy, as_conversions, [restriction]);
+prefixed_lint_versioned!(1.95, clippy, as_pointer_underscore, [restriction]);
+prefixed_lint_versioned!(1.66, clippy, as_ptr_cast_mut, [nursery]);
+prefixed_lint_versioned!(1.63, clippy, as_underscore, [restriction]);
+prefixed_lint!(clippy, assertions_on_constants, [style]);
+prefixed_lint_versioned!(1.64, clippy, assertions_on_result_states, [restriction]);
+prefixed_lint!(clippy, assign_op_pattern, [style]);
+// clippy::assign_ops is deprecated since at least 1.45
+prefixed_lint_versioned!(1.95, clippy, assigning_clones, [pedantic]);
+prefixed_lint_versioned!(1.48, clippy, async_yields_async, [correctness]);
+prefixed_lint_versioned!(1.62, clippy, await_holding_invalid_type, [suspicious]);
+prefixed_lint!(clippy, await_holding_lock, [suspicious]);
+prefixed_lint_versioned!(1.49, clippy, await_holding_refcell_ref, [suspicious]);
+prefixed_lint!(clippy, bad_bit_mask, [correctness]);
+prefixed_lint_versioned!(1.95, clippy, big_endian_bytes, [restriction]);
+prefixed_lint!(clippy, bind_instead_of_map, [complexity]);
+// clippy::blacklisted_name has been renamed to `clippy, disallowed_names`
+prefixed_lint_versioned!(1.47, clippy, blanket_clippy_restriction_lints, [suspicious]);
+prefixed_lint_versioned!(1.95, clippy, blocks_in_conditions, [style]);
+// clippy::blocks_in_if_conditions has been renamed to `clippy::blocks_in_conditions`
+prefixed_lint_versioned!(1.53, clippy, bool_assert_comparison, [style]);
+prefixed_lint!(clippy, bool_comparison, [complexity]);
+prefixed_lint_versioned!(1.65, clippy, bool_to_int_with_if, [pedantic]);
+prefixed_lint_versioned!(1.60, clippy, borrow_as_ptr, [pedantic]);
+prefixed_lint_versioned!(1.63, clippy, borrow_deref_ref, [complexity]);
+prefixed_lint!(clippy, borrow_interior_mutable_const, [style]);
+prefixed_lint!(clippy, borrowed_box, [complexity]);
+prefixed_lint_versioned!(1.57, clippy, box_collection, [perf]);
+prefixed_lint_versioned!(1.66, clippy, box_default, [style]);
+// clippy::box_vec has been renamed to `clippy, box_collection`
+prefixed_lint!(clippy, boxed_local, [perf]);
+prefixed_lint_versioned!(1.53, clippy, branches_sharing_code, [nursery]);
+prefixed_lint!(clippy, builtin_type_shadow, [style]);
+prefixed_lint_versioned!(1.95, clippy, byte_char_slices, [style]);
+prefixed_lint_versioned!(1.62, clippy, bytes_count_to_len, [complexity]);
+prefixed_lint_versioned!(1.52, clippy, bytes_nth, [style]);
+prefixed_lint!(clippy, cargo_common_metadata, [cargo]);
+prefixed_lint_versioned!(1.51, clippy, case_sensitive_file_extension_comparisons, [pedantic]);
+prefixed_lint_versioned!(1.62, clippy, cast_abs_to_unsigned, [suspicious]);
+prefixed_lint_versioned!(1.61, clippy, cast_enum_constructor, [suspicious]);
+prefixed_lint_versioned!(1.61, clippy, cast_enum_truncation, [suspicious]);
+prefixed_lint!(clippy, cast_lossless, [pedantic]);
+prefixed_lint_versioned!(1.66, clippy, cast_nan_to_int, [suspicious]);
+prefixed_lint!(clippy, cast_possible_truncation, [pedantic]);
+prefixed_lint!(clippy, cast_possible_wrap, [pedantic]);
+prefixed_lint!(clippy, cast_precision_loss, [pedantic]);
+prefixed_lint!(clippy, cast_ptr_alignment, [pedantic]);
+// clippy::cast_ref_to_mut has been renamed to (prefixless) `invalid_reference_casting`
+prefixed_lint!(clippy, cast_sign_loss, [pedantic]);
+prefixed_lint_versioned!(1.61, clippy, cast_slice_different_sizes, [correctness]);
+prefixed_lint_versioned!(1.65, clippy, cast_slice_from_raw_parts, [suspicious]);
+prefixed_lint_versioned!(1.95, clippy, cfg_not_test, [restriction]);
+prefixed_lint_versioned!(1.95, clippy, char_indices_as_byte_indices, [correctness]);
+prefixed_lint!(clippy, char_lit_as_u8, [complexity]);
+prefixed_lint!(clippy, chars_last_cmp, [style]);
+prefixed_lint!(clippy, chars_next_cmp, [style]);
+prefixed_lint!(clippy, checked_conversions, [pedantic]);
+prefixed_lint_versioned!(1.69, clippy, clear_with_drain, [nursery]);
+// clippy::clone_double_ref is renamed to (prefixless) suspicious_double_ref_op
+prefixed_lint!(clippy, clone_on_copy, [complexity]);
+prefixed_lint!(clippy, clone_on_ref_ptr, [restriction]);
+prefixed_lint!(clippy, cloned_instead_of_copied, [pedantic]);
+prefixed_lint_versioned!(1.95, clippy, cloned_ref_to_slice_refs, [perf]);
+// clippy::cmp_nan has been renamed to (prefixless) `invalid_nan_comparisons`
+prefixed_lint!(clippy, cmp_null, [style]);
+prefixed_lint!(clippy, cmp_owned, [perf]);
+prefixed_lint_versioned!(1.95, clippy, coerce_container_to_any, [nursery]);
+prefixed_lint!(clippy, cognitive_complexity, [restriction]);
+prefixed_lint_versioned!(1.51, clippy, collapsible_else_if, [pedantic]);
+prefixed_lint!(clippy, collapsible_if, [style]);
+prefixed_lint_versioned!(1.50, clippy, collapsible_match, [style]);
+prefixed_lint_versioned!(1.65, clippy, collapsible_str_replace, [perf]);
+prefixed_lint_versioned!(1.69, clippy, collection_is_never_read, [nursery]);
+prefixed_lint!(clippy, comparison_chain, [pedantic]);
+prefixed_lint_versioned!(1.49, clippy, comparison_to_empty, [style]);
+prefixed_lint_versioned!(1.95, clippy, confusing_method_to_numeric_cast, [suspicious]);
+prefixed_lint_versioned!(1.95, clippy, const_is_empty, [suspicious]);
+prefixed_lint!(clippy, copy_iterator, [pedantic]);
+prefixed_lint_versioned!(1.62, clippy, crate_in_macro_def, [suspicious]);
+prefixed_lint_versioned!(1.48, clippy, create_dir, [restriction]);
+prefixed_lint!(clippy, crosspointer_transmute, [suspicious]);
+prefixed_lint!(clippy, dbg_macro, [restriction]);
+prefixed_lint!(clippy, debug_assert_with_mut_call, [nursery]);
+prefixed_lint_versioned!(1.95, clippy, decimal_bitwise_operands, [pedantic]);
+prefixed_lint!(clippy, decimal_literal_representation, [restriction]);
+prefixed_lint!(clippy, declare_interior_mutable_const, [suspicious]);
+prefixed_lint_versioned!(1.95, clippy, default_constructed_unit_structs, [complexity]);
+prefixed_lint_versioned!(1.64, clippy, default_instead_of_iter_empty, [style]);
+prefixed_lint_versioned!(1.52, clippy, default_numeric_fallback, [restriction]);
+prefixed_lint!(clippy, default_trait_access, [pedantic]);
+prefixed_lint_versioned!(1.60, clippy, default_union_representation, [restriction]);
+prefixed_lint!(clippy, deprecated_cfg_attr, [complexity]);
+prefixed_lint_versioned!(1.95, clippy, deprecated_clippy_cfg_attr, [suspicious]);
+prefixed_lint!(clippy, deprecated_semver, [correctness]);
+prefixed_lint!(clippy, deref_addrof, [complexity]);
+prefixed_lint_versioned!(1.61, clippy, deref_by_slicing, [restriction]);
+prefixed_lint_versioned!(1.57, clippy, derivable_impls, [complexity]);
+// clippy::derive_hash_xor_eq has been renamed to `clippy::derived_hash_with_manual_eq`
+prefixed_lint_versioned!(1.47, clippy, derive_ord_xor_partial_ord, [correctness]);
+prefixed_lint_versioned!(1.63, clippy, derive_partial_eq_without_eq, [nursery]);
+prefixed_lint!(clippy, derived_hash_with_manual_eq, [correctness]);
+prefixed_lint_versioned!(1.95, clippy, disallowed_fields, [style]);
+prefixed_lint_versioned!(1.66, clippy, disallowed_macros, [style]);
+prefixed_lint_versioned!(1.49, clippy, disallowed_methods, [style]);
+prefixed_lint!(clippy, disallowed_names, [style]);
+prefixed_lint_versioned!(1.55, clippy, disallowed_script_idents, [restriction]);
+prefixed_lint_versioned!(1.55, clippy, disallowed_types, [style]);
+prefixed_lint!(clippy, diverging_sub_expression, [complexity]);
+prefixed_lint_versioned!(1.95, clippy, doc_broken_link, [pedantic]);
+prefixed_lint_versioned!(1.95, clippy, doc_comment_double_space_linebreaks, [pedantic]);
+prefixed_lint_versioned!(1.95, clippy, doc_include_without_cfg, [restriction]);
+prefixed_lint_versioned!(1.95, clippy, doc_lazy_continuation, [style]);
+prefixed_lint_versioned!(1.95, clippy, doc_link_code, [nursery]);
+prefixed_lint_versioned!(1.63, clippy, doc_link_with_quotes, [pedantic]);
+prefixed_lint!(clippy, doc_markdown, [pedantic]);
+prefixed_lint_versioned!(1.95, clippy, doc_nested_refdefs, [suspicious]);
+prefixed_lint_versioned!(1.95, clippy, doc_overindented_list_items, [style]);
+prefixed_lint_versioned!(1.95, clippy, doc_paragraphs_missing_punctuation, [restriction]);
+prefixed_lint_versioned!(1.95, clippy, doc_suspicious_footnotes, [suspicious]);
+prefixed_lint!(clippy, double_comparisons, [complexity]);
+prefixed_lint_versioned!(1.95, clippy, double_ended_iterator_last, [perf]);
+prefixed_lint!(clippy, double_must_use, [style]);
+// clippy::double_neg has been renamed to (prefixless) `double_negations`
+prefixed_lint!(clippy, double_parens, [complexity]);
+prefixed_lint_versioned!(1.95, clippy, drain_collect, [perf]);
+// clippy::drop_bounds has been renamed to (prefixless) `drop_bounds`
+//
+// clippy::drop_copy has been renamed to (prefixless) `dropping_copy_types`
+prefixed_lint_versioned!(1.62, clippy, drop_non_drop, [suspicious]);
+// clippy::drop_ref has been renamed to (prefixless) `dropping_references`
+prefixed_lint_versioned!(1.63, clippy, duplicate_mod, [suspicious]);
+prefixed_lint!(clippy, duplicate_underscore_argument, [style]);
+prefixed_lint_versioned!(1.95, clippy, duplicated_attributes, [suspicious]);
+prefixed_lint_versioned!(1.95, clippy, duration_suboptimal_units, [pedantic]);
+prefixed_lint!(clippy, duration_subsec, [complexity]);
+prefixed_lint_versioned!(1.95, clippy, eager_transmute, [correctness]);
+prefixed_lint_versioned!(1.95, clippy, elidable_lifetime_names, [pedantic]);
+prefixed_lint!(clippy, else_if_without_else, [restriction]);
+prefixed_lint_versioned!(1.95, clippy, empty_docs, [suspicious]);
+prefixed_lint_versioned!(1.62, clippy, empty_drop, [restriction]);
+// clippy::empty_enum has been renamed to `clippy::empty_enums`
+prefixed_lint_versioned!(1.95, clippy, empty_enum_variants_with_brackets, [restriction]);
+prefixed_lint_versioned!(1.95, clippy, empty_enums, [pedantic]);
+prefixed_lint_versioned!(1.95, clippy, empty_line_after_doc_comments, [suspicious]);
+prefixed_lint!(clippy, empty_line_after_outer_attr, [suspicious]);
+prefixed_lint!(clippy, empty_loop, [suspicious]);
+prefixed_lint_versioned!(1.62, clippy, empty_structs_with_brackets, [restriction]);
+prefixed_lint!(clippy, enum_clike_unportable_variant, [correctness]);
+prefixed_lint!(clippy, enum_glob_use, [pedantic]);
+prefixed_lint!(clippy, enum_variant_names, [style]);
+prefixed_lint!(clippy, eq_op, [correctness]);
+prefixed_lint_versioned!(1.57, clippy, equatable_if_let, [nursery]);
+prefixed_lint!(clippy, erasing_op, [correctness]);
+prefixed_lint_versioned!(1.62, clippy, err_expect, [style]);
+prefixed_lint_versioned!(1.95, clippy, error_impl_error, [restriction]);
+// clippy::eval_order_dependence has been renamed to `clippy::mixed_read_write_in_expression`
+prefixed_lint_versioned!(1.95, clippy, excessive_nesting, [complexity]);
+prefixed_lint!(clippy, excessive_precision, [style]);
+prefixed_lint_versioned!(1.51, clippy, exhaustive_enums, [restriction]);
+prefixed_lint_versioned!(1.51, clippy, exhaustive_structs, [restriction]);
+prefixed_lint!(clippy, exit, [restriction]);
+prefixed_lint!(clippy, expect_fun_call, [perf]);
+prefixed_lint_allowed!(clippy, expect_used, [restriction]);
+prefixed_lint!(clippy, expl_impl_clone_on_copy, [pedantic]);
+prefixed_lint_versioned!(1.64, clippy, explicit_auto_deref, [complexity]);
+prefixed_lint!(clippy, explicit_counter_loop, [complexity]);
+prefixed_lint!(clippy, explicit_deref_methods, [pedantic]);
+prefixed_lint!(clippy, explicit_into_iter_loop, [pedantic]);
+prefixed_lint!(clippy, explicit_iter_loop, [pedantic]);
+prefixed_lint!(clippy, explicit_write, [complexity]);
+// clippy::extend_from_slice is deprecated since at least 1.44.1
+prefixed_lint_versioned!(1.55, clippy, extend_with_drain, [perf]);
+prefixed_lint!(clippy, extra_unused_lifetimes, [complexity]);
+prefixed_lint_versioned!(1.69, clippy, extra_unused_type_parameters, [complexity]);
+prefixed_lint!(clippy, fallible_impl_from, [nursery]);
+prefixed_lint_versioned!(1.49, clippy, field_reassign_with_default, [style]);
+prefixed_lint_versioned!(1.95, clippy, field_scoped_visibility_modifiers, [restriction]);
+prefixed_lint!(clippy, filetype_is_file, [restriction]);
+// clippy::filter_map has been removed: this lint has been replaced by `manual_filter_map`, a more
+// specific (and prefixless) lint.
+prefixed_lint_versioned!(1.95, clippy, filter_map_bool_then, [style]);
+prefixed_lint_versioned!(1.52, clippy, filter_map_identity, [complexity]);
+prefixed_lint!(clippy, filter_map_next, [pedantic]);
+prefixed_lint!(clippy, filter_next, [complexity]);
+// clippy::find_map has been removed: this lint has been replaced by `manual_find_map`, a more
+// specific (and prefixless) lint.
+prefixed_lint!(clippy, flat_map_identity, [complexity]);
+prefixed_lint_versioned!(1.53, clippy, flat_map_option, [pedantic]);
+prefixed_lint!(clippy, float_arithmetic, [restriction]);
+prefixed_lint!(clippy, float_cmp, [pedantic]);
+prefixed_lint!(clippy, float_cmp_const, [restriction]);
+prefixed_lint_versioned!(1.48, clippy, float_equality_without_abs, [suspicious]);
+// clippy::fn_address_comparisons has been renamed to (prefixless) `unpredictable_function_pointer_comparisons`
+// clippy::fn_null_check has been renamed to (prefixless) `useless_ptr_null_checks`
+prefixed_lint!(clippy, fn_params_excessive_bools, [pedantic]);
+prefixed_lint!(clippy, fn_to_numeric_cast, [style]);
+prefixed_lint_versioned!(1.58, clippy, fn_to_numeric_cast_any, [restriction]);
+prefixed_lint!(clippy, fn_to_numeric_cast_with_truncation, [style]);
+prefixed_lint!(clippy, for_kv_map, [style]);
+// clippy::for_loop_over_option is removed since 1.45
+//
+// clippy::for_loop_over_result is removed since 1.45
+//
+// clippy::for_loops_over_fallibles has been renamed to (prefixless) `for_loops_over_fallibles`
+//
+// clippy::forget_copy has been renamed to (prefixless) forgetting_copy_types
+prefixed_lint_versioned!(1.62, clippy, forget_non_drop, [suspicious]);
+// clippy::forget_ref has been renamed to (prefixless) forgetting_references
+prefixed_lint_versioned!(1.95, clippy, format_collect, [pedantic]);
+prefixed_lint_versioned!(1.58, clippy, format_in_format_args, [perf]);
+prefixed_lint_versioned!(1.62, clippy, format_push_string, [pedantic]);
+prefixed_lint_versioned!(1.95, clippy, four_forward_slashes, [suspicious]);
+prefixed_lint_versioned!(1.49, clippy, from_iter_instead_of_collect, [pedantic]);
+prefixed_lint_versioned!(1.51, clippy, from_over_into, [style]);
+prefixed_lint_versioned!(1.67, clippy, from_raw_with_void_ptr, [suspicious]);
+prefixed_lint_versioned!(1.52, clippy, from_str_radix_10, [style]);
+prefixed_lint!(clippy, future_not_send, [nursery]);
+prefixed_lint_versioned!(1.63, clippy, get_first, [style]);
+prefixed_lint!(clippy, get_last_with_len, [complexity]);
+prefixed_lint!(clippy, get_unwrap, [restriction]);
+prefixed_lint_versioned!(1.95, clippy, host_endian_bytes, [restriction]);
+// clippy::identity_conversion is removed since 1.45
+prefixed_lint!(clippy, identity_op, [complexity]);
+prefixed_lint!(clippy, if_let_mutex, [correctness]);
+// clippy::if_let_redundant_pattern_matching is deprecated since at least 1.45
+//
+// clippy::if_let_some_result has been renamed to `clippy::match_result_ok`
+prefixed_lint!(clippy, if_not_else, [pedantic]);
+prefixed_lint!(clippy, if_same_then_else, [style]);
+prefixed_lint_versioned!(1.53, clippy, if_then_some_else_none, [restriction]);
+prefixed_lint!(clippy, ifs_same_cond, [correctness]);
+prefixed_lint_versioned!(1.95, clippy, ignore_without_reason, [pedantic]);
+prefixed_lint_versioned!(1.95, clippy, ignored_unit_patterns, [pedantic]);
+prefixed_lint_versioned!(1.95, clippy, impl_hash_borrow_with_str_and_bytes, [correctness]);
+prefixed_lint_versioned!(1.69, clippy, impl_trait_in_params, [restriction]);
+prefixed_lint_versioned!(1.52, clippy, implicit_clone, [pedantic]);
+prefixed_lint!(clippy, implicit_hasher, [pedantic]);
+prefixed_lint!(clippy, implicit_return, [restriction]);
+prefixed_lint_versioned!(1.66, clippy, implicit_saturating_add, [style]);
+prefixed_lint!(clippy, implicit_saturating_sub, [style]);
+prefixed_lint_versioned!(1.95, clippy, implied_bounds_in_impls, [complexity]);
+prefixed_lint_versioned!(1.95, clippy, impossible_comparisons, [correctness]);
+prefixed_lint!(clippy, imprecise_flops, [nursery]);
+prefixed_lint_versioned!(1.95, clippy, incompatible_msrv, [suspicious]);
+prefixed_lint!(clippy, inconsistent_digit_grouping, [style]);
+prefixed_lint_versioned!(1.52, clippy, inconsistent_struct_constructor, [pedantic]);
+prefixed_lint_versioned!(1.59, clippy, index_refutable_slice, [pedantic]);
+prefixed_lint_allowed!(clippy, indexing_slicing, [restriction]);
+prefixed_lint!(clippy, ineffective_bit_mask, [correctness]);
+prefixed_lint_versioned!(1.95, clippy, ineffective_open_options, [suspicious]);
+prefixed_lint!(clippy, inefficient_to_string, [pedantic]);
+prefixed_lint!(clippy, infallible_destructuring_match, [style]);
+prefixed_lint_versioned!(1.95, clippy, infallible_try_from, [suspicious]);
+prefixed_lint!(clippy, infinite_iter, [correctness]);
+prefixed_lint_versioned!(1.95, clippy, infinite_loop, [restriction]);
+prefixed_lint!(clippy, inherent_to_string, [style]);
+prefixed_lint!(clippy, inherent_to_string_shadow_display, [correctness]);
+prefixed_lint_versioned!(1.59, clippy, init_numbered_fields, [style]);
+prefixed_lint!(clippy, inline_always, [pedantic]);
+prefixed_lint_versioned!(1.49, clippy, inline_asm_x86_att_syntax, [restriction]);
+prefixed_lint_versioned!(1.49, clippy, inline_asm_x86_intel_syntax, [restriction]);
+prefixed_lint!(clippy, inline_fn_without_body, [correctness]);
+prefixed_lint_versioned!(1.51, clippy, inspect_for_each, [complexity]);
+prefixed_lint!(clippy, int_plus_one, [complexity]);
+// clippy::integer_arithmetic has been renamed to `clippy::arithmetic_side_effects`
+prefixed_lint!(clippy, integer_division, [restriction]);
+prefixed_lint_versioned!(1.95, clippy, integer_division_remainder_used, [restriction]);
+// clippy::into_iter_on_array is deprecated since at least 1.45
+prefixed_lint!(clippy, into_iter_on_ref, [style]);
+prefixed_lint_versioned!(1.95, clippy, into_iter_without_iter, [pedantic]);
+// clippy::invalid_atomic_ordering has been renamed to (prefixless) `invalid_atomic_ordering`
+// clippy::invalid_null_ptr_usage has been renamed to (prefixless) `invalid_null_arguments`
+// clippy::invalid_ref is deprecated since at least 1.44.1
+prefixed_lint!(clippy, invalid_regex, [correctness]);
+prefixed_lint!(clippy, invalid_upcast_comparisons, [pedantic]);
+// clippy::invalid_utf8_in_unchecked has been renamed to (prefixless) `invalid_from_utf8_unchecked`
+prefixed_lint_versioned!(1.95, clippy, inverted_saturating_sub, [correctness]);
+prefixed_lint_versioned!(1.49, clippy, invisible_characters, [correctness]);
+prefixed_lint_versioned!(1.95, clippy, io_other_error, [style]);
+prefixed_lint_versioned!(1.95, clippy, ip_constant, [pedantic]);
+prefixed_lint_versioned!(1.62, clippy, is_digit_ascii_radix, [style]);
+prefixed_lint!(clippy, items_after_statements, [pedantic]);
+prefixed_lint_versioned!(1.70, clippy, items_after_test_module, [style]);
+prefixed_lint!(clippy, iter_cloned_collect, [style]);
+prefixed_lint_versioned!(1.52, clippy, iter_count, [complexity]);
+prefixed_lint_versioned!(1.95, clippy, iter_filter_is_ok, [pedantic]);
+prefixed_lint_versioned!(1.95, clippy, iter_filter_is_some, [pedantic]);
+prefixed_lint_versioned!(1.66, clippy, iter_kv_map, [complexity]);
+prefixed_lint!(clippy, iter_next_loop, [correctness]);
+prefixed_lint_versioned!(1.46, clippy, iter_next_slice, [style]);
+prefixed_lint_versioned!(1.57, clippy, iter_not_returning_iterator, [pedantic]);
+prefixed_lint!(clippy, iter_nth, [style]);
+prefixed_lint!(clippy, iter_nth_zero, [style]);
+prefixed_lint_versioned!(1.65, clippy, iter_on_empty_collections, [nursery]);
+prefixed_lint_versioned!(1.65, clippy, iter_on_single_items, [nursery]);
+prefixed_lint_versioned!(1.95, clippy, iter_out_of_bounds, [suspicious]);
+prefixed_lint_versioned!(1.95, clippy, iter_over_hash_type, [restriction]);
+prefixed_lint_versioned!(1.60, clippy, iter_overeager_cloned, [perf]);
+prefixed_lint!(clippy, iter_skip_next, [style]);
+prefixed_lint_versioned!(1.95, clippy, iter_skip_zero, [correctness]);
+prefixed_lint_versioned!(1.61, clippy, iter_with_drain, [nursery]);
+prefixed_lint_versioned!(1.95, clippy, iter_without_into_iter, [pedantic]);
+prefixed_lint!(clippy, iterator_step_by_zero, [correctness]);
+prefixed_lint_versioned!(1.95, clippy, join_absolute_paths, [suspicious]);
+prefixed_lint!(clippy, just_underscores_and_digits, [style]);
+prefixed_lint!(clippy, large_const_arrays, [perf]);
+prefixed_lint!(clippy, large_digit_groups, [pedantic]);
+prefixed_lint!(clippy, large_enum_variant, [perf]);
+prefixed_lint_versioned!(1.68, clippy, large_futures, [pedantic]);
+prefixed_lint_versioned!(1.62, clippy, large_include_file, [restriction]);
+prefixed_lint!(clippy, large_stack_arrays, [pedantic]);
+prefixed_lint_versioned!(1.95, clippy, large_stack_frames, [nursery]);
+prefixed_lint_versioned!(1.49, clippy, large_types_passed_by_value, [pedantic]);
+prefixed_lint_versioned!(1.95, clippy, legacy_numeric_constants, [style]);
+prefixed_lint!(clippy, len_without_is_empty, [style]);
+prefixed_lint!(clippy, len_zero, [style]);
+prefixed_lint!(clippy, let_and_return, [style]);
+prefixed_lint_versioned!(1.67, clippy, let_underscore_future, [suspicious]);
+prefixed_lint!(clippy, let_underscore_lock, [correctness]);
+prefixed_lint!(clippy, let_underscore_must_use, [restriction]);
+prefixed_lint_versioned!(1.69, clippy, let_underscore_untyped, [restriction]);
+prefixed_lint!(clippy, let_unit_value, [style]);
+prefixed_lint_versioned!(1.69, clippy, let_with_type_underscore, [complexity]);
+prefixed_lint_versioned!(1.70, clippy, lines_filter_map_ok, [suspicious]);
+prefixed_lint!(clippy, linkedlist, [pedantic]);
+prefixed_lint_versioned!(1.95, clippy, lint_groups_priority, [correctness]);
+prefixed_lint_versioned!(1.95, clippy, literal_string_with_formatting_args, [nursery]);
+prefixed_lint_versioned!(1.95, clippy, little_endian_bytes, [restriction]);
+// clippy::logic_bug has been renamed to `clippy::overly_complex_bool_expr`
+prefixed_lint!(clippy, lossy_float_literal, [restriction]);
+prefixed_lint_versioned!(1.95, clippy, macro_metavars_in_unsafe, [suspicious]);
+prefixed_lint!(clippy, macro_use_imports, [pedantic]);
+prefixed_lint!(clippy, main_recursion, [style]);
+prefixed_lint_versioned!(1.95, clippy, manual_abs_diff, [complexity]);
+prefixed_lint_versioned!(1.57, clippy, manual_assert, [pedantic]);
+prefixed_lint!(clippy, manual_async_fn, [style]);
+prefixed_lint_versioned!(1.60, clippy, manual_bits, [style]);
+prefixed_lint_versioned!(1.95, clippy, manual_c_str_literals, [complexity]);
+prefixed_lint_versioned!(1.95, clippy, manual_checked_ops, [complexity]);
+prefixed_lint_versioned!(1.66, clippy, manual_clamp, [complexity]);
+prefixed_lint_versioned!(1.95, clippy, manual_contains, [perf]);
+prefixed_lint_versioned!(1.95, clippy, manual_dangling_ptr, [style]);
+prefixed_lint_versioned!(1.95, clippy, manual_div_ceil, [complexity]);
+prefixed_lint_versioned!(1.66, clippy, manual_filter, [complexity]);
+prefixed_lint_versioned!(1.51, clippy, manual_filter_map, [complexity]);
+prefixed_lint_versioned!(1.64, clippy, manual_find, [complexity]);
+prefixed_lint_versioned!(1.51, clippy, manual_find_map, [complexity]);
+prefixed_lint_versioned!(1.52, clippy, manual_flatten, [complexity]);
+prefixed_lint_versioned!(1.95, clippy, manual_hash_one, [complexity]);
+prefixed_lint_versioned!(1.95, clippy, manual_ignore_case_cmp, [perf]);
+prefixed_lint_versioned!(1.95, clippy, manual_ilog2, [pedantic]);
+prefixed_lint_versioned!(1.95, clippy, manual_inspect, [complexity]);
+prefixed_lint_versioned!(1.65, clippy, manual_instant_elapsed, [pedantic]);
+prefixed_lint_versioned!(1.67, clippy, manual_is_ascii_check, [style]);
+prefixed_lint_versioned!(1.95, clippy, manual_is_finite, [style]);
+prefixed_lint_versioned!(1.95, clippy, manual_is_infinite, [style]);
+prefixed_lint_versioned!(1.95, clippy, manual_is_multiple_of, [complexity]);
+prefixed_lint_versioned!(1.95, clippy, manual_is_power_of_two, [pedantic]);
+prefixed_lint_versioned!(1.95, clippy, manual_is_variant_and, [pedantic]);
+prefixed_lint_versioned!(1.67, clippy, manual_let_else, [pedantic]);
+prefixed_lint_versioned!(1.70, clippy, manual_main_separator_str, [complexity]);
+prefixed_lint_versioned!(1.52, clippy, manual_map, [style]);
+prefixed_lint!(clippy, manual_memcpy, [perf]);
+prefixed_lint_versioned!(1.95, clippy, manual_midpoint, [pedantic]);
+prefixed_lint_versioned!(1.95, clippy, manual_next_back, [style]);
+prefixed_lint!(clippy, manual_non_exhaustive, [style]);
+prefixed_lint_versioned!(1.95, clippy, manual_ok_err, [complexity]);
+prefixed_lint_versioned!(1.49, clippy, manual_ok_or, [style]);
+prefixed_lint_versioned!(1.95, clippy, manual_option_as_slice, [complexity]);
+prefixed_lint_versioned!(1.95, clippy, manual_pattern_char_comparison, [style]);
+prefixed_lint_versioned!(1.49, clippy, manual_range_contains, [style]);
+prefixed_lint_versioned!(1.95, clippy, manual_range_patterns, [complexity]);
+prefixed_lint_versioned!(1.64, clippy, manual_rem_euclid, [complexity]);
+prefixed_lint_versioned!(1.95, clippy, manual_repeat_n, [style]);
+prefixed_lint_versioned!(1.64, clippy, manual_retain, [perf]);
+prefixed_lint_versioned!(1.95, clippy, manual_rotate, [style]);
+prefixed_lint!(clippy, manual_saturating_arithmetic, [style]);
+prefixed_lint_versioned!(1.95, clippy, manual_slice_fill, [style]);
+prefixed_lint_versioned!(1.70, clippy, manual_slice_size_calculation, [complexity]);
+prefixed_lint_versioned!(1.57, clippy, manual_split_once, [complexity]);
+prefixed_lint_versioned!(1.54, clippy, manual_str_repeat, [perf]);
+prefixed_lint_versioned!(1.65, clippy, manual_string_new, [pedantic]);
+prefixed_lint_versioned!(1.48, clippy, manual_strip, [complexity]);
+prefixed_lint!(clippy, manual_swap, [complexity]);
+prefixed_lint_versioned!(1.95, clippy, manual_take, [complexity]);
+prefixed_lint_versioned!(1.95, clippy, manual_try_fold, [perf]);
+prefixed_lint_versioned!(1.49, clippy, manual_unwrap_or, [complexity]);
+prefixed_lint_versioned!(1.95, clippy, manual_unwrap_or_default, [suspicious]);
+prefixed_lint_versioned!(1.70, clippy, manual_while_let_some, [style]);
+prefixed_lint!(clippy, many_single_char_names, [pedantic]);
+prefixed_lint_versioned!(1.95, clippy, map_all_any_identity, [complexity]);
+prefixed_lint!(clippy, map_clone, [style]);
+prefixed_lint_versioned!(1.49, clippy, map_collect_result_unit, [style]);
+prefixed_lint!(clippy, map_entry, [perf]);
+prefixed_lint_versioned!(1.48, clippy, map_err_ignore, [restriction]);
+prefixed_lint!(clippy, map_flatten, [complexity]);
+prefixed_lint_versioned!(1.47, clippy, map_identity, [complexity]);
+prefixed_lint!(clippy, map_unwrap_or, [pedantic]);
+prefixed_lint_versioned!(1.95, clippy, map_with_unused_argument_over_ranges, [restriction]);
+prefixed_lint!(clippy, match_as_ref, [complexity]);
+prefixed_lint!(clippy, match_bool, [pedantic]);
+prefixed_lint_versioned!(1.47, clippy, match_like_matches_macro, [style]);
+// clippy::match_on_vec_items has been removed: `clippy::indexing_slicing` covers indexing and slicing on `Vec<_>`
+prefixed_lint!(clippy, match_overlapping_arm, [style]);
+prefixed_lint!(clippy, match_ref_pats, [style]);
+prefixed_lint_versioned!(1.57, clippy, match_result_ok, [style]);
+prefixed_lint!(clippy, match_same_arms, [pedantic]);
+prefixed_lint!(clippy, match_single_binding, [complexity]);
+prefixed_lint_versioned!(1.58, clippy, match_str_case_mismatch, [correctness]);
+prefixed_lint!(clippy, match_wild_err_arm, [pedantic]);
+prefixed_lint!(clippy, match_wildcard_for_single_variants, [pedantic]);
+prefixed_lint!(clippy, maybe_infinite_iter, [pedantic]);
+// clippy::mem_discriminant_non_enum has been renamed to (prefixless) `enum_intrinsics_non_enums`
+prefixed_lint!(clippy, mem_forget, [restriction]);
+prefixed_lint!(clippy, mem_replace_option_with_none, [style]);
+prefixed_lint_versioned!(1.95, clippy, mem_replace_option_with_some, [style]);
+prefixed_lint!(clippy, mem_replace_with_default, [style]);
+prefixed_lint!(clippy, mem_replace_with_uninit, [correctness]);
+prefixed_lint_versioned!(1.95, clippy, min_ident_chars, [restriction]);
+prefixed_lint!(clippy, min_max, [correctness]);
+// clippy::misaligned_transmute is deprecated since at least 1.44.1
+// clippy::mismatched_target_os has been renamed to (prefixless) `unexpected_cfgs`
+prefixed_lint_versioned!(1.63, clippy, mismatching_type_param_order, [pedantic]);
+prefixed_lint_versioned!(1.67, clippy, misnamed_getters, [suspicious]);
+prefixed_lint!(clippy, misrefactored_assign_op, [suspicious]);
+prefixed_lint_versioned!(1.69, clippy, missing_assert_message, [restriction]);
+prefixed_lint_versioned!(1.95, clippy, missing_asserts_for_indexing, [restriction]);
+prefixed_lint!(clippy, missing_const_for_fn, [nursery]);
+prefixed_lint_versioned!(1.95, clippy, missing_const_for_thread_local, [perf]);
+prefixed_lint!(clippy, missing_docs_in_private_items, [restriction]);
+prefixed_lint_versioned!(1.55, clippy, missing_enforced_import_renames, [style]);
+prefixed_lint!(clippy, missing_errors_doc, [pedantic]);
+prefixed_lint_versioned!(1.95, clippy, missing_fields_in_debug, [pedantic]);
+prefixed_lint!(clippy, missing_inline_in_public_items, [restriction]);
+prefixed_lint_versioned!(1.51, clippy, missing_panics_doc, [pedantic]);
+prefixed_lint_allowed!(clippy, missing_safety_doc, [style]);
+prefixed_lint_versioned!(1.61, clippy, missing_spin_loop, [perf]);
+prefixed_lint_versioned!(1.66, clippy, missing_trait_methods, [restriction]);
+prefixed_lint_versioned!(1.95, clippy, missing_transmute_annotations, [suspicious]);
+prefixed_lint!(clippy, mistyped_literal_suffixes, [correctness]);
+prefixed_lint_versioned!(1.95, clippy, mixed_attributes_style, [style]);
+prefixed_lint!(clippy, mixed_case_hex_literals, [style]);
+prefixed_lint!(clippy, mixed_read_write_in_expression, [restriction]);
+prefixed_lint_versioned!(1.57, clippy, mod_module_files, [restriction]);
+prefixed_lint!(clippy, module_inception, [style]);
+prefixed_lint!(clippy, module_name_repetitions, [restriction]);
+prefixed_lint!(clippy, modulo_arithmetic, [restriction]);
+prefixed_lint!(clippy, modulo_one, [correctness]);
+prefixed_lint_versioned!(1.65, clippy, multi_assignments, [suspicious]);
+prefixed_lint_versioned!(1.95, clippy, multiple_bound_locations, [style]);
+prefixed_lint!(clippy, multiple_crate_versions, [cargo]);
+prefixed_lint!(clippy, multiple_inherent_impl, [restriction]);
+prefixed_lint_versioned!(1.69, clippy, multiple_unsafe_ops_per_block, [restriction]);
+prefixed_lint!(clippy, must_use_candidate, [pedantic]);
+prefixed_lint!(clippy, must_use_unit, [style]);
+prefixed_lint!(clippy, mut_from_ref, [correctness]);
+prefixed_lint!(clippy, mut_mut, [pedantic]);
+prefixed_lint_versioned!(1.49, clippy, mut_mutex_lock, [style]);
+prefixed_lint!(clippy, mut_range_bound, [suspicious]);
+prefixed_lint!(clippy, mutable_key_type, [suspicious]);
+prefixed_lint!(clippy, mutex_atomic, [restriction]);
+prefixed_lint!(clippy, mutex_integer, [restriction]);
+prefixed_lint!(clippy, naive_bytecount, [pedantic]);
+prefixed_lint_versioned!(1.47, clippy, needless_arbitrary_self_type, [complexity]);
+prefixed_lint_versioned!(1.95, clippy, needless_as_bytes, [complexity]);
+prefixed_lint_versioned!(1.54, clippy, needless_bitwise_bool, [pedantic]);
+prefixed_lint!(clippy, needless_bool, [complexity]);
+prefixed_lint_versioned!(1.69, clippy, needless_bool_assign, [complexity]);
+prefixed_lint!(clippy, needless_borrow, [style]);
+prefixed_lint!(clippy, needless_borrowed_reference, [complexity]);
+prefixed_lint_versioned!(1.95, clippy, needless_borrows_for_generic_args, [style]);
+prefixed_lint_versioned!(1.95, clippy, needless_character_iteration, [suspicious]);
+prefixed_lint!(clippy, needless_collect, [nursery]);
+prefixed_lint!(clippy, needless_continue, [pedantic]);
+prefixed_lint!(clippy, needless_doctest_main, [style]);
+prefixed_lint_versioned!(1.95, clippy, needless_else, [style]);
+prefixed_lint_versioned!(1.53, clippy, needless_for_each, [pedantic]);
+prefixed_lint_versioned!(1.95, clippy, needless_ifs, [complexity]);
+prefixed_lint_versioned!(1.59, clippy, needless_late_init, [style]);
+prefixed_lint!(clippy, needless_lifetimes, [complexity]);
+prefixed_lint_versioned!(1.61, clippy, needless_match, [complexity]);
+prefixed_lint_versioned!(1.95, clippy, needless_maybe_sized, [suspicious]);
+prefixed_lint_versioned!(1.57, clippy, needless_option_as_deref, [complexity]);
+prefixed_lint_versioned!(1.62, clippy, needless_option_take, [complexity]);
+prefixed_lint_versioned!(1.63, clippy, needless_parens_on_range_literals, [style]);
+prefixed_lint_versioned!(1.95, clippy, needless_pass_by_ref_mut, [nursery]);
+prefixed_lint!(clippy, needless_pass_by_value, [pedantic]);
+prefixed_lint_versioned!(1.95, clippy, needless_pub_self, [style]);
+prefixed_lint_versioned!(1.51, clippy, needless_question_mark, [complexity]);
+prefixed_lint!(clippy, needless_range_loop, [style]);
+prefixed_lint_versioned!(1.95, clippy, needless_raw_string_hashes, [pedantic]);
+prefixed_lint_versioned!(1.95, clippy, needless_raw_strings, [restriction]);
+prefixed_lint!(clippy, needless_return, [style]);
+prefixed_lint_versioned!(1.95, clippy, needless_return_with_question_mark, [style]);
+prefixed_lint_versioned!(1.59, clippy, needless_splitn, [complexity]);
+prefixed_lint_versioned!(1.95, clippy, needless_type_cast, [nursery]);
+prefixed_lint!(clippy, needless_update, [complexity]);
+prefixed_lint!(clippy, neg_cmp_op_on_partial_ord, [complexity]);
+prefixed_lint!(clippy, neg_multiply, [style]);
+prefixed_lint_versioned!(1.57, clippy, negative_feature_names, [cargo]);
+prefixed_lint!(clippy, never_loop, [correctness]);
+prefixed_lint!(clippy, new_ret_no_self, [style]);
+prefixed_lint!(clippy, new_without_default, [style]);
+prefixed_lint!(clippy, no_effect, [complexity]);
+prefixed_lint_versioned!(1.63, clippy, no_effect_replace, [suspicious]);
+prefixed_lint_versioned!(1.58, clippy, no_effect_underscore_binding, [pedantic]);
+prefixed_lint_versioned!(1.69, clippy, no_mangle_with_rust_abi, [pedantic]);
+prefixed_lint!(clippy, non_ascii_literal, [restriction]);
+prefixed_lint_versioned!(1.95, clippy, non_canonical_clone_impl, [suspicious]);
+prefixed_lint_versioned!(1.95, clippy, non_canonical_partial_ord_impl, [suspicious]);
+prefixed_lint_versioned!(1.95, clippy, non_minimal_cfg, [style]);
+prefixed_lint_versioned!(1.53, clippy, non_octal_unix_permissions, [correctness]);
+prefixed_lint_versioned!(1.57, clippy, non_send_fields_in_send_ty, [nursery]);
+prefixed_lint_versioned!(1.95, clippy, non_std_lazy_statics, [pedantic]);
+prefixed_lint_versioned!(1.95, clippy, non_zero_suggestions, [restriction]);
+prefixed_lint!(clippy, nonminimal_bool, [complexity]);
+prefixed_lint!(clippy, nonsensical_open_options, [correctness]);
+prefixed_lint_versioned!(1.55, clippy, nonstandard_macro_braces, [nursery]);
+prefixed_lint!(clippy, not_unsafe_ptr_arg_deref, [correctness]);
+prefixed_lint_versioned!(1.64, clippy, obfuscated_if_else, [style]);
+prefixed_lint_versioned!(1.59, clippy, octal_escapes, [suspicious]);
+prefixed_lint!(clippy, ok_expect, [style]);
+prefixed_lint_versioned!(1.61, clippy, only_used_in_recursion, [complexity]);
+prefixed_lint!(clippy, op_ref, [style]);
+// clippy::option_and_then_some is renamed to `clippy::bind_instead_of_map`
+prefixed_lint_versioned!(1.95, clippy, option_as_ref_cloned, [pedantic]);
+prefixed_lint!(clippy, option_as_ref_deref, [complexity]);
+prefixed_lint!(clippy, option_env_unwrap, [correctness]);
+// clippy::option_expect_used is removed (renamed to `clippy::expect_used`)
+prefixed_lint_versioned!(1.53, clippy, option_filter_map, [complexity]);
+prefixed_lint_versioned!(1.47, clippy, option_if_let_else, [nursery]);
+prefixed_lint!(clippy, option_map_or_none, [style]);
+prefixed_lint!(clippy, option_map_unit_fn, [complexity]);
+// clippy::option_map_unwrap_or is renamed to `clippy::map_unwrap_or`
+//
+// clippy::option_mapw_unwrap_or_else is removed (since 1.45.0?)
+prefixed_lint!(clippy, option_option, [pedantic]);
+// clippy::option_unwrap_used is renamed to `clippy::unwrap_used`
+prefixed_lint!(clippy, or_fun_call, [nursery]);
+prefixed_lint_versioned!(1.61, clippy, or_then_unwrap, [complexity]);
+prefixed_lint!(clippy, out_of_bounds_indexing, [correctness]);
+// clippy::overflow_check_conditional has been renamed to `clippy::panicking_overflow_checks`
+prefixed_lint!(clippy, overly_complex_bool_expr, [correctness]);
+prefixed_lint_versioned!(1.95, clippy, owned_cow, [style]);
+prefixed_lint_allowed!(clippy, panic, [restriction]);
+prefixed_lint_versioned!(1.48, clippy, panic_in_result_fn, [restriction]);
+// clippy::panic_params has been renamed to (prefixless)
+prefixed_lint_versioned!(1.95, clippy, panicking_overflow_checks, [correctness]);
+prefixed_lint!(clippy, panicking_unwrap, [correctness]);
+prefixed_lint_versioned!(1.66, clippy, partial_pub_fields, [restriction]);
+prefixed_lint!(clippy, partialeq_ne_impl, [complexity]);
+prefixed_lint_versioned!(1.65, clippy, partialeq_to_none, [style]);
+prefixed_lint!(clippy, path_buf_push_overwrite, [nursery]);
+prefixed_lint_versioned!(1.95, clippy, path_ends_with_ext, [suspicious]);
+prefixed_lint_versioned!(1.95, clippy, pathbuf_init_then_push, [restriction]);
+
+prefixed_lint_versioned!(1.47, clippy, pattern_type_mismatch, [restriction]);
+prefixed_lint_versioned!(1.68, clippy, permissions_set_readonly_false, [suspicious]);
+prefixed_lint_versioned!(1.95, clippy, pointer_format, [restriction]);
+prefixed_lint_versioned!(1.95, clippy, pointers_in_nomem_asm_block, [suspicious]);
+prefixed_lint!(clippy, possible_missing_comma, [correctness]);
+prefixed_lint_versioned!(1.95, clippy, possible_missing_else, [suspicious]);
+prefixed_lint!(clippy, precedence, [complexity]);
+prefixed_lint_versioned!(1.95, clippy, precedence_bits, [restriction]);
+prefixed_lint_versioned!(1.61, clippy, print_in_format_impl, [suspicious]);
+prefixed_lint!(clippy, print_literal, [style]);
+prefixed_lint_versioned!(1.50, clippy, print_stderr, [restriction]);
+prefixed_lint!(clippy, print_stdout, [restriction]);
+prefixed_lint!(clippy, print_with_newline, [style]);
+prefixed_lint!(clippy, println_empty_string, [style]);
+prefixed_lint!(clippy, ptr_arg, [style]);
+prefixed_lint_versioned!(1.51, clippy, ptr_as_ptr, [pedantic]);
+prefixed_lint_versioned!(1.95, clippy, ptr_cast_constness, [pedantic]);
+prefixed_lint_versioned!(1.49, clippy, ptr_eq, [style]);
+prefixed_lint_versioned!(1.95, clippy, ptr_offset_by_literal, [pedantic]);
+prefixed_lint!(clippy, ptr_offset_with_cast, [complexity]);
+// clippy:: pub_enum_variant_names has been removed. set the `avoid-breaking-exported-api` config
+// option to `false` to enable the `clippy::enum_variant_names` lint for public items.
+prefixed_lint_versioned!(1.95, clippy, pub_underscore_fields, [pedantic]);
+prefixed_lint_versioned!(1.62, clippy, pub_use, [restriction]);
+prefixed_lint_versioned!(1.95, clippy, pub_with_shorthand, [restriction]);
+prefixed_lint_versioned!(1.95, clippy, pub_without_shorthand, [restriction]);
+prefixed_lint!(clippy, question_mark, [style]);
+prefixed_lint_versioned!(1.69, clippy, question_mark_used, [restriction]);
+prefixed_lint!(clippy, range_minus_one, [pedantic]);
+prefixed_lint!(clippy, range_plus_one, [pedantic]);
+// clippy::range_step_by_zero is deprecated since at least 1.44.1
+prefixed_lint!(clippy, range_zip_with_len, [complexity]);
+prefixed_lint_versioned!(1.48, clippy, rc_buffer, [restriction]);
+prefixed_lint_versioned!(1.63, clippy, rc_clone_in_vec_init, [suspicious]);
+prefixed_lint_versioned!(1.55, clippy, rc_mutex, [restriction]);
+prefixed_lint_versioned!(1.95, clippy, read_line_without_trim, [correctness]);
+prefixed_lint_versioned!(1.63, clippy, read_zero_byte_vec, [nursery]);
+prefixed_lint_versioned!(1.95, clippy, readonly_write_lock, [perf]);
+prefixed_lint_versioned!(1.48, clippy, recursive_format_impl, [correctness]);
+prefixed_lint!(clippy, redundant_allocation, [perf]);
+prefixed_lint_versioned!(1.95, clippy, redundant_as_str, [complexity]);
+prefixed_lint_versioned!(1.69, clippy, redundant_async_block, [complexity]);
+prefixed_lint_versioned!(1.95, clippy, redundant_at_rest_pattern, [complexity]);
+prefixed_lint!(clippy, redundant_clone, [nursery]);
+prefixed_lint!(clippy, redundant_closure, [style]);
+prefixed_lint!(clippy, redundant_closure_call, [complexity]);
+prefixed_lint!(clippy, redundant_closure_for_method_calls, [pedantic]);
+prefixed_lint_versioned!(1.95, clippy, redundant_comparisons, [correctness]);
+prefixed_lint_versioned!(1.50, clippy, redundant_else, [pedantic]);
+prefixed_lint_versioned!(1.57, clippy, redundant_feature_names, [cargo]);
+prefixed_lint!(clippy, redundant_field_names, [style]);
+prefixed_lint_versioned!(1.95, clippy, redundant_guards, [complexity]);
+prefixed_lint_versioned!(1.95, clippy, redundant_iter_cloned, [perf]);
+prefixed_lint_versioned!(1.95, clippy, redundant_locals, [suspicious]);
+prefixed_lint!(clippy, redundant_pattern, [style]);
+prefixed_lint!(clippy, redundant_pattern_matching, [style]);
+prefixed_lint!(clippy, redundant_pub_crate, [nursery]);
+prefixed_lint_versioned!(1.51, clippy, redundant_slicing, [complexity]);
+prefixed_lint!(clippy, redundant_static_lifetimes, [style]);
+prefixed_lint_versioned!(1.95, clippy, redundant_test_prefix, [restriction]);
+prefixed_lint_versioned!(1.95, clippy, redundant_type_annotations, [restriction]);
+prefixed_lint_versioned!(1.95, clippy, ref_as_ptr, [pedantic]);
+prefixed_lint_versioned!(1.54, clippy, ref_binding_to_reference, [pedantic]);
+// clippy::ref_in_deref has been renamed to `clippy::needless_borrow`
+prefixed_lint_versioned!(1.95, clippy, ref_option, [pedantic]);
+prefixed_lint_versioned!(1.49, clippy, ref_option_ref, [pedantic]);
+prefixed_lint_versioned!(1.95, clippy, ref_patterns, [restriction]);
+prefixed_lint_versioned!(1.95, clippy, regex_creation_in_loops, [perf]);
+// clippy::regex_macro has been removed
+prefixed_lint_versioned!(1.95, clippy, renamed_function_params, [restriction]);
+prefixed_lint_versioned!(1.47, clippy, repeat_once, [complexity]);
+prefixed_lint_versioned!(1.95, clippy, repeat_vec_with_capacity, [suspicious]);
+prefixed_lint_versioned!(1.95, clippy, replace_box, [perf]);
+// clippy::replace_consts is deprecated since 1.45
+prefixed_lint_versioned!(1.95, clippy, repr_packed_without_abi, [suspicious]);
+prefixed_lint_versioned!(1.95, clippy, reserve_after_initialization, [complexity]);
+prefixed_lint!(clippy, rest_pat_in_fully_bound_structs, [restriction]);
+// clippy::result_expect_used has been renamed to `clippy::expect_used`
+prefixed_lint_versioned!(1.95, clippy, result_filter_map, [complexity]);
+prefixed_lint_versioned!(1.65, clippy, result_large_err, [perf]);
+prefixed_lint!(clippy, result_map_or_into_option, [style]);
+prefixed_lint!(clippy, result_map_unit_fn, [complexity]);
+// clippy::result_map_unwrap_or_else is renamed to `clippy::map_unwrap_or`
+prefixed_lint_versioned!(1.49, clippy, result_unit_err, [style]);
+// clippy::result_unwrap_used is renamed to `clippy::unwrap_used`
+prefixed_lint_versioned!(1.95, clippy, return_and_then, [restriction]);
+prefixed_lint_versioned!(1.59, clippy, return_self_not_must_use, [pedantic]);
+// clippy::reverse_range_loop is removed (since 1.45?)
+prefixed_lint!(clippy, reversed_empty_ranges, [correctness]);
+prefixed_lint!(clippy, same_functions_in_if_condition, [pedantic]);
+prefixed_lint_versioned!(1.47, clippy, same_item_push, [style]);
+prefixed_lint_versioned!(1.95, clippy, same_length_and_capacity, [pedantic]);
+prefixed_lint_versioned!(1.57, clippy, same_name_method, [restriction]);
+prefixed_lint!(clippy, search_is_some, [nursery]);
+prefixed_lint_versioned!(1.67, clippy, seek_from_current, [complexity]);
+prefixed_lint_versioned!(1.67, clippy, seek_to_start_instead_of_rewind, [complexity]);
+prefixed_lint_versioned!(1.48, clippy, self_assignment, [correctness]);
+prefixed_lint_versioned!(1.55, clippy, self_named_constructors, [style]);
+prefixed_lint_versioned!(1.57, clippy, self_named_module_files, [restriction]);
+prefixed_lint_versioned!(1.95, clippy, self_only_used_in_recursion, [pedantic]);
+prefixed_lint_versioned!(1.52, clippy, semicolon_if_nothing_returned, [pedantic]);
+prefixed_lint_versioned!(1.68, clippy, semicolon_inside_block, [restriction]);
+prefixed_lint_versioned!(1.68, clippy, semicolon_outside_block, [restriction]);
+prefixed_lint_versioned!(1.58, clippy, separated_literal_suffix, [restriction]);
+prefixed_lint!(clippy, serde_api_misuse, [correctness]);
+prefixed_lint_versioned!(1.95, clippy, set_contains_or_insert, [nursery]);
+prefixed_lint!(clippy, shadow_reuse, [restriction]);
+prefixed_lint!(clippy, shadow_same, [restriction]);
+prefixed_lint!(clippy, shadow_unrelated, [restriction]);
+prefixed_lint!(clippy, short_circuit_statement, [complexity]);
+// clippy::should_assert_eq is deprecated since at least 1.44.1
+prefixed_lint!(clippy, should_implement_trait, [style]);
+prefixed_lint_versioned!(1.95, clippy, should_panic_without_expect, [pedantic]);
+prefixed_lint_versioned!(1.60, clippy, significant_drop_in_scrutinee, [nursery]);
+prefixed_lint_versioned!(1.69, clippy, significant_drop_tightening, [nursery]);
+prefixed_lint!(clippy, similar_names, [pedantic]);
+prefixed_lint_versioned!(1.95, clippy, single_call_fn, [restriction]);
+prefixed_lint_versioned!(1.49, clippy, single_char_add_str, [style]);
+prefixed_lint_versioned!(1.60, clippy, single_char_lifetime_names, [restriction]);
+prefixed_lint!(clippy, single_char_pattern, [pedantic]);
+prefixed_lint!(clippy, single_component_path_imports, [style]);
+prefixed_lint_versioned!(1.49, clippy, single_element_loop, [complexity]);
+prefixed_lint!(clippy, single_match, [style]);
+prefixed_lint!(clippy, single_match_else, [pedantic]);
+prefixed_lint_versioned!(1.95, clippy, single_option_map, [nursery]);
+prefixed_lint_versioned!(1.95, clippy, single_range_in_vec_init, [suspicious]);
+prefixed_lint_versioned!(1.50, clippy, size_of_in_element_count, [correctness]);
+prefixed_lint_versioned!(1.68, clippy, size_of_ref, [suspicious]);
+prefixed_lint!(clippy, skip_while_next, [complexity]);
+prefixed_lint_versioned!(1.95, clippy, sliced_string_as_bytes, [perf]);
+prefixed_lint!(clippy, slow_vector_initialization, [perf]);
+prefixed_lint_versioned!(1.47, clippy, stable_sort_primitive, [pedantic]);
+prefixed_lint_versioned!(1.64, clippy, std_instead_of_alloc, [restriction]);
+prefixed_lint_versioned!(1.64, clippy, std_instead_of_core, [restriction]);
+prefixed_lint_versioned!(1.95, clippy, str_split_at_newline, [pedantic]);
+prefixed_lint!(clippy, str_to_string, [restriction]);
+prefixed_lint!(clippy, string_add, [restriction]);
+prefixed_lint!(clippy, string_add_assign, [pedantic]);
+prefixed_lint!(clippy, string_extend_chars, [style]);
+prefixed_lint_versioned!(1.50, clippy, string_from_utf8_as_bytes, [complexity]);
+prefixed_lint!(clippy, string_lit_as_bytes, [nursery]);
+prefixed_lint_versioned!(1.95, clippy, string_lit_chars_any, [restriction]);
+prefixed_lint_versioned!(1.58, clippy, string_slice, [restriction]);
+// clippy::string_to_string has been removed: `clippy::implicit_clone` covers those cases
+prefixed_lint_versioned!(1.55, clippy, strlen_on_c_strings, [complexity]);
+prefixed_lint!(clippy, struct_excessive_bools, [pedantic]);
+prefixed_lint_versioned!(1.95, clippy, struct_field_names, [pedantic]);
+prefixed_lint!(clippy, suboptimal_flops, [nursery]);
+prefixed_lint!(clippy, suspicious_arithmetic_impl, [suspicious]);
+prefixed_lint!(clippy, suspicious_assignment_formatting, [suspicious]);
+prefixed_lint_versioned!(1.69, clippy, suspicious_command_arg_space, [suspicious]);
+prefixed_lint_versioned!(1.70, clippy, suspicious_doc_comments, [suspicious]);
+prefixed_lint!(clippy, suspicious_else_formatting, [suspicious]);
+prefixed_lint!(clippy, suspicious_map, [suspicious]);
+prefixed_lint!(clippy, suspicious_op_assign_impl, [suspicious]);
+prefixed_lint_versioned!(1.95, clippy, suspicious_open_options, [suspicious]);
+prefixed_lint_versioned!(1.50, clippy, suspicious_operation_groupings, [nursery]);
+prefixed_lint_versioned!(1.54, clippy, suspicious_splitn, [correctness]);
+prefixed_lint_versioned!(1.65, clippy, suspicious_to_owned, [suspicious]);
+prefixed_lint!(clippy, suspicious_unary_op_formatting, [suspicious]);
+prefixed_lint_versioned!(1.67, clippy, suspicious_xor_used_as_pow, [restriction]);
+prefixed_lint_versioned!(1.63, clippy, swap_ptr_to_ref, [suspicious]);
+prefixed_lint_versioned!(1.95, clippy, swap_with_temporary, [complexity]);
+prefixed_lint!(clippy, tabs_in_doc_comments, [style]);
+prefixed_lint!(clippy, temporary_assignment, [complexity]);
+// clippy::temporary_cstring_as_ptr is renamed to (prefixless) temporary_cstring_as_ptr
+prefixed_lint_versioned!(1.95, clippy, test_attr_in_doctest, [suspicious]);
+prefixed_lint_versioned!(1.70, clippy, tests_outside_test_module, [restriction]);
+prefixed_lint!(clippy, to_digit_is_some, [style]);
+prefixed_lint_versioned!(1.58, clippy, to_string_in_format_args, [perf]);
+prefixed_lint_versioned!(1.95, clippy, to_string_trait_impl, [style]);
+prefixed_lint!(clippy, todo, [restriction]);
+prefixed_lint_versioned!(1.95, clippy, too_long_first_doc_paragraph, [nursery]);
+prefixed_lint!(clippy, too_many_arguments, [complexity]);
+prefixed_lint!(clippy, too_many_lines, [pedantic]);
+prefixed_lint!(clippy, toplevel_ref_arg, [style]);
+prefixed_lint_versioned!(1.58, clippy, trailing_empty_array, [nursery]);
+prefixed_lint_versioned!(1.47, clippy, trait_duplication_in_bounds, [nursery]);
+prefixed_lint!(clippy, transmute_bytes_to_str, [complexity]);
+// clippy::transmute_float_to_int has been renamed to (prefixless) `unnecessary_transmutes`
+prefixed_lint!(clippy, transmute_int_to_bool, [complexity]);
+// clippy::transmute_int_to_char has been renamed to (prefixless) `unnecessary_transmutes`
+// clippy::transmute_int_to_float has been renamed to (prefixless) `unnecessary_transmutes`
+prefixed_lint_versioned!(1.69, clippy, transmute_int_to_non_zero, [complexity]);
+prefixed_lint_versioned!(1.68, clippy, transmute_null_to_fn, [correctness]);
+// clippy::transmute_num_to_bytes has been renamed to (prefixless) `unnecessary_transmutes`
+prefixed_lint!(clippy, transmute_ptr_to_ptr, [pedantic]);
+prefixed_lint!(clippy, transmute_ptr_to_ref, [complexity]);
+prefixed_lint_versioned!(1.60, clippy, transmute_undefined_repr, [nursery]);
+prefixed_lint_versioned!(1.47, clippy, transmutes_expressible_as_ptr_casts, [complexity]);
+prefixed_lint!(clippy, transmuting_null, [correctness]);
+prefixed_lint_versioned!(1.62, clippy, trim_split_whitespace, [style]);
+prefixed_lint!(clippy, trivial_regex, [nursery]);
+prefixed_lint!(clippy, trivially_copy_pass_by_ref, [pedantic]);
+prefixed_lint!(clippy, try_err, [restriction]);
+prefixed_lint_versioned!(1.95, clippy, tuple_array_conversions, [nursery]);
+prefixed_lint!(clippy, type_complexity, [complexity]);
+prefixed_lint_versioned!(1.95, clippy, type_id_on_box, [suspicious]);
+prefixed_lint!(clippy, type_repetition_in_bounds, [nursery]);
+prefixed_lint_versioned!(1.95, clippy, unbuffered_bytes, [perf]);
+// clippy::unchecked_duration_subtraction has been renamed to `clippy::unchecked_time_subtraction`
+prefixed_lint_versioned!(1.95, clippy, unchecked_time_subtraction, [pedantic]);
+prefixed_lint_versioned!(1.95, clippy, unconditional_recursion, [suspicious]);
+prefixed_lint_versioned!(1.58, clippy, undocumented_unsafe_blocks, [restriction]);
+// clippy::undropped_manually_drops has been renamed to (prefixless) `undropped_manually_drops`
+prefixed_lint!(clippy, unicode_not_nfc, [pedantic]);
+prefixed_lint!(clippy, unimplemented, [restriction]);
+prefixed_lint_versioned!(1.95, clippy, uninhabited_references, [nursery]);
+prefixed_lint!(clippy, uninit_assumed_init, [correctness]);
+prefixed_lint_versioned!(1.58, clippy, uninit_vec, [correctness]);
+prefixed_lint_versioned!(1.66, clippy, uninlined_format_args, [pedantic]);
+prefixed_lint!(clippy, unit_arg, [complexity]);
+prefixed_lint!(clippy, unit_cmp, [correctness]);
+prefixed_lint_versioned!(1.58, clippy, unit_hash, [correctness]);
+prefixed_lint_versioned!(1.47, clippy, unit_return_expecting_ord, [correctness]);
+prefixed_lint_versioned!(1.70, clippy, unnecessary_box_returns, [pedantic]);
+// clippy::unknown_clippy_lints is renamed to (prefixless rustc lint) `unknown_lints`
+prefixed_lint!(clippy, unnecessary_cast, [complexity]);
+prefixed_lint_versioned!(1.95, clippy, unnecessary_clippy_cfg, [suspicious]);
+prefixed_lint_versioned!(1.95, clippy, unnecessary_debug_formatting, [pedantic]);
+prefixed_lint_versioned!(1.95, clippy, unnecessary_fallible_conversions, [style]);
+prefixed_lint!(clippy, unnecessary_filter_map, [complexity]);
+prefixed_lint_versioned!(1.61, clippy, unnecessary_find_map, [complexity]);
+prefixed_lint_versioned!(1.95, clippy, unnecessary_first_then_check, [complexity]);
+prefixed_lint!(clippy, unnecessary_fold, [style]);
+prefixed_lint_versioned!(1.95, clippy, unnecessary_get_then_check, [suspicious]);
+prefixed_lint_versioned!(1.61, clippy, unnecessary_join, [pedantic]);
+prefixed_lint_versioned!(1.48, clippy, unnecessary_lazy_evaluations, [style]);
+prefixed_lint_versioned!(1.95, clippy, unnecessary_literal_bound, [pedantic]);
+prefixed_lint_versioned!(1.95, clippy, unnecessary_literal_unwrap, [complexity]);
+prefixed_lint_versioned!(1.95, clippy, unnecessary_map_on_constructor, [complexity]);
+prefixed_lint_versioned!(1.95, clippy, unnecessary_map_or, [style]);
+prefixed_lint_versioned!(1.95, clippy, unnecessary_min_or_max, [complexity]);
+prefixed_lint!(clippy, unnecessary_mut_passed, [style]);
+prefixed_lint!(clippy, unnecessary_operation, [complexity]);
+prefixed_lint_versioned!(1.95, clippy, unnecessary_option_map_or_else, [suspicious]);
+prefixed_lint_versioned!(1.62, clippy, unnecessary_owned_empty_strings, [style]);
+prefixed_lint_versioned!(1.95, clippy, unnecessary_result_map_or_else, [suspicious]);
+prefixed_lint_versioned!(1.67, clippy, unnecessary_safety_comment, [restriction]);
+prefixed_lint_versioned!(1.67, clippy, unnecessary_safety_doc, [restriction]);
+prefixed_lint_versioned!(1.53, clippy, unnecessary_self_imports, [restriction]);
+prefixed_lint_versioned!(1.95, clippy, unnecessary_semicolon, [pedantic]);
+prefixed_lint_versioned!(1.46, clippy, unnecessary_sort_by, [complexity]);
+prefixed_lint_versioned!(1.70, clippy, unnecessary_struct_initialization, [nursery]);
+prefixed_lint_versioned!(1.59, clippy, unnecessary_to_owned, [perf]);
+prefixed_lint_versioned!(1.95, clippy, unnecessary_trailing_comma, [pedantic]);
+prefixed_lint!(clippy, unnecessary_unwrap, [complexity]);
+prefixed_lint_versioned!(1.50, clippy, unnecessary_wraps, [pedantic]);
+prefixed_lint!(clippy, unneeded_field_pattern, [restriction]);
+prefixed_lint_versioned!(1.95, clippy, unneeded_struct_pattern, [style]);
+prefixed_lint!(clippy, unneeded_wildcard_pattern, [complexity]);
+prefixed_lint_versioned!(1.46, clippy, unnested_or_patterns, [pedantic]);
+prefixed_lint!(clippy, unreachable, [restriction]);
+prefixed_lint!(clippy, unreadable_literal, [pedantic]);
+prefixed_lint!(clippy, unsafe_derive_deserialize, [pedantic]);
+prefixed_lint!(clippy, unsafe_removed_from_name, [style]);
+// clippy::unsafe_vector_initialization is deprecated since at least 1.44.1
+prefixed_lint!(clippy, unseparated_literal_suffix, [restriction]);
+prefixed_lint!(clippy, unsound_collection_transmute, [correctness]);
+// clippy::unstable_as_mut_slice is deprecated since at least 1.44.1
+//
+// clippy::unstable_as_slice is deprecated since at least 1.44.1
+prefixed_lint_versioned!(1.54, clippy, unused_async, [pedantic]);
+// clippy::unused_collect is deprecated since at least 1.44.1
+prefixed_lint_versioned!(1.95, clippy, unused_enumerate_index, [style]);
+prefixed_lint_versioned!(1.66, clippy, unused_format_specs, [complexity]);
+prefixed_lint!(clippy, unused_io_amount, [correctness]);
+// clippy::unused_label is deprecated since at least 1.44.1
+prefixed_lint_versioned!(1.65, clippy, unused_peekable, [nursery]);
+prefixed_lint_versioned!(1.95, clippy, unused_result_ok, [restriction]);
+prefixed_lint_versioned!(1.63, clippy, unused_rounding, [nursery]);
+prefixed_lint!(clippy, unused_self, [pedantic]);
+prefixed_lint_versioned!(1.95, clippy, unused_trait_names, [restriction]);
+prefixed_lint!(clippy, unused_unit, [style]);
+prefixed_lint_versioned!(1.49, clippy, unusual_byte_groupings, [style]);
+prefixed_lint_versioned!(1.48, clippy, unwrap_in_result, [restriction]);
+prefixed_lint_versioned!(1.95, clippy, unwrap_or_default, [style]);
+// clippy::unwrap_or_else_default has been renamed to `clippy::unwrap_or_default`
+prefixed_lint_allowed!(clippy, unwrap_used, [restriction]);
+prefixed_lint_versioned!(1.51, clippy, upper_case_acronyms, [style]);
+prefixed_lint!(clippy, use_debug, [restriction]);
+prefixed_lint!(clippy, use_self, [nursery]);
+prefixed_lint!(clippy, used_underscore_binding, [pedantic]);
+prefixed_lint_versioned!(1.95, clippy, used_underscore_items, [pedantic]);
+prefixed_lint!(clippy, useless_asref, [complexity]);
+prefixed_lint!(clippy, useless_attribute, [correctness]);
+prefixed_lint_versioned!(1.95, clippy, useless_concat, [complexity]);
+prefixed_lint!(clippy, useless_conversion, [complexity]);
+prefixed_lint!(clippy, useless_format, [complexity]);
+prefixed_lint!(clippy, useless_let_if_seq, [nursery]);
+prefixed_lint_versioned!(1.95, clippy, useless_nonzero_new_unchecked, [complexity]);
+prefixed_lint!(clippy, useless_transmute, [complexity]);
+prefixed_lint!(clippy, useless_vec, [perf]);
+prefixed_lint!(clippy, vec_box, [complexity]);
+prefixed_lint_versioned!(1.51, clippy, vec_init_then_push, [perf]);
+prefixed_lint_versioned!(1.46, clippy, vec_resize_to_zero, [correctness]);
+prefixed_lint!(clippy, verbose_bit_mask, [pedantic]);
+prefixed_lint!(clippy, verbose_file_reads, [restriction]);
+prefixed_lint_versioned!(1.95, clippy, volatile_composites, [nursery]);
+// clippy::vtable_address_comparisons has been renamed to (prefixless) `ambiguous_wide_pointer_comparisons`
+prefixed_lint_versioned!(1.95, clippy, waker_clone_wake, [perf]);
+prefixed_lint_versioned!(1.95, clippy, while_float, [nursery]);
+prefixed_lint!(clippy, while_immutable_condition, [correctness]);
+prefixed_lint!(clippy, while_let_loop, [complexity]);
+prefixed_lint!(clippy, while_let_on_iterator, [style]);
+prefixed_lint!(clippy, wildcard_dependencies, [cargo]);
+prefixed_lint!(clippy, wildcard_enum_match_arm, [restriction]);
+prefixed_lint!(clippy, wildcard_imports, [pedantic]);
+prefixed_lint!(clippy, wildcard_in_or_patterns, [complexity]);
+prefixed_lint!(clippy, write_literal, [style]);
+prefixed_lint!(clippy, write_with_newline, [style]);
+prefixed_lint!(clippy, writeln_empty_string, [style]);
+// clippy::wrong_pub_self_convention has been removed: set the `avoid-breaking-exported-api` config
+// option to `false` to enable the `clippy::wrong_self_convention` lint for public items.
+prefixed_lint!(clippy, wrong_self_convention, [style]);
+prefixed_lint!(clippy, wrong_transmute, [correctness]);
+prefixed_lint!(clippy, zero_divided_by_zero, [complexity]);
+prefixed_lint!(clippy, zero_prefixed_literal, [complexity]);
+prefixed_lint!(clippy, zero_ptr, [style]);
+prefixed_lint_versioned!(1.95, clippy, zero_repeat_side_effects, [suspicious]);
+prefixed_lint_versioned!(1.50, clippy, zero_sized_map_values, [pedantic]);
+// clippy::zero_width_space renamed in 1.49 to clippy::invisible_characters
+prefixed_lint_versioned!(1.95, clippy, zombie_processes, [suspicious]);
+prefixed_lint!(clippy, zst_offset, [correctness]);
+
+// TODO compile test that the following fails - BUT ONLY with `cargo clippy`
+// prefixed_lint!(clippy, WRONG_LINT);
+
+// @TODO negative compile tests:
+//
+// with `cargo doc`:
+//
+// ::allow_internal::check_that_prefixed_lint_exists!(rustdoc, bufo);
+//
+// with `cargo clippy`:
+//
+// ::allow_internal::check_that_prefixed_lint_exists!(clippy, bufo);
+//
+// standard_lint!(non_existing_std_lint);
+
+// Lint groups. Generated only with `groups` cargo feature (and the respective `rustc` or `clippy`
+// feature). Their members are not listed here, but build.rs derives them from the lint declarations
+// above: from the groups in the trailing brackets of standard lints (like `[unused]`), and from the
+// categories of `clippy::` lints (see `emit_lint_table` in build.rs). As of Rust 1.95, based on
+// `rustc -W help` and `clippy-driver -W help`.
+//
+// Not here:
+// - warnings is a group of all lints that are set to issue warnings.
+// - keyword_idents is (also) a lint. See above.
+// - future_incompatible, rust_2021_compatibility and rust_2024_compatibility - at crate level only.
+// - clippy::all is a group of clippy::correctness, clippy::suspicious, clippy::style,
+//   clippy::complexity and clippy::perf. Its members are groups, hence listed.
+
+standard_lint_group!(let_underscore, 1.66);
+standard_lint_group!(nonstandard_style, 1.45);
+standard_lint_group!(rust_2018_compatibility, 1.45);
+standard_lint_group!(rust_2018_idioms, 1.45);
+standard_lint_group!(unused, 1.45);
+clippy_lint_group!(cargo, clippy_cargo, 1.45);
+clippy_lint_group!(complexity, clippy_complexity, 1.45);
+clippy_lint_group!(correctness, clippy_correctness, 1.45);
+clippy_lint_group!(nursery, clippy_nursery, 1.45);
+clippy_lint_group!(pedantic, clippy_pedantic, 1.45);
+clippy_lint_group!(perf, clippy_perf, 1.45);
+clippy_lint_group!(restriction, clippy_restriction, 1.45);
+clippy_lint_group!(style, clippy_style, 1.45);
+clippy_lint_group!(suspicious, clippy_suspicious, 1.57);
+clippy_lint_group!(all, clippy_all, 1.45, [complexity, correctness, perf, style, suspicious]);
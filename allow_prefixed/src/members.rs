@@ -0,0 +1,463 @@
+//! Suppressing lints on individual fields and variants, like
+//! `#[members(dead_code = [Variant1, field_x], clippy::struct_excessive_bools = [flags])]` on a
+//! struct, an enum or a union. Attribute macros can't be placed on fields or variants themselves,
+//! so this container attribute walks the item's tokens (without `syn`), and it injects
+//! `#[allow(lint_path)]` onto the named members.
+//!
+//! Fields of a tuple struct are named by their index, like `#[members(dead_code = [0])]`.
+
+use proc_macro::{Delimiter, Group, Ident, Punct, Spacing, Span, TokenStream, TokenTree};
+
+use crate::auxiliary;
+use crate::config::{self, Config};
+use crate::deny_by_default;
+use crate::lint_table;
+use crate::metadata;
+use crate::params::{self, Params, ParseResult};
+use crate::policy;
+use crate::proc_builder;
+
+/// Example parameters of `#[members(...)]`, for error messages.
+const EXAMPLE: &str = "dead_code = [Variant1, field_x]";
+
+/// One `lint_path = [member, ...]` parameter (of `#[members(...)]`, or of
+/// [`#[methods(...)]`](crate::methods)).
+pub struct MemberLint {
+    /// `clippy` or `rustdoc`, or an empty string for a standard (prefixless) lint.
+    pub prefix: String,
+    pub lint: String,
+    /// Identifiers (of fields, variants or associated items), or integer literals (indexes of tuple
+    /// struct fields).
+    pub members: Vec<TokenTree>,
+}
+
+impl MemberLint {
+    /// `prefix::lint`, or just `lint` for a standard lint.
+    pub fn lint_path(&self) -> String {
+        if self.prefix.is_empty() {
+            self.lint.clone()
+        } else {
+            format!("{}::{}", self.prefix, self.lint)
+        }
+    }
+
+    /// `#[allow(lint_path)]` (or its variations - see
+    /// [`proc_builder::brackets_allow_tool_lint_params_parts`]) to inject onto a member, with the
+    /// reason (if any) from `params`.
+    pub fn allow_attribute(&self, params: &Params, span: Span) -> TokenStream {
+        auxiliary::token_streams_to_stream(&[
+            proc_builder::get_hash(span),
+            proc_builder::brackets_allow_tool_lint_params_parts(
+                &self.prefix,
+                &self.lint,
+                params,
+                span,
+            ),
+        ])
+    }
+}
+
+/// Injection of the parsed parameters onto the members of an item (see [`output`]).
+pub type Inject = fn(&[MemberLint], &Params, TokenStream) -> ParseResult<TokenStream>;
+
+/// The whole output of `#[members(...)]`: `item` with `#[allow(lint_path)]` injected onto the
+/// members named in `given_attrs`. See [`output`].
+pub fn allow_members_output(given_attrs: TokenStream, item: TokenStream) -> TokenStream {
+    output("members", EXAMPLE, given_attrs, item, inject)
+}
+
+/// The whole output of attribute `macro_name` (`members` or `methods`): `item` with the allows
+/// injected by `inject`. Like an alias, it checks the reason (if the consumer's configuration
+/// requires one - see [`params::check_reason`]), and for each lint the required metadata (see
+/// [`metadata::check_required`]) and the acknowledgement of a deny-by-default lint (see
+/// [`deny_by_default::acknowledge_deny`]). If the configuration or `given_attrs` are malformed, or
+/// they name a member that the item doesn't have, return an error, followed by the item as-is.
+pub fn output(
+    macro_name: &str,
+    example: &str,
+    given_attrs: TokenStream,
+    item: TokenStream,
+    inject: Inject,
+) -> TokenStream {
+    let result = config::load(Span::call_site()).and_then(|config| {
+        let (member_lints, params) = parse(macro_name, example, given_attrs, &config)?;
+        params::check_reason(macro_name, &params, &config)?;
+        let span = proc_builder::consumer_span(&item);
+        // `acknowledge_deny` applies to all the lints, so it's enough that one of them is
+        // deny-by-default.
+        let any_deny_by_default = member_lints
+            .iter()
+            .any(|member_lint| lint_table::is_deny_by_default(&member_lint.lint_path()));
+        let mut notices = Vec::new();
+        for member_lint in &member_lints {
+            let lint_path = member_lint.lint_path();
+            metadata::check_required(macro_name, &lint_path, &params, &config)?;
+            let deny_by_default = lint_table::is_deny_by_default(&lint_path);
+            if deny_by_default || !any_deny_by_default {
+                notices.extend(deny_by_default::acknowledge_deny(
+                    macro_name,
+                    &lint_path,
+                    deny_by_default,
+                    &params,
+                    &config,
+                    proc_builder::has_notice_place(&item),
+                    span,
+                )?);
+            }
+        }
+        let mut output = inject(&member_lints, &params, item.clone())?;
+        for member_lint in &member_lints {
+            output = metadata::with_doc(&member_lint.lint_path(), &params, output, span);
+        }
+        let output = proc_builder::track_files(output, &config, span);
+        Ok(if notices.is_empty() {
+            output
+        } else {
+            proc_builder::with_notice(output, Some(auxiliary::token_streams_to_stream(&notices)))
+        })
+    });
+    match result {
+        Ok(output) => output,
+        Err(error) => auxiliary::token_streams_to_stream(&[error, item]),
+    }
+}
+
+/// Error about the parameters of attribute `macro_name` (`members` or `methods`), or about the item
+/// it's applied to.
+pub fn error(macro_name: &str, message: &str, span: Span) -> TokenStream {
+    proc_builder::compile_error(
+        &format!(
+            "Attribute `{}` (under whatever name you imported it): {}",
+            macro_name, message
+        ),
+        span,
+    )
+}
+
+/// Parse `given_attrs` of attribute `macro_name`: `lint_path = [member, ...]`, separated by commas,
+/// optionally with `reason = "..."`, `acknowledge_deny = true` and metadata (with keys from the
+/// consumer's `config`), which apply to all of them. Param `example` shows such parameters (for
+/// error messages).
+pub fn parse(
+    macro_name: &str,
+    example: &str,
+    given_attrs: TokenStream,
+    config: &Config,
+) -> ParseResult<(Vec<MemberLint>, Params)> {
+    let mut member_lints = Vec::new();
+    // The parameters other than lint paths, separated by commas, for `params::parse`.
+    let mut given_params = Vec::new();
+    for param in params::split_by_commas(given_attrs) {
+        match param.as_slice() {
+            [] => {}
+            [TokenTree::Ident(name), TokenTree::Punct(eq), value]
+                if eq.as_char() == '='
+                    && !matches!(value, TokenTree::Group(_))
+                    && (name.to_string() == "reason"
+                        || name.to_string() == "acknowledge_deny"
+                        || config.metadata_keys.contains(&name.to_string())) =>
+            {
+                if !given_params.is_empty() {
+                    given_params.push(TokenTree::Punct(Punct::new(',', Spacing::Alone)));
+                }
+                given_params.extend(param);
+            }
+            _ => member_lints.push(parse_member_lint(macro_name, example, &param)?),
+        }
+    }
+    let params = params::parse(
+        macro_name,
+        auxiliary::token_trees_to_stream(&given_params),
+        config,
+    )?;
+    if member_lints.is_empty() {
+        return Err(error(
+            macro_name,
+            &format!("Expecting `lint_path = [member, ...]`, like `{}`.", example),
+            Span::call_site(),
+        ));
+    }
+    Ok((member_lints, params))
+}
+
+/// `prefix::lint`, or just `lint` if `prefix` is empty.
+pub fn lint_path(prefix: &str, lint: &Ident) -> String {
+    if prefix.is_empty() {
+        lint.to_string()
+    } else {
+        format!("{}::{}", prefix, lint)
+    }
+}
+
+/// Parse `lint = [...]` or `prefix::lint = [...]`.
+fn parse_member_lint(
+    macro_name: &str,
+    example: &str,
+    param: &[TokenTree],
+) -> ParseResult<MemberLint> {
+    let error = |message: &str, span: Span| error(macro_name, message, span);
+    let malformed = |span: Span| {
+        error(
+            &format!("Expecting `lint_path = [member, ...]`, like `{}`.", example),
+            span,
+        )
+    };
+    let (prefix, lint, rest) = match params::split_lint_path(param) {
+        Some(lint_path) => lint_path,
+        None => return Err(malformed(param[0].span())),
+    };
+    let group = match rest {
+        [TokenTree::Punct(eq), TokenTree::Group(group)]
+            if eq.as_char() == '=' && group.delimiter() == Delimiter::Bracket =>
+        {
+            group
+        }
+        _ => return Err(malformed(param[0].span())),
+    };
+    let mut members = Vec::new();
+    for member in params::split_by_commas(group.stream()) {
+        match member.as_slice() {
+            [] => {}
+            [name @ TokenTree::Ident(_)] | [name @ TokenTree::Literal(_)] => {
+                members.push(name.clone())
+            }
+            _ => {
+                return Err(error(
+                    "Expecting a name of a member (or an index of a tuple struct field).",
+                    member[0].span(),
+                ))
+            }
+        }
+    }
+    if members.is_empty() {
+        return Err(error(
+            &format!("Expecting at least one member for lint `{}`.", lint),
+            group.span(),
+        ));
+    }
+    policy::check(macro_name, &lint_path(&prefix, lint), param[0].span())?;
+    Ok(MemberLint {
+        prefix,
+        lint: lint.to_string(),
+        members,
+    })
+}
+
+/// The body of a struct, an enum or a union.
+enum Body {
+    /// Named fields (of a struct or a union), or variants (of an enum), in braces.
+    Named(Group),
+    /// Fields of a tuple struct, in parentheses.
+    Tuple(Group),
+    /// A unit struct has no members.
+    None,
+}
+
+fn inject(
+    member_lints: &[MemberLint],
+    params: &Params,
+    item: TokenStream,
+) -> ParseResult<TokenStream> {
+    let span = proc_builder::consumer_span(&item);
+    let mut tokens: Vec<TokenTree> = item.into_iter().collect();
+    let (item_name, body_index) = locate_body(&tokens)?;
+    let body = match body_index {
+        Some(index) => match &tokens[index] {
+            TokenTree::Group(group) if group.delimiter() == Delimiter::Brace => {
+                Body::Named(group.clone())
+            }
+            TokenTree::Group(group) => Body::Tuple(group.clone()),
+            _ => Body::None,
+        },
+        None => Body::None,
+    };
+    let members = match &body {
+        Body::Named(group) | Body::Tuple(group) => split_members(group.stream()),
+        Body::None => Vec::new(),
+    };
+    let names: Vec<Option<String>> = members
+        .iter()
+        .enumerate()
+        .map(|(index, member)| match &body {
+            Body::Tuple(_) if !member.is_empty() => Some(index.to_string()),
+            _ => member_name(member),
+        })
+        .collect();
+
+    let mut injected: Vec<Vec<TokenStream>> = vec![Vec::new(); members.len()];
+    for member_lint in member_lints {
+        for requested in &member_lint.members {
+            let requested_str = requested.to_string();
+            let index = match names
+                .iter()
+                .position(|name| name.as_ref() == Some(&requested_str))
+            {
+                Some(index) => index,
+                None => {
+                    return Err(error(
+                        "members",
+                        &format!(
+                            "`{}` has no field or variant `{}`.",
+                            item_name, requested_str
+                        ),
+                        requested.span(),
+                    ))
+                }
+            };
+            injected[index].push(member_lint.allow_attribute(params, span));
+        }
+    }
+
+    let (group, index) = match (body, body_index) {
+        (Body::Named(group), Some(index)) | (Body::Tuple(group), Some(index)) => (group, index),
+        _ => return Ok(auxiliary::token_trees_to_stream(&tokens)),
+    };
+    let mut new_body = Vec::with_capacity(members.len() * 2);
+    for (member_index, member) in members.iter().enumerate() {
+        if member_index > 0 {
+            new_body.push(TokenStream::from(TokenTree::Punct(Punct::new(
+                ',',
+                Spacing::Alone,
+            ))));
+        }
+        new_body.append(&mut injected[member_index]);
+        new_body.push(auxiliary::token_trees_to_stream(member));
+    }
+    let mut new_group = Group::new(
+        group.delimiter(),
+        auxiliary::token_streams_to_stream(&new_body),
+    );
+    new_group.set_span(group.span());
+    tokens[index] = TokenTree::Group(new_group);
+    Ok(auxiliary::token_trees_to_stream(&tokens))
+}
+
+/// Locate the item's name, and the index (if any) of its body (a group) in `tokens`. Return an
+/// error if the item is not a struct, an enum or a union.
+pub fn locate_body(tokens: &[TokenTree]) -> ParseResult<(String, Option<usize>)> {
+    let mut index = skip_attributes_and_visibility(tokens);
+    let keyword = match tokens.get(index) {
+        Some(TokenTree::Ident(keyword)) => keyword.to_string(),
+        _ => String::new(),
+    };
+    if keyword != "struct" && keyword != "enum" && keyword != "union" {
+        return Err(error(
+            "members",
+            "Expecting a struct, an enum or a union (to suppress lints on its fields or variants).",
+            Span::call_site(),
+        ));
+    }
+    index += 1;
+    let item_name = match tokens.get(index) {
+        Some(name) => name.to_string(),
+        None => String::new(),
+    };
+    index += 1;
+    // Skip generics (which may contain parentheses, like `<F: Fn(u8)>`) and any `where` clause.
+    let mut depth = 0;
+    let mut in_where_clause = false;
+    while index < tokens.len() {
+        let token = &tokens[index];
+        depth += angle_depth_change(index.checked_sub(1).map(|i| &tokens[i]), token);
+        if depth == 0 {
+            match token {
+                TokenTree::Ident(ident) if ident.to_string() == "where" => in_where_clause = true,
+                TokenTree::Group(group) if group.delimiter() == Delimiter::Brace => {
+                    return Ok((item_name, Some(index)))
+                }
+                TokenTree::Group(group)
+                    if group.delimiter() == Delimiter::Parenthesis
+                        && !in_where_clause
+                        && keyword == "struct" =>
+                {
+                    return Ok((item_name, Some(index)))
+                }
+                TokenTree::Punct(punct) if punct.as_char() == ';' => return Ok((item_name, None)),
+                _ => {}
+            }
+        }
+        index += 1;
+    }
+    Ok((item_name, None))
+}
+
+/// Index of the first token after any outer attributes `#[...]` and visibility (like `pub` or
+/// `pub(crate)`) at the start of `tokens`.
+pub fn skip_attributes_and_visibility(tokens: &[TokenTree]) -> usize {
+    let mut index = 0;
+    while let [TokenTree::Punct(hash), TokenTree::Group(group), ..] = &tokens[index..] {
+        if hash.as_char() != '#' || group.delimiter() != Delimiter::Bracket {
+            break;
+        }
+        index += 2;
+    }
+    if let Some(TokenTree::Ident(ident)) = tokens.get(index) {
+        if ident.to_string() == "pub" {
+            index += 1;
+            if let Some(TokenTree::Group(group)) = tokens.get(index) {
+                if group.delimiter() == Delimiter::Parenthesis {
+                    index += 1;
+                }
+            }
+        }
+    }
+    index
+}
+
+/// Name of a named field or of a variant: the identifier after its attributes and visibility.
+pub fn member_name(member: &[TokenTree]) -> Option<String> {
+    match member.get(skip_attributes_and_visibility(member)) {
+        Some(TokenTree::Ident(name)) => Some(name.to_string()),
+        _ => None,
+    }
+}
+
+/// Split the body of a struct, an enum or a union into its members, by commas outside of angle
+/// brackets (like in `HashMap<K, V>`). A trailing comma results in an empty last member.
+pub fn split_members(body: TokenStream) -> Vec<Vec<TokenTree>> {
+    let mut members = vec![Vec::new()];
+    let mut depth = 0;
+    // After `=` (of an enum discriminant, like `A = 1 << 2`) we don't count angle brackets.
+    let mut in_discriminant = false;
+    let mut previous: Option<TokenTree> = None;
+    for token in body {
+        if !in_discriminant {
+            depth += angle_depth_change(previous.as_ref(), &token);
+        }
+        match &token {
+            TokenTree::Punct(punct) if depth == 0 && punct.as_char() == ',' => {
+                members.push(Vec::new());
+                in_discriminant = false;
+                previous = None;
+                continue;
+            }
+            TokenTree::Punct(punct)
+                if depth == 0 && punct.as_char() == '=' && punct.spacing() == Spacing::Alone =>
+            {
+                in_discriminant = true
+            }
+            _ => {}
+        }
+        members.last_mut().unwrap().push(token.clone());
+        previous = Some(token);
+    }
+    members
+}
+
+/// Change of the depth of angle brackets `<...>` (of generics) caused by `token`. A `>` right
+/// after a joint `-` or `=` is a part of `->` or `=>`, rather than a closing angle bracket.
+pub fn angle_depth_change(previous: Option<&TokenTree>, token: &TokenTree) -> i32 {
+    match token {
+        TokenTree::Punct(punct) if punct.as_char() == '<' => 1,
+        TokenTree::Punct(punct) if punct.as_char() == '>' => match previous {
+            Some(TokenTree::Punct(previous))
+                if previous.spacing() == Spacing::Joint
+                    && (previous.as_char() == '-' || previous.as_char() == '=') =>
+            {
+                0
+            }
+            _ => -1,
+        },
+        _ => 0,
+    }
+}
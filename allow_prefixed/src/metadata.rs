@@ -0,0 +1,62 @@
+//! Metadata of suppressions, like `#[alias(owner = "...", ticket = "PROJ-123")]`, validated
+//! against a schema configured by the consumer crate (see [`config`](crate::config)). It has no
+//! effect on the compilation. We keep it in a generated `#[doc = "..."]` on the item, so that
+//! rustdoc (and source scanners) can extract it.
+
+use proc_macro::{Span, TokenStream};
+
+use crate::config::Config;
+use crate::params::{self, Params};
+use crate::proc_builder;
+
+/// Check that `params` have all the metadata that `config` requires for `lint_path`. If not, return
+/// an error located at the alias use.
+pub fn check_required(
+    macro_name: &str,
+    lint_path: &str,
+    params: &Params,
+    config: &Config,
+) -> Result<(), TokenStream> {
+    let missing: Vec<&str> = config
+        .required_metadata(lint_path)
+        .iter()
+        .filter(|required| {
+            !params
+                .metadata
+                .iter()
+                .any(|(key, _)| key.to_string() == **required)
+        })
+        .map(String::as_str)
+        .collect();
+    if missing.is_empty() {
+        return Ok(());
+    }
+    Err(params::error(
+        macro_name,
+        &format!(
+            "Suppression of lint `{}` requires metadata `{}` (as configured under `[package.metadata.allow.required_metadata]` in your Cargo.toml). Add it like `{} = \"...\"`.",
+            lint_path,
+            missing.join("`, `"),
+            missing[0]
+        ),
+        Span::call_site(),
+    ))
+}
+
+/// Append `#[doc = "..."]` (a paragraph) with the metadata (if any) to the item's outer attributes.
+pub fn with_doc(lint_path: &str, params: &Params, item: TokenStream, span: Span) -> TokenStream {
+    if params.metadata.is_empty() {
+        return item;
+    }
+    let metadata: Vec<String> = params
+        .metadata
+        .iter()
+        .map(|(key, value)| format!("{} = {}", key, value))
+        .collect();
+    let doc = format!(
+        "Suppression of lint `{}`: {}.",
+        lint_path,
+        metadata.join(", ")
+    );
+    proc_builder::append_doc_paragraph(item, &doc, span)
+}
@@ -0,0 +1,157 @@
+//! Suppressing lints on individual associated items (methods, associated constants and types) of
+//! an `impl` or a `trait` block, like `#[methods(clippy::too_many_arguments = [new, with_all])]`.
+//! That's narrower than an alias on the whole block. Like [`members`](crate::members), it walks the
+//! block's tokens (without `syn`), and it injects `#[allow(lint_path)]` onto the named items.
+
+use proc_macro::{Delimiter, Group, Span, TokenStream, TokenTree};
+
+use crate::auxiliary;
+use crate::items;
+use crate::members::{self, angle_depth_change, skip_attributes_and_visibility, MemberLint};
+use crate::params::{Params, ParseResult};
+use crate::proc_builder;
+
+/// Example parameters of `#[methods(...)]`, for error messages.
+const EXAMPLE: &str = "clippy::too_many_arguments = [new, with_all]";
+
+/// The whole output of `#[methods(...)]`: `item` (an `impl` or a `trait` block) with
+/// `#[allow(lint_path)]` injected onto the associated items named in `given_attrs`. See
+/// [`members::output`].
+pub fn allow_methods_output(given_attrs: TokenStream, item: TokenStream) -> TokenStream {
+    members::output("methods", EXAMPLE, given_attrs, item, inject)
+}
+
+fn inject(
+    member_lints: &[MemberLint],
+    params: &Params,
+    item: TokenStream,
+) -> ParseResult<TokenStream> {
+    let span = proc_builder::consumer_span(&item);
+    let mut tokens: Vec<TokenTree> = item.into_iter().collect();
+    let (block_kind, body_index) = locate_body(&tokens)?;
+    let body = match &tokens[body_index] {
+        TokenTree::Group(group) => group.clone(),
+        _ => unreachable!("locate_body() returns an index of a group"),
+    };
+    let associated_items = split_associated_items(body.stream());
+    let names: Vec<Option<String>> = associated_items
+        .iter()
+        .map(|associated_item| associated_item_name(associated_item))
+        .collect();
+
+    let mut injected: Vec<Vec<TokenStream>> = vec![Vec::new(); associated_items.len()];
+    for member_lint in member_lints {
+        for requested in &member_lint.members {
+            let requested_str = requested.to_string();
+            match names
+                .iter()
+                .position(|name| name.as_ref() == Some(&requested_str))
+            {
+                Some(index) => injected[index].push(member_lint.allow_attribute(params, span)),
+                None => {
+                    return Err(members::error(
+                        "methods",
+                        &format!(
+                        "This `{}` block has no associated item (method, constant or type) `{}`.",
+                        block_kind, requested_str
+                    ),
+                        requested.span(),
+                    ))
+                }
+            }
+        }
+    }
+
+    let mut new_body = Vec::with_capacity(associated_items.len() * 2);
+    for (index, associated_item) in associated_items.iter().enumerate() {
+        new_body.append(&mut injected[index]);
+        new_body.push(auxiliary::token_trees_to_stream(associated_item));
+    }
+    let mut new_group = Group::new(
+        Delimiter::Brace,
+        auxiliary::token_streams_to_stream(&new_body),
+    );
+    new_group.set_span(body.span());
+    tokens[body_index] = TokenTree::Group(new_group);
+    Ok(auxiliary::token_trees_to_stream(&tokens))
+}
+
+/// Locate the block's kind (`impl` or `trait`), and the index of its body (a brace group) in
+/// `tokens`. Return an error if the item is not an `impl` or a `trait` block.
+fn locate_body(tokens: &[TokenTree]) -> ParseResult<(&'static str, usize)> {
+    let mut block_kind = None;
+    let mut depth = 0;
+    for index in skip_attributes_and_visibility(tokens)..tokens.len() {
+        let token = &tokens[index];
+        depth += angle_depth_change(index.checked_sub(1).map(|i| &tokens[i]), token);
+        if depth > 0 {
+            continue;
+        }
+        match token {
+            // Qualifiers, like `unsafe impl` or `auto trait`.
+            TokenTree::Ident(ident) if block_kind.is_none() => match ident.to_string().as_str() {
+                "impl" => block_kind = Some("impl"),
+                "trait" => block_kind = Some("trait"),
+                "unsafe" | "auto" | "default" => {}
+                _ => break,
+            },
+            TokenTree::Group(group) if group.delimiter() == Delimiter::Brace => {
+                if let Some(block_kind) = block_kind {
+                    return Ok((block_kind, index));
+                }
+                break;
+            }
+            _ => {}
+        }
+    }
+    Err(members::error(
+        "methods",
+        "Expecting an `impl` or a `trait` block (to suppress lints on its methods).",
+        Span::call_site(),
+    ))
+}
+
+/// Split the body of an `impl` or a `trait` block into its associated items (including any inner
+/// attributes `#![...]`, each as a separate "item").
+fn split_associated_items(body: TokenStream) -> Vec<Vec<TokenTree>> {
+    let tokens: Vec<TokenTree> = body.into_iter().collect();
+    let mut associated_items = Vec::new();
+    let mut index = 0;
+    while index < tokens.len() {
+        let end = match &tokens[index..] {
+            [TokenTree::Punct(hash), TokenTree::Punct(bang), TokenTree::Group(_), ..]
+                if hash.as_char() == '#' && bang.as_char() == '!' =>
+            {
+                3
+            }
+            rest => items::item_end(rest),
+        };
+        associated_items.push(tokens[index..index + end].to_vec());
+        index += end;
+    }
+    associated_items
+}
+
+/// Name of an associated function, constant or type: the identifier after `fn`, `const` or `type`
+/// (skipping qualifiers, like in `const unsafe fn`).
+fn associated_item_name(associated_item: &[TokenTree]) -> Option<String> {
+    let start = skip_attributes_and_visibility(associated_item);
+    let mut after_keyword = false;
+    for token in &associated_item[start..] {
+        match token {
+            TokenTree::Ident(ident) => {
+                let ident = ident.to_string();
+                match ident.as_str() {
+                    "fn" | "const" | "type" => after_keyword = true,
+                    "unsafe" | "async" | "extern" | "default" => {}
+                    _ if after_keyword => return Some(ident),
+                    _ => return None,
+                }
+            }
+            // The ABI, like in `extern "C" fn`.
+            TokenTree::Literal(_) => {}
+            _ => return None,
+        }
+    }
+    None
+}
@@ -0,0 +1,445 @@
+//! Parameters that the generated attribute macros accept, like
+//! `#[alias(cfg(test), reason = "...")]`. They are optional, separated by commas, in any order.
+
+use proc_macro::{Delimiter, Ident, Literal, Span, TokenStream, TokenTree};
+
+use crate::config::Config;
+use crate::proc_builder;
+
+/// Parsed (and validated) parameters of a generated attribute macro.
+#[derive(Default)]
+pub struct Params {
+    /// Configuration predicate: the tokens inside `cfg(...)`. If present, we inject
+    /// `#[cfg_attr(predicate, allow(...))]` instead of `#[allow(...)]`.
+    pub cfg: Option<TokenStream>,
+    /// A string literal from `reason = "..."`.
+    pub reason: Option<Literal>,
+    /// A string literal from `until_version = "major.minor.patch"`, and its parsed version. See
+    /// [`expiry`](crate::expiry).
+    pub until_version: Option<(Literal, Version)>,
+    /// A string literal from `until_rust = "major.minor"`, and its parsed version. See
+    /// [`expiry`](crate::expiry).
+    pub until_rust: Option<(Literal, Version)>,
+    /// Pairs of (key, string literal) from `key = "..."`, like `owner = "..."`, where the keys come
+    /// from the consumer's configuration. See [`metadata`](crate::metadata).
+    pub metadata: Vec<(Ident, Literal)>,
+    /// The parameter name, and the value, from `acknowledge_deny = true` (or `false`). See
+    /// [`deny_by_default`](crate::deny_by_default).
+    pub acknowledge_deny: Option<(Ident, bool)>,
+    /// From `document = true` (or `false`). See [`document`](crate::document).
+    pub document: Option<bool>,
+}
+
+/// A version `major.minor.patch`, comparable. A pre-release (like `2.0.0-beta.1`) is lower than the
+/// same version without the pre-release (but we don't compare pre-releases among themselves).
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord)]
+pub struct Version {
+    pub major: u64,
+    pub minor: u64,
+    pub patch: u64,
+    /// `false` for a pre-release.
+    pub release: bool,
+}
+
+impl Version {
+    /// Parse `major.minor.patch`, or `major.minor`, or `major` (the missing parts are zero). If
+    /// `allow_pre_release`, then accept a pre-release suffix `-...` and a build metadata suffix
+    /// `+...`, too.
+    pub fn parse(version: &str, allow_pre_release: bool) -> Option<Version> {
+        let (version, build_metadata) = match version.find('+') {
+            Some(plus) => (&version[..plus], true),
+            None => (version, false),
+        };
+        let (version, pre_release) = match version.find('-') {
+            Some(minus) => (&version[..minus], true),
+            None => (version, false),
+        };
+        if (build_metadata || pre_release) && !allow_pre_release {
+            return None;
+        }
+        let mut parts = Vec::with_capacity(3);
+        for part in version.split('.') {
+            if part.is_empty() || !part.chars().all(|c| c.is_ascii_digit()) {
+                return None;
+            }
+            parts.push(part.parse().ok()?);
+        }
+        if parts.len() > 3 {
+            return None;
+        }
+        parts.resize(3, 0);
+        Some(Version {
+            major: parts[0],
+            minor: parts[1],
+            patch: parts[2],
+            release: !pre_release,
+        })
+    }
+}
+
+impl std::fmt::Display for Version {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}.{}.{}", self.major, self.minor, self.patch)
+    }
+}
+
+/// Result of parsing. The error is a spanned `compile_error!(...)`.
+pub type ParseResult<T> = Result<T, TokenStream>;
+
+/// Parse parameters `given_attrs` of the generated macro `macro_name`. The consumer crate's `config`
+/// determines what metadata keys we accept.
+pub fn parse(macro_name: &str, given_attrs: TokenStream, config: &Config) -> ParseResult<Params> {
+    let mut params = Params::default();
+    for param in split_by_commas(given_attrs) {
+        let name = match param.first() {
+            Some(TokenTree::Ident(name)) => name.to_string(),
+            Some(other) => {
+                return Err(error(
+                    macro_name,
+                    &format!("Expecting a parameter name, but received `{}`.", other),
+                    other.span(),
+                ))
+            }
+            // An empty parameter, like a trailing comma.
+            None => continue,
+        };
+        let name_span = param[0].span();
+        match name.as_str() {
+            "cfg" => {
+                if params.cfg.is_some() {
+                    return Err(error(macro_name, "Duplicate parameter `cfg`.", name_span));
+                }
+                params.cfg = Some(parse_cfg(macro_name, &param)?);
+            }
+            "reason" => {
+                if params.reason.is_some() {
+                    return Err(error(
+                        macro_name,
+                        "Duplicate parameter `reason`.",
+                        name_span,
+                    ));
+                }
+                params.reason = Some(parse_string_value(macro_name, &param)?);
+            }
+            "until_version" => {
+                if params.until_version.is_some() {
+                    return Err(error(
+                        macro_name,
+                        "Duplicate parameter `until_version`.",
+                        name_span,
+                    ));
+                }
+                params.until_version = Some(parse_version_value(
+                    macro_name,
+                    &param,
+                    "`until_version = \"major.minor.patch\"`, like `until_version = \"2.0.0\"`",
+                )?);
+            }
+            "until_rust" => {
+                if params.until_rust.is_some() {
+                    return Err(error(
+                        macro_name,
+                        "Duplicate parameter `until_rust`.",
+                        name_span,
+                    ));
+                }
+                params.until_rust = Some(parse_version_value(
+                    macro_name,
+                    &param,
+                    "`until_rust = \"major.minor\"`, like `until_rust = \"1.80\"`",
+                )?);
+            }
+            "acknowledge_deny" => {
+                if params.acknowledge_deny.is_some() {
+                    return Err(error(
+                        macro_name,
+                        "Duplicate parameter `acknowledge_deny`.",
+                        name_span,
+                    ));
+                }
+                let value = parse_bool_value(macro_name, &param, "`acknowledge_deny = true`")?;
+                if let TokenTree::Ident(name) = &param[0] {
+                    params.acknowledge_deny = Some((name.clone(), value));
+                }
+            }
+            "document" => {
+                if params.document.is_some() {
+                    return Err(error(
+                        macro_name,
+                        "Duplicate parameter `document`.",
+                        name_span,
+                    ));
+                }
+                params.document = Some(parse_bool_value(macro_name, &param, "`document = true`")?);
+            }
+            _ if config.metadata_keys.contains(&name) => {
+                if params
+                    .metadata
+                    .iter()
+                    .any(|(key, _)| key.to_string() == name)
+                {
+                    return Err(error(
+                        macro_name,
+                        &format!("Duplicate metadata `{}`.", name),
+                        name_span,
+                    ));
+                }
+                let value = parse_string_value(macro_name, &param)?;
+                if string_value(&value).trim().is_empty() {
+                    return Err(error(
+                        macro_name,
+                        &format!("Metadata `{}` must not be empty.", name),
+                        value.span(),
+                    ));
+                }
+                if let TokenTree::Ident(key) = &param[0] {
+                    params.metadata.push((key.clone(), value));
+                }
+            }
+            _ => {
+                let metadata_keys = if config.metadata_keys.is_empty() {
+                    "none".to_owned()
+                } else {
+                    format!("`{}`", config.metadata_keys.join("`, `"))
+                };
+                return Err(error(
+                    macro_name,
+                    &format!(
+                        "Unknown parameter `{}`. Expecting `cfg(predicate)`, `reason = \"...\"`, `until_version = \"...\"`, `until_rust = \"...\"`, `acknowledge_deny = true`, `document = true`, or metadata with a key listed in `metadata` under `[package.metadata.allow]` in your Cargo.toml (listed: {}).",
+                        name, metadata_keys
+                    ),
+                    name_span,
+                ));
+            }
+        }
+    }
+    Ok(params)
+}
+
+/// If the consumer's `config` has `require_reason = true`, check that `params` have a non-empty
+/// `reason = "..."`. A missing reason is reported at the (bare) alias use.
+pub fn check_reason(macro_name: &str, params: &Params, config: &Config) -> ParseResult<()> {
+    if !config.require_reason {
+        return Ok(());
+    }
+    match &params.reason {
+        Some(reason) if string_value(reason).trim().is_empty() => Err(error(
+            macro_name,
+            "Parameter `reason` must not be empty (as configured with `require_reason = true` under `[package.metadata.allow]` in your Cargo.toml).",
+            reason.span(),
+        )),
+        Some(_) => Ok(()),
+        None => Err(error(
+            macro_name,
+            "Expecting `reason = \"...\"` (as configured with `require_reason = true` under `[package.metadata.allow]` in your Cargo.toml). Why do you suppress this lint?",
+            Span::call_site(),
+        )),
+    }
+}
+
+/// Error about a parameter of the generated macro `macro_name`.
+pub fn error(macro_name: &str, message: &str, span: Span) -> TokenStream {
+    proc_builder::compile_error(
+        &format!(
+            "Alias `{}` (under whatever name you imported it): {}",
+            macro_name, message
+        ),
+        span,
+    )
+}
+
+/// Split a lint path (`lint_name`, or `prefix::lint_name`) off the start of `tokens`. Return the
+/// prefix (an empty string if none), the lint name, and the rest of `tokens`. Return `None` if
+/// `tokens` don't start with a lint path. The prefix can be any tool (not only `clippy` or
+/// `rustdoc`), since rustc accepts lints of Dylint libraries and of `#![register_tool(...)]` tools.
+pub fn split_lint_path(tokens: &[TokenTree]) -> Option<(String, &Ident, &[TokenTree])> {
+    match tokens {
+        [TokenTree::Ident(prefix), TokenTree::Punct(first), TokenTree::Punct(second), TokenTree::Ident(lint), rest @ ..]
+            if first.as_char() == ':' && second.as_char() == ':' =>
+        {
+            Some((prefix.to_string(), lint, rest))
+        }
+        [TokenTree::Ident(lint), rest @ ..] => Some((String::new(), lint, rest)),
+        _ => None,
+    }
+}
+
+/// Split `tokens` by (top level) commas.
+pub fn split_by_commas(tokens: TokenStream) -> Vec<Vec<TokenTree>> {
+    let mut parts = vec![Vec::new()];
+    for token in tokens {
+        match &token {
+            TokenTree::Punct(punct) if punct.as_char() == ',' => parts.push(Vec::new()),
+            _ => parts.last_mut().unwrap().push(token),
+        }
+    }
+    parts
+}
+
+/// Parse `name = true` or `name = false`. Param `expecting` describes the expected format (for the
+/// error message).
+fn parse_bool_value(macro_name: &str, param: &[TokenTree], expecting: &str) -> ParseResult<bool> {
+    if let [_, TokenTree::Punct(eq), TokenTree::Ident(value)] = param {
+        if eq.as_char() == '=' {
+            match value.to_string().as_str() {
+                "true" => return Ok(true),
+                "false" => return Ok(false),
+                _ => {}
+            }
+        }
+    }
+    Err(error(
+        macro_name,
+        &format!("Expecting {}.", expecting),
+        param[0].span(),
+    ))
+}
+
+/// Parse `cfg(predicate)`. Return the predicate.
+fn parse_cfg(macro_name: &str, param: &[TokenTree]) -> ParseResult<TokenStream> {
+    match param {
+        [_, TokenTree::Group(group)] if group.delimiter() == Delimiter::Parenthesis => {
+            let predicate: Vec<TokenTree> = group.stream().into_iter().collect();
+            if predicate.is_empty() {
+                return Err(error(
+                    macro_name,
+                    "Expecting a configuration predicate inside `cfg(...)`.",
+                    group.span(),
+                ));
+            }
+            check_predicate(macro_name, &predicate)?;
+            Ok(group.stream())
+        }
+        _ => Err(error(
+            macro_name,
+            "Expecting `cfg(predicate)`, like `cfg(test)` or `cfg(target_os = \"linux\")`.",
+            param[0].span(),
+        )),
+    }
+}
+
+/// Check that `predicate` is a well-formed configuration predicate: `name`, `name = "value"`,
+/// `all(...)`, `any(...)` or `not(...)`. See
+/// https://doc.rust-lang.org/nightly/reference/conditional-compilation.html.
+fn check_predicate(macro_name: &str, predicate: &[TokenTree]) -> ParseResult<()> {
+    let malformed = |span: Span| {
+        error(
+            macro_name,
+            "Malformed configuration predicate. Expecting `name`, `name = \"value\"`, `all(...)`, `any(...)` or `not(...)`.",
+            span,
+        )
+    };
+    match predicate {
+        [TokenTree::Ident(_)] => Ok(()),
+        [TokenTree::Ident(_), TokenTree::Punct(eq), TokenTree::Literal(value)]
+            if eq.as_char() == '=' =>
+        {
+            if is_string_literal(value) {
+                Ok(())
+            } else {
+                Err(error(
+                    macro_name,
+                    "Expecting a string literal as a configuration option value.",
+                    value.span(),
+                ))
+            }
+        }
+        [TokenTree::Ident(operator), TokenTree::Group(group)]
+            if group.delimiter() == Delimiter::Parenthesis =>
+        {
+            let operands: Vec<Vec<TokenTree>> = split_by_commas(group.stream())
+                .into_iter()
+                .filter(|operand| !operand.is_empty())
+                .collect();
+            match operator.to_string().as_str() {
+                "all" | "any" => {}
+                "not" if operands.len() == 1 => {}
+                "not" => {
+                    return Err(error(
+                        macro_name,
+                        "Expecting exactly one configuration predicate inside `not(...)`.",
+                        group.span(),
+                    ))
+                }
+                _ => return Err(malformed(operator.span())),
+            }
+            for operand in operands {
+                check_predicate(macro_name, &operand)?;
+            }
+            Ok(())
+        }
+        _ => Err(malformed(predicate[0].span())),
+    }
+}
+
+/// Parse `name = "value"`. Return the value.
+pub fn parse_string_value(macro_name: &str, param: &[TokenTree]) -> ParseResult<Literal> {
+    match param {
+        [_, TokenTree::Punct(eq), TokenTree::Literal(value)]
+            if eq.as_char() == '=' && is_string_literal(value) =>
+        {
+            Ok(value.clone())
+        }
+        _ => Err(error(
+            macro_name,
+            &format!("Expecting `{} = \"...\"`.", param[0]),
+            param[0].span(),
+        )),
+    }
+}
+
+/// Parse `name = "version"`. Return the value, and the parsed version. Param `expected` describes the
+/// expected parameter (for the error message).
+fn parse_version_value(
+    macro_name: &str,
+    param: &[TokenTree],
+    expected: &str,
+) -> ParseResult<(Literal, Version)> {
+    let literal = parse_string_value(macro_name, param)?;
+    match Version::parse(&string_value(&literal), false) {
+        Some(version) => Ok((literal, version)),
+        None => Err(error(
+            macro_name,
+            &format!("Expecting {}.", expected),
+            literal.span(),
+        )),
+    }
+}
+
+/// Whether `literal` is a (possibly raw) string literal.
+fn is_string_literal(literal: &Literal) -> bool {
+    let literal = literal.to_string();
+    literal.starts_with('"') || literal.starts_with("r\"") || literal.starts_with("r#")
+}
+
+/// The value of a (possibly raw) string literal, without the quotes. It doesn't unescape any escape
+/// sequences (we use it for values that don't need them, like versions).
+pub fn string_value(literal: &Literal) -> String {
+    let literal = literal.to_string();
+    let without_prefix = literal.trim_start_matches('r').trim_start_matches('#');
+    let without_suffix = without_prefix.trim_end_matches('#');
+    without_suffix
+        .trim_start_matches('"')
+        .trim_end_matches('"')
+        .to_owned()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Version;
+
+    #[test]
+    fn version_parse_and_compare() {
+        let v2 = Version::parse("2.0.0", false).unwrap();
+        assert_eq!(Version::parse("2", false), Some(v2));
+        assert_eq!(Version::parse("2.0", false), Some(v2));
+        assert!(Version::parse("2.0.0-beta.1", false).is_none());
+        assert!(Version::parse("2.0.0.0", false).is_none());
+        assert!(Version::parse("2.x", false).is_none());
+
+        assert!(Version::parse("2.0.0-beta.1", true).unwrap() < v2);
+        assert!(Version::parse("1.99.99", true).unwrap() < v2);
+        assert!(Version::parse("2.0.0+build", true).unwrap() >= v2);
+        assert!(Version::parse("2.0.1", true).unwrap() > v2);
+    }
+}
@@ -0,0 +1,386 @@
+//! A suppression policy for a whole workspace: lints that must not be suppressed in some crates,
+//! like `unsafe_op_in_unsafe_fn` in crypto crates, or `clippy::unwrap_used` in servers. The aliases
+//! (and `tool_lint`, `intent`, `members`, `methods`, `expr` and `stmt`) check it at expansion time.
+//! So the compiler itself enforces the policy, with no separate CI step.
+//!
+//! The policy file is
+//! - the file named by environment variable `ALLOW_POLICY` (relative to the directory of the crate
+//!   being compiled, unless absolute). Set it under `[env]` in `.cargo/config.toml`. Or else
+//! - the nearest `allow-policy.toml` in the directory of the crate being compiled
+//!   (`CARGO_MANIFEST_DIR`), or in any of its ancestors - typically at the workspace root.
+//!
+//! It consists of named rules, like
+//! ```toml
+//! [rules.no_unwrap_in_servers]
+//! lints = ["clippy::unwrap_used", "clippy::expect_used"]
+//! # Optional. A trailing `*` matches any suffix. If omitted, then all crates in the directory of
+//! # the policy file (or below) - that is, the workspace - but not dependencies from elsewhere.
+//! crates = ["api_server", "gateway_*"]
+//! # Optional. Appended to the error.
+//! message = "Servers must not panic. Handle the error instead."
+//! ```
+//!
+//! Lint groups (like `clippy::restriction`) stand for their members: A rule that lists a group
+//! forbids suppressing its members, and suppressing a group is forbidden if a rule forbids any of
+//! its members. Suppressing `warnings` (which covers all lints) is forbidden by any rule.
+
+use std::cell::RefCell;
+use std::path::{Path, PathBuf};
+use std::rc::Rc;
+use std::time::SystemTime;
+
+use proc_macro::Span;
+
+use crate::config::{self, Value};
+use crate::lint_table;
+use crate::params::ParseResult;
+use crate::proc_builder;
+
+/// The policy file that we look for in the crate's directory and its ancestors.
+const POLICY_TOML: &str = "allow-policy.toml";
+
+/// Environment variable that names the policy file (overriding [`POLICY_TOML`] lookup).
+const POLICY_ENV: &str = "ALLOW_POLICY";
+
+/// A loaded policy file: its path, its modification time (`None` if missing) when we loaded it, and
+/// its rules (or an error message, if it's unreadable or malformed).
+type Loaded = (PathBuf, Option<SystemTime>, Result<Rc<Vec<Rule>>, String>);
+
+thread_local! {
+    /// Policies loaded so far, so that we don't read and parse the file on each use of an alias.
+    /// Keyed like the configurations (see [`config::load`]): by the path, and by the modification
+    /// time.
+    static LOADED: RefCell<Vec<Loaded>> = RefCell::new(Vec::new());
+}
+
+/// A rule of the policy: lints that must not be suppressed (in some crates).
+struct Rule {
+    /// From the key, like `no_unwrap_in_servers` in `[rules.no_unwrap_in_servers]`.
+    name: String,
+    /// Lint paths, like `unsafe_op_in_unsafe_fn` or `clippy::unwrap_used`. From `lints = [...]`.
+    lints: Vec<String>,
+    /// Crate names, or prefixes followed by `*`. From `crates = [...]`. `None` means all crates in
+    /// the directory of the policy file (or below).
+    crates: Option<Vec<String>>,
+    /// Why, or what to do instead. From `message = "..."`.
+    message: Option<String>,
+}
+
+impl Rule {
+    /// The lint whose suppression this rule forbids (when suppressing `lint_path` in crate
+    /// `crate_name`, if known), if any: `lint_path` itself, or one of its members (if it's a lint
+    /// group), or the rule's first lint (if `lint_path` is `warnings`, which covers all lints).
+    /// `in_workspace` tells whether the crate is in the directory of the policy file (or below).
+    fn forbidden<'a>(
+        &'a self,
+        lint_path: &'a str,
+        crate_name: Option<&str>,
+        in_workspace: bool,
+    ) -> Option<&'a str> {
+        let applies = match (&self.crates, crate_name) {
+            (None, _) => in_workspace,
+            (Some(crates), Some(crate_name)) => crates
+                .iter()
+                .any(|pattern| crate_matches(pattern, crate_name)),
+            (Some(_), None) => false,
+        };
+        if !applies {
+            return None;
+        }
+        if lint_path == "warnings" {
+            return self.lints.first().map(String::as_str);
+        }
+        std::iter::once(lint_path)
+            .chain(lint_table::group_members(lint_path).iter().copied())
+            .find(|suppressed| {
+                self.lints.iter().any(|lint| {
+                    lint == suppressed || lint_table::group_members(lint).contains(suppressed)
+                })
+            })
+    }
+}
+
+/// Whether `crate_name` matches `pattern`: a crate name, or a prefix followed by `*`. Hyphens and
+/// underscores are interchangeable (as in Cargo package names vs. crate names).
+fn crate_matches(pattern: &str, crate_name: &str) -> bool {
+    let pattern = pattern.replace('-', "_");
+    let crate_name = crate_name.replace('-', "_");
+    match pattern.strip_suffix('*') {
+        Some(prefix) => crate_name.starts_with(prefix),
+        None => pattern == crate_name,
+    }
+}
+
+/// Check that the policy (if any) doesn't forbid suppressing `lint_path` (with attribute or macro
+/// `macro_name`) in the crate being compiled. If it does, return an error at `span` that names the
+/// rule. Return an error, too, if the policy file is malformed.
+pub fn check(macro_name: &str, lint_path: &str, span: Span) -> ParseResult<()> {
+    let (file_name, in_workspace, rules) = match load() {
+        Ok(Some(policy)) => policy,
+        Ok(None) => return Ok(()),
+        Err(message) => return Err(proc_builder::compile_error(&message, span)),
+    };
+    let crate_name = std::env::var("CARGO_PKG_NAME").ok();
+    match rules.iter().find_map(|rule| {
+        rule.forbidden(lint_path, crate_name.as_deref(), in_workspace)
+            .map(|forbidden| (rule, forbidden))
+    }) {
+        Some((rule, forbidden)) => {
+            let mut message = format!(
+                "Suppressing lint `{}` (with `{}`, under whatever name you imported it) in crate `{}` is forbidden by policy rule `{}` in {}.",
+                lint_path,
+                macro_name,
+                crate_name.as_deref().unwrap_or_default(),
+                rule.name,
+                file_name
+            );
+            if lint_path == "warnings" {
+                message.push_str(&format!(
+                    " (`warnings` covers all lints, including lint `{}`.)",
+                    forbidden
+                ));
+            } else if forbidden != lint_path {
+                message.push_str(&format!(" (The group includes lint `{}`.)", forbidden));
+            }
+            if let Some(rule_message) = &rule.message {
+                message.push(' ');
+                message.push_str(rule_message);
+            }
+            Err(proc_builder::compile_error(&message, span))
+        }
+        None => Ok(()),
+    }
+}
+
+/// The policy for the crate being compiled: the policy file's name (for messages), whether the
+/// crate is in the directory of the policy file (or below), and the rules.
+type Policy = (String, bool, Rc<Vec<Rule>>);
+
+/// Load the [`Policy`]. Return `None` if there's no policy file, or if we don't know the crate's
+/// directory (when not built by cargo). Return an error message if the policy file can't be read,
+/// or if it's malformed.
+///
+/// Loaded once (until the file changes). See [`LOADED`].
+fn load() -> Result<Option<Policy>, String> {
+    let dir = match std::env::var_os("CARGO_MANIFEST_DIR") {
+        Some(dir) => PathBuf::from(dir),
+        None => return Ok(None),
+    };
+    let path = match file(&dir) {
+        Some(path) => path,
+        None => return Ok(None),
+    };
+    let modified = std::fs::metadata(&path)
+        .and_then(|metadata| metadata.modified())
+        .ok();
+    let rules = LOADED.with(|loaded| {
+        let mut loaded = loaded.borrow_mut();
+        if let Some((_, _, rules)) = loaded.iter().find(|(loaded_path, loaded_modified, _)| {
+            *loaded_path == path && *loaded_modified == modified
+        }) {
+            return rules.clone();
+        }
+        let rules = load_uncached(&path).map(Rc::new);
+        loaded.retain(|(loaded_path, _, _)| *loaded_path != path);
+        loaded.push((path.clone(), modified, rules.clone()));
+        rules
+    })?;
+    let file_name = path
+        .file_name()
+        .map_or_else(String::new, |name| name.to_string_lossy().into_owned());
+    let in_workspace = path
+        .parent()
+        .map_or(false, |policy_dir| dir.starts_with(policy_dir));
+    Ok(Some((file_name, in_workspace, rules)))
+}
+
+/// Like [`load`], but read and parse the policy file at `path` each time. Return its rules.
+fn load_uncached(path: &Path) -> Result<Vec<Rule>, String> {
+    let content = std::fs::read_to_string(path)
+        .map_err(|error| format!("Can't read policy file {}: {}", path.display(), error))?;
+    parse(&content)
+        .map_err(|message| format!("Invalid policy file {}: {}", path.display(), message))
+}
+
+/// The policy file for the crate in `dir` (its `CARGO_MANIFEST_DIR`), if any: named by
+/// [`POLICY_ENV`], or else found by [`find`]. (It may not exist if named by [`POLICY_ENV`].)
+pub fn file(dir: &Path) -> Option<PathBuf> {
+    match std::env::var_os(POLICY_ENV) {
+        Some(path) => Some(dir.join(path)),
+        None => find(dir),
+    }
+}
+
+/// The nearest [`POLICY_TOML`] in `dir` or in its ancestors.
+fn find(dir: &Path) -> Option<PathBuf> {
+    dir.ancestors()
+        .map(|ancestor| ancestor.join(POLICY_TOML))
+        .find(|path| path.is_file())
+}
+
+/// Parse `content` of a policy file into its rules.
+fn parse(content: &str) -> Result<Vec<Rule>, String> {
+    let mut rules = Vec::new();
+    for entry in config::entries(content)? {
+        let key: Vec<&str> = entry.path.iter().map(String::as_str).collect();
+        let line = entry.line;
+        let at_line = |message: &str| format!("line {}: {}", line, message);
+        match (key.as_slice(), entry.value) {
+            (["rules", name, "lints"], Value::Array(lints)) => {
+                let lints = config::strings(lints)
+                    .ok_or_else(|| at_line("Expecting an array of lint paths."))?;
+                if let Some(unknown) = lints.iter().find(|lint| !lint_table::is_known(lint)) {
+                    return Err(at_line(&format!(
+                        "Unknown lint `{}` in rule `{}`.",
+                        unknown, name
+                    )));
+                }
+                rule_mut(&mut rules, name).lints = lints;
+            }
+            (["rules", name, "crates"], Value::Array(crates)) => {
+                let crates = config::strings(crates)
+                    .ok_or_else(|| at_line("Expecting an array of crate names."))?;
+                rule_mut(&mut rules, name).crates = Some(crates);
+            }
+            (["rules", name, "message"], Value::String(message)) => {
+                rule_mut(&mut rules, name).message = Some(message);
+            }
+            (["rules", _, "lints"], _) => return Err(at_line("Expecting an array of lint paths.")),
+            (["rules", _, "crates"], _) => return Err(at_line("Expecting an array of crate names.")),
+            (["rules", _, "message"], _) => return Err(at_line("Expecting a string.")),
+            _ => {
+                return Err(at_line(&format!(
+                    "Unknown key `{}`. Expecting `rules.<name>.lints` (with optional `rules.<name>.crates` and `rules.<name>.message`).",
+                    key.join(".")
+                )))
+            }
+        }
+    }
+    if let Some(rule) = rules.iter().find(|rule| rule.lints.is_empty()) {
+        return Err(format!("Rule `{}` has no `lints`.", rule.name));
+    }
+    Ok(rules)
+}
+
+/// The rule named `name`, created (with no lints) if not parsed yet.
+fn rule_mut<'a>(rules: &'a mut Vec<Rule>, name: &str) -> &'a mut Rule {
+    match rules.iter().position(|rule| rule.name == name) {
+        Some(index) => &mut rules[index],
+        None => {
+            rules.push(Rule {
+                name: name.to_owned(),
+                lints: Vec::new(),
+                crates: None,
+                message: None,
+            });
+            rules.last_mut().unwrap()
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{crate_matches, parse, Rule};
+
+    #[test]
+    fn parse_rules() {
+        let rules = parse(
+            r#"
+[rules.no_unsafe_in_crypto]
+lints = ["unsafe_op_in_unsafe_fn"]
+crates = ["crypto-*"] # a comment
+
+[rules.no_unwrap_in_servers]
+lints = ["clippy::unwrap_used"]
+crates = ["api_server"]
+message = "Servers must not panic."
+
+[rules]
+everywhere.lints = ["clippy::indexing_slicing"]
+"#,
+        )
+        .unwrap();
+        assert_eq!(rules.len(), 3);
+        let no_unsafe = &rules[0];
+        let forbids = |rule: &Rule, lint_path, crate_name| {
+            rule.forbidden(lint_path, crate_name, false).is_some()
+        };
+        assert!(forbids(
+            no_unsafe,
+            "unsafe_op_in_unsafe_fn",
+            Some("crypto_core")
+        ));
+        assert!(!forbids(
+            no_unsafe,
+            "unsafe_op_in_unsafe_fn",
+            Some("api_server")
+        ));
+        assert!(!forbids(no_unsafe, "unsafe_op_in_unsafe_fn", None));
+        assert!(!forbids(no_unsafe, "unsafe_code", Some("crypto_core")));
+        assert_eq!(rules[1].message.as_deref(), Some("Servers must not panic."));
+        // Without `crates`, only for the crates in the directory of the policy file (or below).
+        let everywhere = &rules[2];
+        assert!(everywhere
+            .forbidden("clippy::indexing_slicing", None, true)
+            .is_some());
+        assert!(!forbids(
+            everywhere,
+            "clippy::indexing_slicing",
+            Some("dependency")
+        ));
+
+        assert!(parse("[rules.x]\nlints = [\"unsafe_op_in_unsafe_f\"]\n").is_err());
+        assert!(parse("[rules.x]\ncrates = [\"server\"]\n").is_err());
+        assert!(parse("[rules.x]\nlints = \"unsafe_code\"\n").is_err());
+        assert!(parse("[rule.x]\nlints = [\"unsafe_code\"]\n").is_err());
+    }
+
+    #[test]
+    fn lint_groups() {
+        let rules = parse(
+            r#"
+[rules.no_unwrap]
+lints = ["clippy::unwrap_used"]
+
+[rules.no_restriction]
+lints = ["clippy::restriction"]
+"#,
+        )
+        .unwrap();
+        let (no_unwrap, no_restriction) = (&rules[0], &rules[1]);
+        // Suppressing a group suppresses its members.
+        assert_eq!(
+            no_unwrap.forbidden("clippy::restriction", None, true),
+            Some("clippy::unwrap_used")
+        );
+        assert_eq!(no_unwrap.forbidden("clippy::pedantic", None, true), None);
+        // A group in a rule stands for its members.
+        assert_eq!(
+            no_restriction.forbidden("clippy::expect_used", None, true),
+            Some("clippy::expect_used")
+        );
+        assert_eq!(
+            no_restriction.forbidden("clippy::restriction", None, true),
+            Some("clippy::restriction")
+        );
+        assert_eq!(no_restriction.forbidden("dead_code", None, true), None);
+        // `warnings` covers all lints.
+        assert_eq!(
+            no_unwrap.forbidden("warnings", None, true),
+            Some("clippy::unwrap_used")
+        );
+        assert_eq!(
+            no_unwrap.forbidden("warnings", Some("dependency"), false),
+            None
+        );
+    }
+
+    #[test]
+    fn crate_patterns() {
+        assert!(crate_matches("api_server", "api-server"));
+        assert!(crate_matches("crypto_*", "crypto_core"));
+        assert!(crate_matches("*", "anything"));
+        assert!(!crate_matches("crypto_*", "cryptography"));
+        assert!(!crate_matches("api_server", "api_server_tests"));
+    }
+}
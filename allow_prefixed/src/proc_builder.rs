@@ -1,56 +1,730 @@
-use proc_macro::{Delimiter, Group, Ident, Punct, Spacing, Span, TokenStream, TokenTree};
+use std::path::PathBuf;
+
+use proc_macro::{Delimiter, Group, Ident, Literal, Punct, Spacing, Span, TokenStream, TokenTree};
 
 use crate::auxiliary;
+use crate::config;
+use crate::deny_by_default;
+use crate::document;
+use crate::expiry;
+use crate::lint_table;
+use crate::members;
+use crate::metadata;
+use crate::params::{self, Params};
+use crate::policy;
 
 /// [`TokenStream`] consisting of one hash character: `#`. It serves as the leading character of the
 /// injected code (just left of the injected `#[allow(...)]`).
-pub fn get_hash() -> TokenStream {
-    TokenStream::from(TokenTree::Punct(Punct::new('#', Spacing::Alone)))
+pub fn get_hash(span: Span) -> TokenStream {
+    TokenStream::from(get_punct_alone('#', span))
 }
 
-fn get_colon_joint() -> TokenTree {
-    TokenTree::Punct(Punct::new(':', Spacing::Joint))
+fn get_punct_alone(c: char, span: Span) -> TokenTree {
+    let mut punct = Punct::new(c, Spacing::Alone);
+    punct.set_span(span);
+    TokenTree::Punct(punct)
 }
 
-fn get_clippy() -> TokenTree {
-    TokenTree::Ident(Ident::new("clippy", Span::call_site()))
+fn get_colon_joint(span: Span) -> TokenTree {
+    let mut colon = Punct::new(':', Spacing::Joint);
+    colon.set_span(span);
+    TokenTree::Punct(colon)
 }
 
-fn get_rustdoc() -> TokenTree {
-    TokenTree::Ident(Ident::new("rustdoc", Span::call_site()))
+fn get_ident_tree(name: &str, span: Span) -> TokenTree {
+    TokenTree::Ident(Ident::new(name, span))
 }
 
 /// [`TokenTree`] consisting of one identifier: `allow`.
-fn get_allow() -> TokenTree {
-    TokenTree::Ident(Ident::new("allow", Span::call_site()))
+fn get_allow(span: Span) -> TokenTree {
+    get_ident_tree("allow", span)
 }
 // -----
 
-/// Param `lint_str` is an empty string if the lint is prefixless (standard, "rustc" lint).
-pub fn brackets_allow_lint_parts(prefix_str: &str, lint_str: &str) -> TokenStream {
+/// Span for the injected code. It's located at the user's attribute (the alias, as the user
+/// invoked it), so that any errors/warnings about the injected `#[allow(...)]` point to the user's
+/// line. But it resolves (for hygiene, and for whether it comes from an external macro) as the
+/// first token of `item`, which is the user's code (or code generated by the user's `macro_rules!`,
+/// even if that comes from another crate).
+///
+/// Hence lints about the injected lint path (like `unknown_lints`) apply as configured in the
+/// consumer crate. See https://github.com/rust-lang/rust/issues/109881.
+pub fn consumer_span(item: &TokenStream) -> Span {
+    match item.clone().into_iter().next() {
+        Some(first) => Span::call_site().resolved_at(first.span()),
+        None => Span::call_site(),
+    }
+}
+
+/// `allow(lint_path)`, or `allow(lint_path, reason = "...")`, without the enclosing brackets. The
+/// `reason` keeps its own span (of the consumer's parameter).
+///
+/// Param `prefix_str` is an empty string if the lint is prefixless (standard, "rustc" lint). Otherwise
+/// it's any tool, like `clippy`, `rustdoc`, or a Dylint library or a `#![register_tool(...)]` tool.
+fn allow_lint_parts(
+    prefix_str: &str,
+    lint_str: &str,
+    reason: Option<&Literal>,
+    span: Span,
+) -> TokenStream {
     let prefix_lint = {
-        let lint = TokenTree::Ident(Ident::new(lint_str, Span::call_site()));
+        let lint = get_ident_tree(lint_str, span);
         if prefix_str.is_empty() {
             auxiliary::token_trees_to_stream(&[lint])
         //TokenStream::from_iter([lint])
         } else {
-            let prefix = match prefix_str {
-                "clippy" => get_clippy(),
-                "rustdoc" => get_rustdoc(),
-                _ => panic!("Unsupported prefix: {}.", prefix_str),
-            };
-            let colon = get_colon_joint(); //@TODO check
+            let prefix = get_ident_tree(prefix_str, span);
+            let colon = get_colon_joint(span); //@TODO check
             auxiliary::token_trees_to_stream(&[prefix, colon.clone(), colon, lint])
             //TokenStream::from_iter([prefix, colon.clone(), colon, lint])
         }
     };
 
-    let parens_lint_path = TokenTree::Group(Group::new(Delimiter::Parenthesis, prefix_lint));
+    let lint_reason = match reason {
+        Some(reason) => auxiliary::token_streams_to_stream(&[
+            prefix_lint,
+            auxiliary::token_trees_to_stream(&[
+                get_punct_alone(',', span),
+                get_ident_tree("reason", span),
+                get_punct_alone('=', span),
+                TokenTree::Literal(reason.clone()),
+            ]),
+        ]),
+        None => prefix_lint,
+    };
+
+    let mut parens_lint_path = TokenTree::Group(Group::new(Delimiter::Parenthesis, lint_reason));
+    parens_lint_path.set_span(span);
 
-    let allow_parens_lint_path = auxiliary::token_trees_to_stream(&[get_allow(), parens_lint_path]);
+    auxiliary::token_trees_to_stream(&[get_allow(span), parens_lint_path])
+}
 
-    TokenStream::from(TokenTree::Group(Group::new(
-        Delimiter::Bracket,
-        allow_parens_lint_path,
-    )))
+/// Enclose `inner` in brackets `[...]`.
+fn brackets(inner: TokenStream, span: Span) -> TokenStream {
+    let mut brackets = TokenTree::Group(Group::new(Delimiter::Bracket, inner));
+    brackets.set_span(span);
+    TokenStream::from(brackets)
+}
+
+/// `[allow(lint_path)]`, or `[allow(lint_path, reason = "...")]`.
+///
+/// Param `prefix_str` is an empty string if the lint is prefixless (standard, "rustc" lint). Otherwise
+/// it's any tool, like `clippy`, `rustdoc`, or a Dylint library or a `#![register_tool(...)]` tool.
+///
+/// Param `span` should come from [`consumer_span`]. It must NOT be `Span::call_site()`, but it MUST
+/// come from the consumer's code. See https://github.com/rust-lang/rust/issues/109881.
+pub fn brackets_allow_lint_parts(
+    prefix_str: &str,
+    lint_str: &str,
+    reason: Option<&Literal>,
+    span: Span,
+) -> TokenStream {
+    brackets(allow_lint_parts(prefix_str, lint_str, reason, span), span)
+}
+
+/// Like [`brackets_allow_lint_parts`], but `[cfg_attr(predicate, allow(lint_path))]`.
+pub fn brackets_cfg_attr_allow_lint_parts(
+    predicate: TokenStream,
+    prefix_str: &str,
+    lint_str: &str,
+    reason: Option<&Literal>,
+    span: Span,
+) -> TokenStream {
+    let cfg_attr_parts = auxiliary::token_streams_to_stream(&[
+        predicate,
+        TokenStream::from(get_punct_alone(',', span)),
+        allow_lint_parts(prefix_str, lint_str, reason, span),
+    ]);
+    let mut parens = TokenTree::Group(Group::new(Delimiter::Parenthesis, cfg_attr_parts));
+    parens.set_span(span);
+    brackets(
+        auxiliary::token_trees_to_stream(&[get_ident_tree("cfg_attr", span), parens]),
+        span,
+    )
+}
+
+/// Configuration predicate (if any) under which we inject `#[allow(...)]` for lints with the given
+/// prefix. It's `doc` for `rustdoc::` lints under cargo feature `tool_aware_rustdoc`, and `clippy`
+/// for `clippy::` lints under cargo feature `tool_aware_clippy`. Then the plain `rustc` build (and,
+/// for `rustdoc::`, a build by Rust older than 1.52) doesn't see those lint paths at all.
+pub fn tool_cfg(prefix_str: &str) -> Option<&'static str> {
+    match prefix_str {
+        "rustdoc" if cfg!(feature = "tool_aware_rustdoc") => Some("doc"),
+        "clippy" if cfg!(feature = "tool_aware_clippy") => Some("clippy"),
+        _ => None,
+    }
+}
+
+/// `[allow(lint_path, reason = "...")]`, or `[cfg_attr(predicate, allow(lint_path, reason =
+/// "..."))]` if [`tool_cfg`] applies, or if `params` has a `cfg` predicate (or both: then the
+/// predicate is `all(tool, cfg_predicate)`).
+///
+/// We inject the reason only if the Rust version supports it (1.81+). Otherwise it serves as
+/// documentation in the consumer's code only.
+pub fn brackets_allow_tool_lint_params_parts(
+    prefix_str: &str,
+    lint_str: &str,
+    params: &Params,
+    span: Span,
+) -> TokenStream {
+    let reason = if cfg!(can_give_lint_reason) {
+        params.reason.as_ref()
+    } else {
+        None
+    };
+    let tool = tool_cfg(prefix_str).map(|tool| TokenStream::from(get_ident_tree(tool, span)));
+    let predicate = match (tool, params.cfg.clone()) {
+        (Some(tool), Some(cfg)) => {
+            let mut parens = TokenTree::Group(Group::new(
+                Delimiter::Parenthesis,
+                auxiliary::token_streams_to_stream(&[
+                    tool,
+                    TokenStream::from(get_punct_alone(',', span)),
+                    cfg,
+                ]),
+            ));
+            parens.set_span(span);
+            Some(auxiliary::token_trees_to_stream(&[
+                get_ident_tree("all", span),
+                parens,
+            ]))
+        }
+        (Some(predicate), None) | (None, Some(predicate)) => Some(predicate),
+        (None, None) => None,
+    };
+    match predicate {
+        Some(predicate) => {
+            brackets_cfg_attr_allow_lint_parts(predicate, prefix_str, lint_str, reason, span)
+        }
+        None => brackets_allow_lint_parts(prefix_str, lint_str, reason, span),
+    }
+}
+
+/// `#[doc = "..."]`.
+pub fn doc_attribute(doc: &str, span: Span) -> TokenStream {
+    let mut doc = TokenTree::Literal(Literal::string(doc));
+    doc.set_span(span);
+    auxiliary::token_streams_to_stream(&[
+        get_hash(span),
+        brackets(
+            auxiliary::token_trees_to_stream(&[
+                get_ident_tree("doc", span),
+                get_punct_alone('=', span),
+                doc,
+            ]),
+            span,
+        ),
+    ])
+}
+
+/// Insert `attributes` (whole `#[...]`) into `item`, after the item's existing outer attributes
+/// (including its doc comments), but before the rest of the item.
+pub fn append_outer_attribute(item: TokenStream, attributes: TokenStream) -> TokenStream {
+    let mut tokens: Vec<TokenTree> = item.into_iter().collect();
+    let mut index = 0;
+    while let [TokenTree::Punct(hash), TokenTree::Group(group), ..] = &tokens[index..] {
+        if hash.as_char() != '#' || group.delimiter() != Delimiter::Bracket {
+            break;
+        }
+        index += 2;
+    }
+    let rest = tokens.split_off(index);
+    auxiliary::token_streams_to_stream(&[
+        auxiliary::token_trees_to_stream(&tokens),
+        attributes,
+        auxiliary::token_trees_to_stream(&rest),
+    ])
+}
+
+/// Append paragraph `text` to the documentation of `item` (see [`append_outer_attribute`]): an
+/// empty `#[doc = ""]`, which separates it from the item's own documentation (if any), and
+/// `#[doc = "text"]`.
+pub fn append_doc_paragraph(item: TokenStream, text: &str, span: Span) -> TokenStream {
+    let attributes =
+        auxiliary::token_streams_to_stream(&[doc_attribute("", span), doc_attribute(text, span)]);
+    append_outer_attribute(item, attributes)
+}
+
+/// What a generated attribute macro knows about its lint (other than the lint path). It's fixed when
+/// we generate the macro.
+#[derive(Clone, Copy)]
+pub struct LintProperties {
+    /// Whether the lint doesn't exist for this Rust version.
+    pub pass_through: bool,
+    /// Whether the lint is deny-by-default (see [`deny_by_default`]).
+    pub deny_by_default: bool,
+    /// URL of the lint's documentation (see [`document`]), if known.
+    pub url: Option<&'static str>,
+}
+
+/// The whole output of a generated attribute macro (other than for crate level-only lints): The
+/// parsed `given_attrs` (see [`params`](crate::params), validated against the consumer's
+/// [`config`]), then `#[allow(lint_path)]` (or its variations - see [`brackets_allow_tool_lint_params_parts`]),
+/// followed by `item` (with any [`metadata`], and with the suppression itself if requested (see
+/// [`document`]), appended as `#[doc = "..."]`). Any notice about an unacknowledged suppression of
+/// a deny-by-default lint (see [`deny_by_default`]), or about an expired or obsolete suppression
+/// (see [`expiry`]), goes inside `item` (see [`place_notice`]), and so do the includes of the
+/// configuration files (see [`track_files`]). If the suppression is obsolete for this Rust version
+/// (see [`expiry::until_rust`]), then we don't inject `#[allow(...)]`.
+///
+/// If `lint.pass_through` is true (the lint doesn't exist for this Rust version), we still validate
+/// `given_attrs`, but then we return `item` as-is.
+///
+/// If the configuration or `given_attrs` are malformed (or a required reason or metadata is
+/// missing), return an error, followed by the item as-is (so that the user gets only this error,
+/// rather than also errors caused by a missing item).
+pub fn allow_attribute_macro_output(
+    macro_name: &str,
+    prefix_str: &str,
+    lint_str: &str,
+    lint: LintProperties,
+    given_attrs: TokenStream,
+    item: TokenStream,
+) -> TokenStream {
+    let config = match config::load(Span::call_site()) {
+        Ok(config) => config,
+        Err(error) => return auxiliary::token_streams_to_stream(&[error, item]),
+    };
+    let params = match params::parse(macro_name, given_attrs, &config) {
+        Ok(params) => params,
+        Err(error) => return auxiliary::token_streams_to_stream(&[error, item]),
+    };
+    let lint_path = if prefix_str.is_empty() {
+        lint_str.to_owned()
+    } else {
+        format!("{}::{}", prefix_str, lint_str)
+    };
+    let checked = policy::check(macro_name, &lint_path, Span::call_site())
+        .and_then(|()| params::check_reason(macro_name, &params, &config))
+        .and_then(|()| metadata::check_required(macro_name, &lint_path, &params, &config))
+        .and_then(|()| expiry::check_placeable(macro_name, &params, &item));
+    if let Err(error) = checked {
+        return auxiliary::token_streams_to_stream(&[error, item]);
+    }
+    let span = consumer_span(&item);
+    let unacknowledged = match deny_by_default::acknowledge_deny(
+        macro_name,
+        &lint_path,
+        lint.deny_by_default,
+        &params,
+        &config,
+        has_notice_place(&item),
+        span,
+    ) {
+        Ok(unacknowledged) => unacknowledged,
+        Err(error) => return auxiliary::token_streams_to_stream(&[error, item]),
+    };
+    let item = track_files(item, &config, span);
+    if lint.pass_through {
+        return item;
+    }
+    let item = metadata::with_doc(&lint_path, &params, item, span);
+    let item = document::with_doc(&lint_path, lint.url, &params, item, span);
+    let item = with_notice(item, unacknowledged);
+    let item = with_notice(
+        item,
+        expiry::until_version(macro_name, &lint_path, &params, span),
+    );
+    if let Some(obsolete) = expiry::until_rust(macro_name, &lint_path, &params, span) {
+        return with_notice(item, Some(obsolete));
+    }
+    auxiliary::token_streams_to_stream(&[
+        get_hash(span),
+        brackets_allow_tool_lint_params_parts(prefix_str, lint_str, &params, span),
+        item,
+    ])
+}
+
+/// The whole output of a generated attribute macro for a crate level-only lint: an error that shows
+/// the `#![allow(lint_name)]` line to add at the crate root, followed by `item` as-is. The line
+/// includes `reason = "..."` and `cfg(predicate)` from `given_attrs` (if any), like
+/// `#![cfg_attr(test, allow(lint_name, reason = "..."))]`.
+///
+/// With cargo feature `nightly`, if the alias is an inner attribute at the crate root (see
+/// [`is_crate_root`]), it injects that line instead. See [`crate_root_output`].
+///
+/// The error points to `given_attrs` (if any), otherwise to the whole attribute (the call site),
+/// since the alias has no tokens of its own. If `given_attrs` are malformed, return their error
+/// instead.
+pub fn crate_level_guidance_output(
+    lint_str: &str,
+    given_attrs: TokenStream,
+    item: TokenStream,
+) -> TokenStream {
+    let span = given_attrs
+        .clone()
+        .into_iter()
+        .next()
+        .map_or_else(Span::call_site, |token_tree| token_tree.span());
+    let config = match config::load(span) {
+        Ok(config) => config,
+        Err(error) => return auxiliary::token_streams_to_stream(&[error, item]),
+    };
+    let params = match params::parse(lint_str, given_attrs, &config) {
+        Ok(params) => params,
+        Err(error) => return auxiliary::token_streams_to_stream(&[error, item]),
+    };
+    if cfg!(feature = "nightly") && is_crate_root(&item) {
+        return crate_root_output(lint_str, &params, &config, item);
+    }
+    let mut allow = format!("allow({}", lint_str);
+    if let Some(reason) = &params.reason {
+        allow.push_str(&format!(", reason = {}", reason));
+    }
+    allow.push(')');
+    let attribute = match &params.cfg {
+        Some(predicate) => format!("cfg_attr({}, {})", predicate, allow),
+        None => allow,
+    };
+    let message = format!(
+        "Lint `{}` is crate level-only: rustc honors `allow` for it only as an inner attribute at the crate root. Add the following line at the top of your `lib.rs` or `main.rs` (and remove this alias): #![{}]. (On nightly Rust, with cargo feature `nightly`, this alias works as an inner attribute at the crate root, under `#![feature(custom_inner_attributes, prelude_import)]`.)",
+        lint_str, attribute
+    );
+    auxiliary::token_streams_to_stream(&[compile_error(&message, span), item])
+}
+
+/// Whether `item` is a whole crate: the input of an inner attribute at the crate root (with nightly
+/// `#![feature(custom_inner_attributes)]`). Rustc injects the implicit prelude, `#[prelude_import]
+/// use ...::prelude::...::*;`, at the top level of the crate root only. (So a crate with
+/// `#![no_implicit_prelude]` doesn't count.)
+pub fn is_crate_root(item: &TokenStream) -> bool {
+    item.clone()
+        .into_iter()
+        .any(|token| is_prelude_import(&token))
+}
+
+/// Whether `token` is the bracketed part of attribute `#[prelude_import]`.
+fn is_prelude_import(token: &TokenTree) -> bool {
+    matches!(
+        token,
+        TokenTree::Group(group) if group.delimiter() == Delimiter::Bracket
+            && group.stream().to_string() == "prelude_import"
+    )
+}
+
+/// The output of an alias of a crate level-only lint used as an inner attribute at the crate root
+/// (see [`crate_level_guidance_output`]): `item` (the whole crate) with `#![allow(lint_name)]` (or
+/// its variations - see [`brackets_allow_tool_lint_params_parts`]) injected after the crate's own
+/// leading inner attributes, so that it overrides any `#![deny(lint_name)]` or `#![warn(...)]`
+/// there. The checks, and any notice or includes (appended at the end of the crate), are as in
+/// [`allow_attribute_macro_output`].
+///
+/// Rustc requires `#![feature(prelude_import)]` for the implicit prelude in the output, and it then
+/// reports the prelude as an unused import. Hence we also inject `#[allow(unused_imports)]` to it
+/// (once, even if there are several such aliases).
+fn crate_root_output(
+    lint_str: &str,
+    params: &Params,
+    config: &config::Config,
+    item: TokenStream,
+) -> TokenStream {
+    let checked = policy::check(lint_str, lint_str, Span::call_site())
+        .and_then(|()| params::check_reason(lint_str, params, config))
+        .and_then(|()| metadata::check_required(lint_str, lint_str, params, config));
+    if let Err(error) = checked {
+        return auxiliary::token_streams_to_stream(&[error, item]);
+    }
+    let span = consumer_span(&item);
+    let unacknowledged = match deny_by_default::acknowledge_deny(
+        lint_str,
+        lint_str,
+        lint_table::is_deny_by_default(lint_str),
+        params,
+        config,
+        // The notice goes after the crate's last item.
+        true,
+        span,
+    ) {
+        Ok(unacknowledged) => unacknowledged,
+        Err(error) => return auxiliary::token_streams_to_stream(&[error, item]),
+    };
+    let tokens: Vec<TokenTree> = item.into_iter().collect();
+    let mut start = 0;
+    while let [TokenTree::Punct(hash), TokenTree::Punct(bang), TokenTree::Group(_), ..] =
+        &tokens[start..]
+    {
+        if hash.as_char() != '#' || bang.as_char() != '!' {
+            break;
+        }
+        start += 3;
+    }
+    let mut output = vec![
+        auxiliary::token_trees_to_stream(&tokens[..start]),
+        get_hash(span),
+        TokenStream::from(get_punct_alone('!', span)),
+        brackets_allow_tool_lint_params_parts("", lint_str, params, span),
+    ];
+    for (index, token) in tokens.iter().enumerate().skip(start) {
+        output.push(TokenStream::from(token.clone()));
+        // Unless another alias at the crate root has injected it already.
+        let allowed = matches!(
+            tokens.get(index + 2),
+            Some(TokenTree::Group(group)) if group.stream().to_string() == "allow(unused_imports)"
+        );
+        if is_prelude_import(token) && !allowed {
+            output.push(get_hash(span));
+            output.push(brackets_allow_lint_parts("", "unused_imports", None, span));
+        }
+    }
+    if let Some(notice) = unacknowledged {
+        output.push(notice);
+    }
+    output.push(includes(config.track(), span));
+    auxiliary::token_streams_to_stream(&output)
+}
+
+/// [`TokenStream`] consisting of `::core::compile_error!("...");` with the given message. All its
+/// tokens are located at `span`. Similar to the one in `allow_internal` crate, but with the full path,
+/// so that it works even if the consumer shadows `compile_error`.
+pub fn compile_error(message: &str, span: Span) -> TokenStream {
+    let mut message = TokenTree::Literal(Literal::string(message));
+    message.set_span(span);
+    let mut parens_message = TokenTree::Group(Group::new(
+        Delimiter::Parenthesis,
+        TokenStream::from(message),
+    ));
+    parens_message.set_span(span);
+
+    auxiliary::token_trees_to_stream(&[
+        get_colon_joint(span),
+        get_punct_alone(':', span),
+        get_ident_tree("core", span),
+        get_colon_joint(span),
+        get_punct_alone(':', span),
+        get_ident_tree("compile_error", span),
+        get_punct_alone('!', span),
+        parens_message,
+        get_punct_alone(';', span),
+    ])
+}
+
+/// `const _: () = { #[deprecated(note = "...")] struct StructName; let _ = StructName; };`.
+///
+/// A proc macro can't emit a warning or a note on stable Rust. But a use of a `#[deprecated]` item
+/// emits a warning with the given note (`use of deprecated unit struct `_::StructName`: ...`). The
+/// consumer can silence it with `#[allow(deprecated)]` around the alias.
+///
+/// This is an item, so it can't be a sibling of an associated item in an `impl` or a `trait` block.
+/// See [`place_notice`].
+///
+/// Param `span` should come from [`consumer_span`], so that the warning applies to, and points at,
+/// the consumer's code.
+pub fn deprecated_notice(struct_name: &str, note: &str, span: Span) -> TokenStream {
+    let mut note = TokenTree::Literal(Literal::string(note));
+    note.set_span(span);
+    let mut parens_note = TokenTree::Group(Group::new(
+        Delimiter::Parenthesis,
+        auxiliary::token_trees_to_stream(&[
+            get_ident_tree("note", span),
+            get_punct_alone('=', span),
+            note,
+        ]),
+    ));
+    parens_note.set_span(span);
+
+    let deprecated = brackets(
+        auxiliary::token_trees_to_stream(&[get_ident_tree("deprecated", span), parens_note]),
+        span,
+    );
+    let body = auxiliary::token_streams_to_stream(&[
+        get_hash(span),
+        deprecated,
+        auxiliary::token_trees_to_stream(&[
+            get_ident_tree("struct", span),
+            get_ident_tree(struct_name, span),
+            get_punct_alone(';', span),
+            get_ident_tree("let", span),
+            get_ident_tree("_", span),
+            get_punct_alone('=', span),
+            get_ident_tree(struct_name, span),
+            get_punct_alone(';', span),
+        ]),
+    ]);
+    let mut braces_body = TokenTree::Group(Group::new(Delimiter::Brace, body));
+    braces_body.set_span(span);
+    let mut parens_unit = TokenTree::Group(Group::new(Delimiter::Parenthesis, TokenStream::new()));
+    parens_unit.set_span(span);
+
+    auxiliary::token_trees_to_stream(&[
+        get_ident_tree("const", span),
+        get_ident_tree("_", span),
+        get_punct_alone(':', span),
+        parens_unit,
+        get_punct_alone('=', span),
+        braces_body,
+        get_punct_alone(';', span),
+    ])
+}
+
+/// `item` with [`includes`] of the consumer's configuration files (`config.files`), placed like a
+/// notice (see [`place_notice`]). Cargo doesn't know about the files that our proc macros read, but
+/// it does know about included files. So then it rebuilds the crate when the configuration changes.
+///
+/// Cargo needs them only once per crate, so if an earlier use has included them (see
+/// [`Config::tracked`](config::Config::tracked)), or `item` has no place for them, return `item`
+/// as-is. (Any other use in the crate will do.)
+pub fn track_files(item: TokenStream, config: &config::Config, span: Span) -> TokenStream {
+    if config.files.is_empty() || config.tracked.get() {
+        return item;
+    }
+    match place_notice(item.clone(), includes(&config.files, span)) {
+        Some(placed) => {
+            config.tracked.set(true);
+            placed
+        }
+        None => item,
+    }
+}
+
+/// `const _: &[u8] = ::core::include_bytes!("...");` for each of `files`. See [`track_files`].
+pub fn includes(files: &[PathBuf], span: Span) -> TokenStream {
+    let mut includes = Vec::with_capacity(files.len());
+    for file in files {
+        let mut path = TokenTree::Literal(Literal::string(&file.to_string_lossy()));
+        path.set_span(span);
+        let mut parens_path =
+            TokenTree::Group(Group::new(Delimiter::Parenthesis, TokenStream::from(path)));
+        parens_path.set_span(span);
+        let mut brackets_u8 = TokenTree::Group(Group::new(
+            Delimiter::Bracket,
+            TokenStream::from(get_ident_tree("u8", span)),
+        ));
+        brackets_u8.set_span(span);
+        includes.push(auxiliary::token_trees_to_stream(&[
+            get_ident_tree("const", span),
+            get_ident_tree("_", span),
+            get_punct_alone(':', span),
+            get_punct_alone('&', span),
+            brackets_u8,
+            get_punct_alone('=', span),
+            get_colon_joint(span),
+            get_punct_alone(':', span),
+            get_ident_tree("core", span),
+            get_colon_joint(span),
+            get_punct_alone(':', span),
+            get_ident_tree("include_bytes", span),
+            get_punct_alone('!', span),
+            parens_path,
+            get_punct_alone(';', span),
+        ]));
+    }
+    auxiliary::token_streams_to_stream(&includes)
+}
+
+/// [`place_notice`] if there's a `notice`. If `item` has no place for it, put `notice` after `item`
+/// (the caller should have rejected such an item - see [`expiry::check_placeable`]).
+pub fn with_notice(item: TokenStream, notice: Option<TokenStream>) -> TokenStream {
+    match notice {
+        Some(notice) => match place_notice(item.clone(), notice.clone()) {
+            Some(placed) => placed,
+            None => auxiliary::token_streams_to_stream(&[item, notice]),
+        },
+        None => item,
+    }
+}
+
+/// Whether [`place_notice`] has a place for a notice in `item`.
+pub fn has_notice_place(item: &TokenStream) -> bool {
+    place_notice(item.clone(), TokenStream::new()).is_some()
+}
+
+/// Place `notice` (an item, like from [`deprecated_notice`]) where it's valid for `item`, even if
+/// `item` is an associated item of an `impl` or a `trait` block (which can't have a `const _`):
+/// - into the body of a function (after the body's inner attributes, if any),
+/// - into the value of a `const` or a `static`, like `const X: T = { notice value };`, or
+/// - after any other item (a struct, a module, an `impl` block...), which can't be associated. (After
+///   it, so that any attributes that the caller prepends apply to `item`.)
+///
+/// Return `None` if `item` may be associated, but it has no such place: a type alias, a macro
+/// invocation, or a function or a `const` without a body or a value (in a `trait`).
+pub fn place_notice(item: TokenStream, notice: TokenStream) -> Option<TokenStream> {
+    let mut tokens: Vec<TokenTree> = item.into_iter().collect();
+    let mut index = members::skip_attributes_and_visibility(&tokens);
+    // The item's keyword, after any qualifiers (like `const unsafe fn` or `extern "C" fn`).
+    let keyword = loop {
+        match tokens.get(index) {
+            Some(TokenTree::Ident(ident)) => {
+                let ident = ident.to_string();
+                let next_is_ident = matches!(tokens.get(index + 1), Some(TokenTree::Ident(_)));
+                match ident.as_str() {
+                    "const" if next_is_ident => match tokens[index + 1].to_string().as_str() {
+                        "fn" | "unsafe" | "async" | "extern" => {}
+                        _ => break ident,
+                    },
+                    "unsafe" | "async" | "extern" | "default" => {}
+                    _ => break ident,
+                }
+            }
+            // The ABI, like `"C"`.
+            Some(TokenTree::Literal(_)) => {}
+            _ => return None,
+        }
+        index += 1;
+    };
+    let is_macro_invocation = matches!(
+        tokens.get(index + 1),
+        Some(TokenTree::Punct(punct)) if punct.as_char() == '!' || punct.as_char() == ':'
+    );
+    match keyword.as_str() {
+        "fn" => {
+            let body = match tokens.last() {
+                Some(TokenTree::Group(body)) if body.delimiter() == Delimiter::Brace => {
+                    body.clone()
+                }
+                _ => return None,
+            };
+            let body_tokens: Vec<TokenTree> = body.stream().into_iter().collect();
+            let mut start = 0;
+            while let [TokenTree::Punct(hash), TokenTree::Punct(bang), TokenTree::Group(_), ..] =
+                &body_tokens[start..]
+            {
+                if hash.as_char() != '#' || bang.as_char() != '!' {
+                    break;
+                }
+                start += 3;
+            }
+            let mut new_body = Group::new(
+                Delimiter::Brace,
+                auxiliary::token_streams_to_stream(&[
+                    auxiliary::token_trees_to_stream(&body_tokens[..start]),
+                    notice,
+                    auxiliary::token_trees_to_stream(&body_tokens[start..]),
+                ]),
+            );
+            new_body.set_span(body.span());
+            *tokens.last_mut().unwrap() = TokenTree::Group(new_body);
+        }
+        "const" | "static" => {
+            // The value is between the first `=` outside of angle brackets (of the type) and the
+            // final `;`.
+            let mut depth = 0;
+            let equals = (index..tokens.len()).find(|&i| {
+                depth +=
+                    members::angle_depth_change(i.checked_sub(1).map(|i| &tokens[i]), &tokens[i]);
+                depth == 0
+                    && matches!(&tokens[i], TokenTree::Punct(punct) if punct.as_char() == '=')
+            })?;
+            let end = match tokens.last() {
+                Some(TokenTree::Punct(punct)) if punct.as_char() == ';' => tokens.len() - 1,
+                _ => tokens.len(),
+            };
+            let value = auxiliary::token_trees_to_stream(&tokens[equals + 1..end]);
+            let mut new_value = Group::new(
+                Delimiter::Brace,
+                auxiliary::token_streams_to_stream(&[notice, value]),
+            );
+            new_value.set_span(tokens[equals].span());
+            tokens.splice(equals + 1..end, vec![TokenTree::Group(new_value)]);
+        }
+        "type" => return None,
+        // Like `foo!(...);`. But `macro_rules!` can't be an associated item.
+        _ if is_macro_invocation && keyword != "macro_rules" => return None,
+        _ => {
+            return Some(auxiliary::token_streams_to_stream(&[
+                auxiliary::token_trees_to_stream(&tokens),
+                notice,
+            ]))
+        }
+    }
+    Some(auxiliary::token_trees_to_stream(&tokens))
 }
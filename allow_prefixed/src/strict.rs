@@ -0,0 +1,340 @@
+//! Enforcing "aliases, not raw `#[allow(...)]`", like `#[strict] mod generated { ... }`. This
+//! attribute walks the tokens of an inline module (or of any item), without `syn`. It reports an
+//! error for each raw `#[allow(...)]`, `#[expect(...)]` or `#[cfg_attr(..., allow(...))]` inside,
+//! suggesting the aliases of those lints (from [`lint_table`]), `tool_lint` for lints without an
+//! alias, or `members` for a field or a variant. With `#[strict(require_reason)]` it also reports
+//! aliases, `members`, `methods`, `expr!` and `stmt!` used without `reason = "..."`.
+//!
+//! Outer attribute macros expand before the ones inside, so we see the aliases inside as written.
+//! We recognize them only under paths starting with `allow::` or `allow_prefixed::` (including the
+//! modules of `allow`, like `allow::rustdoc::` or `allow::tool_attributes::`), not when imported
+//! under other names.
+
+use proc_macro::{Delimiter, Group, Ident, Span, TokenStream, TokenTree};
+
+use crate::auxiliary;
+use crate::lint_table;
+use crate::members;
+use crate::params::{self, ParseResult};
+
+/// Names of our aliases that are not in the [`lint_table`]: of the tool attributes, and the audited
+/// aliases. Generated by ../build.rs.
+const OTHER_ALIASES: &[&str] = include!(concat!(env!("OUT_DIR"), "/other_aliases.rs"));
+
+/// Names of the aliases of other tools' lints (from `ALLOW_PREFIXED_TOOL_LINTS`). Generated by
+/// ../build.rs.
+const TOOL_LINT_ALIASES: &[&str] = include!(concat!(env!("OUT_DIR"), "/tool_lint_aliases.rs"));
+
+/// The whole output of `#[strict]` (or `#[strict(require_reason)]`): an error for each violation
+/// in `item`, followed by the item as-is. If `given_attrs` are malformed, return an error, followed
+/// by the item as-is.
+pub fn strict_output(given_attrs: TokenStream, item: TokenStream) -> TokenStream {
+    let require_reason = match parse(given_attrs) {
+        Ok(require_reason) => require_reason,
+        Err(error) => return auxiliary::token_streams_to_stream(&[error, item]),
+    };
+    let mut streams = Vec::new();
+    check(item.clone(), None, require_reason, &mut streams);
+    streams.push(item);
+    auxiliary::token_streams_to_stream(&streams)
+}
+
+/// Parse `given_attrs`: nothing, or `require_reason`. Return whether aliases require a reason.
+fn parse(given_attrs: TokenStream) -> ParseResult<bool> {
+    let tokens: Vec<TokenTree> = given_attrs.into_iter().collect();
+    match tokens.as_slice() {
+        [] => Ok(false),
+        [TokenTree::Ident(option)] if option.to_string() == "require_reason" => Ok(true),
+        _ => Err(members::error(
+            "strict",
+            "Expecting no parameters, or `require_reason`.",
+            tokens[0].span(),
+        )),
+    }
+}
+
+/// The struct, enum or union and its field or variant (its name, or its index in a tuple struct)
+/// that the walked tokens belong to, if any.
+type Member<'a> = Option<(&'a str, &'a str)>;
+
+/// Walk `tokens` (including any nested groups). Add an error to `errors` for each violation.
+fn check(tokens: TokenStream, member: Member, require_reason: bool, errors: &mut Vec<TokenStream>) {
+    let tokens: Vec<TokenTree> = tokens.into_iter().collect();
+    // The index of the body of a struct, an enum or a union in `tokens`, with the item's name.
+    let mut body: Option<(usize, String)> = None;
+    for (index, token) in tokens.iter().enumerate() {
+        match token {
+            TokenTree::Group(group)
+                if group.delimiter() == Delimiter::Bracket && is_after_hash(&tokens[..index]) =>
+            {
+                check_attribute(group.stream(), member, require_reason, errors);
+            }
+            TokenTree::Group(group) => match &body {
+                Some((body_index, item_name)) if *body_index == index => {
+                    check_members(group, item_name, require_reason, errors);
+                }
+                _ => check(group.stream(), member, require_reason, errors),
+            },
+            TokenTree::Ident(keyword) if is_item_keyword(keyword, tokens.get(index + 1)) => {
+                if let Ok((item_name, Some(body_index))) = members::locate_body(&tokens[index..]) {
+                    body = Some((index + body_index, item_name));
+                }
+            }
+            TokenTree::Ident(_) if require_reason => {
+                if let Some((path, span)) =
+                    inline_without_reason(&tokens[..index], &tokens[index..])
+                {
+                    errors.push(without_reason_error("Macro", &path, span));
+                }
+            }
+            _ => {}
+        }
+    }
+}
+
+/// Whether `keyword` (followed by `next`) starts a struct, an enum or a union. (`union` is a
+/// keyword only when an identifier follows it.)
+fn is_item_keyword(keyword: &Ident, next: Option<&TokenTree>) -> bool {
+    matches!(keyword.to_string().as_str(), "struct" | "enum" | "union")
+        && matches!(next, Some(TokenTree::Ident(_)))
+}
+
+/// Check the fields or variants in `body` of struct, enum or union `item_name`.
+fn check_members(
+    body: &Group,
+    item_name: &str,
+    require_reason: bool,
+    errors: &mut Vec<TokenStream>,
+) {
+    for (index, member) in members::split_members(body.stream()).iter().enumerate() {
+        let member_name = if body.delimiter() == Delimiter::Parenthesis {
+            Some(index.to_string())
+        } else {
+            members::member_name(member)
+        };
+        let member_context = member_name
+            .as_deref()
+            .map(|member_name| (item_name, member_name));
+        check(
+            auxiliary::token_trees_to_stream(member),
+            member_context,
+            require_reason,
+            errors,
+        );
+    }
+}
+
+/// Whether `preceding` tokens end with `#` (of an outer attribute) or `#!` (of an inner attribute).
+fn is_after_hash(preceding: &[TokenTree]) -> bool {
+    match preceding {
+        [.., hash] if is_punct(hash, '#') => true,
+        [.., hash, bang] => is_punct(hash, '#') && is_punct(bang, '!'),
+        _ => false,
+    }
+}
+
+fn is_punct(token: &TokenTree, c: char) -> bool {
+    match token {
+        TokenTree::Punct(punct) => punct.as_char() == c,
+        _ => false,
+    }
+}
+
+/// Check the content of an attribute (between its brackets), on `member` (if any).
+fn check_attribute(
+    attribute: TokenStream,
+    member: Member,
+    require_reason: bool,
+    errors: &mut Vec<TokenStream>,
+) {
+    let tokens: Vec<TokenTree> = attribute.into_iter().collect();
+    if let [TokenTree::Ident(name), TokenTree::Group(arguments)] = tokens.as_slice() {
+        if arguments.delimiter() == Delimiter::Parenthesis {
+            match name.to_string().as_str() {
+                "allow" | "expect" => errors.push(raw_error(name, arguments.stream(), member)),
+                // `cfg_attr(predicate, attribute, ...)`.
+                "cfg_attr" => {
+                    for attribute in params::split_by_commas(arguments.stream())
+                        .into_iter()
+                        .skip(1)
+                    {
+                        check_attribute(
+                            auxiliary::token_trees_to_stream(&attribute),
+                            member,
+                            require_reason,
+                            errors,
+                        );
+                    }
+                }
+                _ => {}
+            }
+            return;
+        }
+    }
+    if require_reason {
+        if let Some((alias, span)) = alias_without_reason(&tokens) {
+            errors.push(without_reason_error("Alias", &alias, span));
+        }
+    }
+}
+
+/// Error about raw `#[allow(...)]` or `#[expect(...)]` (`name`) with `arguments` (lint paths,
+/// possibly followed by `reason = "..."`), on `member` (if any). On a field or a variant it
+/// suggests `members(...)` on the parent item, since attribute macros don't apply to fields and
+/// variants. Otherwise it suggests the aliases of those lints (as generated under the enabled cargo
+/// features), or `tool_lint` for lints without one.
+fn raw_error(name: &Ident, arguments: TokenStream, member: Member) -> TokenStream {
+    let mut message = format!("Expecting aliases rather than raw `#[{}(...)]`.", name);
+    for lint_path in params::split_by_commas(arguments) {
+        let (prefix, lint) = match params::split_lint_path(&lint_path) {
+            Some((prefix, lint, [])) => (prefix, lint),
+            // `reason = "..."`, or malformed (which rustc reports).
+            _ => continue,
+        };
+        let lint_path = members::lint_path(&prefix, lint);
+        match (member, lint_table::alias(&lint_path)) {
+            (Some((item_name, member_name)), _) => message.push_str(&format!(
+                " For `{}` on `{}` use `#[allow_prefixed::members({} = [{}])]` on `{}`.",
+                lint_path, member_name, lint_path, member_name, item_name
+            )),
+            (None, Some(alias)) => message.push_str(&format!(
+                " For `{}` use `#[allow_prefixed::{}]`.",
+                lint_path, alias
+            )),
+            (None, None) if lint_table::is_group(&lint_path) => message.push_str(&format!(
+                " For lint group `{}` use `#[allow_prefixed::{}]` (with cargo feature `groups`).",
+                lint_path,
+                lint_path.replace("::", "_")
+            )),
+            (None, None) if !prefix.is_empty() || lint_table::is_known(&lint_path) => message
+                .push_str(&format!(
+                    " For `{}` use `#[allow_prefixed::tool_lint({})]`.",
+                    lint_path, lint_path
+                )),
+            (None, None) => message.push_str(&format!(" There's no alias for `{}`.", lint_path)),
+        }
+    }
+    members::error("strict", &message, name.span())
+}
+
+/// Error about `path` of an alias or a macro (`kind`) used without `reason = "..."`.
+fn without_reason_error(kind: &str, path: &str, span: Span) -> TokenStream {
+    members::error(
+        "strict",
+        &format!(
+            "{} `{}` is used without `reason = \"...\"` (required by `strict(require_reason)`).",
+            kind, path
+        ),
+        span,
+    )
+}
+
+/// Segments of a path at the start of `tokens` (like `allow_prefixed::intent::generated`), and the
+/// tokens after it.
+fn split_path(tokens: &[TokenTree]) -> (Vec<&Ident>, &[TokenTree]) {
+    let mut segments: Vec<&Ident> = Vec::new();
+    let mut rest = tokens;
+    loop {
+        match rest {
+            [TokenTree::Punct(first), TokenTree::Punct(second), more @ ..]
+                if first.as_char() == ':' && second.as_char() == ':' =>
+            {
+                rest = more;
+            }
+            [TokenTree::Ident(segment), more @ ..] => {
+                segments.push(segment);
+                rest = more;
+            }
+            _ => break,
+        }
+    }
+    (segments, rest)
+}
+
+/// Whether `params` (comma-separated) include `reason = "..."`.
+fn has_reason(params: TokenStream) -> bool {
+    params::split_by_commas(params)
+        .iter()
+        .any(|param| match param.as_slice() {
+            [TokenTree::Ident(key), eq, ..] => key.to_string() == "reason" && is_punct(eq, '='),
+            _ => false,
+        })
+}
+
+/// If `attribute` (the content between its brackets) is an alias without `reason = "..."`, return
+/// the alias path and its span. Aliases are `allow::` or `allow_prefixed::` followed by the name of
+/// one (see [`is_alias`]), by a module of `allow` and an alias re-exported there (like
+/// `rustdoc::broken_intra_doc_links`), or by a path in `intent::` module.
+fn alias_without_reason(attribute: &[TokenTree]) -> Option<(String, Span)> {
+    let (segments, rest) = split_path(attribute);
+    let arguments = match rest {
+        [] => TokenStream::new(),
+        [TokenTree::Group(group)] if group.delimiter() == Delimiter::Parenthesis => group.stream(),
+        _ => return None,
+    };
+    let names: Vec<String> = segments.iter().map(|segment| segment.to_string()).collect();
+    let is_alias = match names.as_slice() {
+        [krate, ..] if !is_our_crate(krate) => false,
+        [_, alias] => is_alias(alias),
+        [_, module, _, ..] if module == "intent" => true,
+        // The modules of `allow` re-export the aliases without the prefix.
+        [_, module, alias] if module == "rustdoc" || module == "clippy" => {
+            is_alias(&format!("{}_{}", module, alias))
+        }
+        [_, module, alias] => (module == "rustc" || module == "tool_attributes") && is_alias(alias),
+        _ => false,
+    };
+    if is_alias && !has_reason(arguments) {
+        Some((names.join("::"), segments[0].span()))
+    } else {
+        None
+    }
+}
+
+/// Whether `name` is the name of one of our alias macros, or `tool_lint`, `members`, `methods` or
+/// `intent`.
+fn is_alias(name: &str) -> bool {
+    lint_table::is_alias(name)
+        || OTHER_ALIASES.contains(&name)
+        || TOOL_LINT_ALIASES.contains(&name)
+        || matches!(name, "tool_lint" | "members" | "methods" | "intent")
+}
+
+/// If `tokens` start an invocation of `allow::expr!`, `allow::stmt!` (or the same under
+/// `allow_prefixed::`) whose lint path has no `reason = "..."`, return the macro path and its span.
+/// `preceding` tokens tell whether `tokens` start a path (rather than continue one).
+fn inline_without_reason(preceding: &[TokenTree], tokens: &[TokenTree]) -> Option<(String, Span)> {
+    if let [.., first, second] = preceding {
+        if is_punct(first, ':') && is_punct(second, ':') {
+            return None;
+        }
+    }
+    let (segments, rest) = split_path(tokens);
+    let names: Vec<String> = segments.iter().map(|segment| segment.to_string()).collect();
+    let arguments = match (names.as_slice(), rest) {
+        ([krate, name], [bang, TokenTree::Group(group), ..])
+            if is_our_crate(krate) && (name == "expr" || name == "stmt") && is_punct(bang, '!') =>
+        {
+            group.stream()
+        }
+        _ => return None,
+    };
+    let lint_path = params::split_by_commas(arguments).into_iter().next()?;
+    let has_reason = match params::split_lint_path(&lint_path) {
+        Some((_, _, [TokenTree::Group(group)])) if group.delimiter() == Delimiter::Parenthesis => {
+            has_reason(group.stream())
+        }
+        Some((_, _, [])) => false,
+        // Malformed, which the macro reports.
+        _ => return None,
+    };
+    if has_reason {
+        None
+    } else {
+        Some((format!("{}!", names.join("::")), segments[0].span()))
+    }
+}
+
+fn is_our_crate(krate: &str) -> bool {
+    krate == "allow" || krate == "allow_prefixed"
+}
@@ -0,0 +1,131 @@
+//! Aliases for tool attributes other than lints: Clippy's configuration attributes (like
+//! `#[clippy::msrv = "1.45"]`). Their intent is just as unclear as the intent of `#[allow(...)]`, so
+//! they get aliases, too - to import under names of your intentions, like
+//! `use allow_prefixed::clippy_msrv as supports_old_rust;`.
+//!
+//! There's no alias of `#[rustfmt::skip]`, since rustfmt doesn't expand attribute macros. Instead,
+//! `rustfmt_skip` reports an error that says so (see [`rustfmt_skip_output`]).
+
+use proc_macro::{Delimiter, Group, Ident, Punct, Spacing, Span, TokenStream, TokenTree};
+
+use crate::auxiliary;
+use crate::members;
+use crate::params;
+use crate::proc_builder;
+
+/// What a tool attribute takes.
+#[derive(Clone, Copy)]
+pub enum Value {
+    /// Nothing, like `#[clippy::has_significant_drop]`. The alias takes no parameters (other than
+    /// `reason`).
+    None,
+    /// A string, like `#[clippy::msrv = "1.45"]`. The alias takes it as its parameter (besides
+    /// `reason`), like `#[clippy_msrv("1.45")]`.
+    String,
+}
+
+/// The whole output of an alias `macro_name` of tool attribute `tool::name`: `#[tool::name]` (or
+/// `#[tool::name = "..."]`) followed by `item`. If `pass_through` (when this Rust version's tool
+/// doesn't know the attribute), return `item` as-is. If `given_attrs` don't match `value`, return an
+/// error, followed by the item as-is. The alias also accepts `reason = "..."`, which (like in lint
+/// aliases) only documents why, so `strict(require_reason)` can require it.
+pub fn tool_attribute_output(
+    macro_name: &str,
+    tool: &str,
+    name: &str,
+    value: Value,
+    pass_through: bool,
+    given_attrs: TokenStream,
+    item: TokenStream,
+) -> TokenStream {
+    let span = proc_builder::consumer_span(&item);
+    // Parameter `reason` is like a comment, so it doesn't go to the attribute.
+    let mut has_reason = false;
+    let mut given: Vec<TokenTree> = Vec::new();
+    for param in params::split_by_commas(given_attrs) {
+        match param.first() {
+            None => {}
+            Some(TokenTree::Ident(ident)) if ident.to_string() == "reason" => {
+                if has_reason {
+                    return error(
+                        macro_name,
+                        "Duplicate parameter `reason`.",
+                        ident.span(),
+                        item,
+                    );
+                }
+                if let Err(err) = params::parse_string_value(macro_name, &param) {
+                    return auxiliary::token_streams_to_stream(&[err, item]);
+                }
+                has_reason = true;
+            }
+            Some(_) => {
+                if !given.is_empty() {
+                    given.push(TokenTree::Punct(Punct::new(',', Spacing::Alone)));
+                }
+                given.extend(param);
+            }
+        }
+    }
+    let value = match (value, given.as_slice()) {
+        (Value::None, []) => None,
+        (Value::String, [TokenTree::Literal(literal)]) if literal.to_string().starts_with('"') => {
+            Some(literal.clone())
+        }
+        (Value::None, _) => {
+            return error(
+                macro_name,
+                "Expecting no parameters (other than `reason = \"...\"`).",
+                given[0].span(),
+                item,
+            );
+        }
+        (Value::String, _) => {
+            let message = format!(
+                "Expecting one string literal (and optionally `reason = \"...\"`), like `{}(\"...\")` for `#[{}::{} = \"...\"]`.",
+                macro_name, tool, name
+            );
+            let error_span = given.first().map_or(span, TokenTree::span);
+            return error(macro_name, &message, error_span, item);
+        }
+    };
+    if pass_through {
+        return item;
+    }
+    let mut attribute = vec![
+        TokenTree::Ident(Ident::new(tool, span)),
+        TokenTree::Punct(Punct::new(':', Spacing::Joint)),
+        TokenTree::Punct(Punct::new(':', Spacing::Alone)),
+        TokenTree::Ident(Ident::new(name, span)),
+    ];
+    if let Some(value) = value {
+        attribute.push(TokenTree::Punct(Punct::new('=', Spacing::Alone)));
+        attribute.push(TokenTree::Literal(value));
+    }
+    let mut brackets = Group::new(
+        Delimiter::Bracket,
+        auxiliary::token_trees_to_stream(&attribute),
+    );
+    brackets.set_span(span);
+    auxiliary::token_streams_to_stream(&[
+        proc_builder::get_hash(span),
+        TokenStream::from(TokenTree::Group(brackets)),
+        item,
+    ])
+}
+
+/// The whole output of `rustfmt_skip`: an error, followed by `item` as-is. Rustfmt reads the source
+/// code as it is, without expanding attribute macros, so an alias of `#[rustfmt::skip]` couldn't make
+/// it skip anything.
+pub fn rustfmt_skip_output(item: TokenStream) -> TokenStream {
+    error(
+        "rustfmt_skip",
+        "Rustfmt doesn't expand attribute macros, so it would never see a `#[rustfmt::skip]` injected by an alias. Use `#[rustfmt::skip]` itself (with a comment on why, if it's not obvious).",
+        Span::call_site(),
+        item,
+    )
+}
+
+fn error(macro_name: &str, message: &str, span: Span, item: TokenStream) -> TokenStream {
+    auxiliary::token_streams_to_stream(&[members::error(macro_name, message, span), item])
+}
@@ -0,0 +1,83 @@
+//! Lints of other tools than `clippy` and `rustdoc`: Dylint libraries, or tools registered with
+//! `#![register_tool(...)]`. We don't know their lints, so we have
+//! - a generic attribute, like `#[tool_lint(our_lints::no_blocking_in_async, reason = "...")]`, and
+//! - aliases generated from a lint list file (see `ALLOW_PREFIXED_TOOL_LINTS` in ../build.rs), like
+//!   `#[our_lints_no_blocking_in_async]`.
+//!
+//! Both accept the same parameters as the other aliases.
+//!
+//! `tool_lint` accepts `clippy::`, `rustdoc::` and standard (prefixless) lints, too: an escape hatch
+//! for lints with no alias (or whose aliases the enabled cargo features don't generate).
+
+use proc_macro::{TokenStream, TokenTree};
+
+use crate::auxiliary;
+use crate::lint_table;
+use crate::members;
+use crate::params;
+use crate::proc_builder::{self, LintProperties};
+
+/// The whole output of `#[tool_lint(tool::lint_name, params...)]` (or of `#[tool_lint(lint_name,
+/// params...)]` for a standard lint - an escape hatch for lints with no alias). Like the output of
+/// an alias (see [`proc_builder::allow_attribute_macro_output`]), but the lint path comes from
+/// `given_attrs`. If `given_attrs` don't start with a lint path, if it's an unknown standard lint,
+/// if it's a lint group (which has its own alias, with its notice), or if it's `warnings` (which
+/// covers all lints), return an error, followed by the item as-is.
+pub fn tool_lint_output(given_attrs: TokenStream, item: TokenStream) -> TokenStream {
+    let tokens: Vec<TokenTree> = given_attrs.into_iter().collect();
+    let parsed = match params::split_lint_path(&tokens) {
+        Some((prefix, lint, [])) => Some((prefix, lint, TokenStream::new())),
+        Some((prefix, lint, [TokenTree::Punct(comma), rest @ ..])) if comma.as_char() == ',' => {
+            Some((prefix, lint, auxiliary::token_trees_to_stream(rest)))
+        }
+        _ => None,
+    };
+    let error = |message: &str, span| {
+        auxiliary::token_streams_to_stream(&[
+            members::error("tool_lint", message, span),
+            item.clone(),
+        ])
+    };
+    match parsed {
+        Some((prefix, lint, _)) if lint_table::is_group(&members::lint_path(&prefix, lint)) => {
+            error(
+                &format!(
+                    "`{}` is a lint group. Use its alias `{}` (with cargo feature `groups`), which names the group's members.",
+                    members::lint_path(&prefix, lint),
+                    members::lint_path(&prefix, lint).replace("::", "_")
+                ),
+                lint.span(),
+            )
+        }
+        Some((prefix, lint, _)) if prefix.is_empty() && lint.to_string() == "warnings" => error(
+            "`warnings` covers all lints that warn, so it would suppress anything. Use the aliases of the lints (or of lint groups) instead.",
+            lint.span(),
+        ),
+        Some((prefix, lint, _))
+            if prefix.is_empty() && !lint_table::is_known(&lint.to_string()) =>
+        {
+            error(&format!("Unknown lint `{}`.", lint), lint.span())
+        }
+        Some((prefix, lint, given_params)) => {
+            let lint_path = members::lint_path(&prefix, lint);
+            proc_builder::allow_attribute_macro_output(
+                "tool_lint",
+                &prefix,
+                &lint.to_string(),
+                LintProperties {
+                    pass_through: false,
+                    deny_by_default: lint_table::is_deny_by_default(&lint_path),
+                    // We don't know where tools document their lints.
+                    url: None,
+                },
+                given_params,
+                item,
+            )
+        }
+        None => error(
+            "Expecting `tool::lint_name` (or `lint_name` of a standard lint), optionally followed \
+             by parameters, like `tool_lint(our_lints::no_blocking_in_async, reason = \"...\")`.",
+            proc_builder::consumer_span(&item),
+        ),
+    }
+}
@@ -5,16 +5,103 @@ macro_rules! prefixed_lint {
     //
     // We CAN'T use one parameter `$lint_path:path` instead of `$lint_prefix:tt, $lint_name:tt`. See
     // https://github.com/rust-lang/rust-analyzer/issues/14772.
-    ($lint_prefix:tt, $lint_name:tt) => {
-        ::allow_internal::check_that_prefixed_lint_exists!($lint_prefix, $lint_name);
+    //
+    // Each prefix has its own branch, so that the whole family can be turned off by a cargo feature.
+    // `clippy::` lints are turned off by the feature of their category (see [`clippy_category`]).
+    (clippy, $lint_name:tt, [$category:tt]) => {
+        clippy_category!(
+            $category,
+            ::allow_internal::check_that_prefixed_lint_exists!(clippy, $lint_name);
+        );
+
+        //::allow_internal::generate_allow_attribute_macro_prefixed!(clippy, $lint_name);
+    };
+    (rustdoc, $lint_name:tt) => {
+        #[cfg(feature = "rustdoc")]
+        ::allow_internal::check_that_prefixed_lint_exists!(rustdoc, $lint_name);
 
-        //::allow_internal::generate_allow_attribute_macro_prefixed!($lint_prefix, $lint_name);
+        //::allow_internal::generate_allow_attribute_macro_prefixed!(rustdoc, $lint_name);
+    };
+}
+
+/// Like [`prefixed_lint`], but generate a macro (an alias) for the lint, too. Only for `clippy::`
+/// lints (so far), under the cargo feature of the lint's category `[$category]`.
+macro_rules! prefixed_lint_allowed {
+    (clippy, $lint_name:tt, [$category:tt]) => {
+        clippy_category!($category, any!(clippy, $lint_name););
+    };
+}
+
+/// Like [`prefixed_lint_allowed`], but for a lint that exists since `$since_major_minor`.
+macro_rules! prefixed_lint_allowed_from {
+    (clippy, $lint_name:tt, $since_major_minor:tt, [$category:tt]) => {
+        clippy_category!(
+            $category,
+            any!(clippy, $lint_name, _, "", $since_major_minor);
+        );
+    };
+}
+
+/// Apply `$item` (a macro invocation that generates the check or the macro for a `clippy::` lint)
+/// only with the cargo feature of Clippy lint category `$category`, like `clippy_pedantic` for
+/// `pedantic`. Category `all` (for `clippy::all` group) requires the features of all its categories.
+///
+/// Feature `clippy` enables all categories.
+macro_rules! clippy_category {
+    (cargo, $item:item) => {
+        #[cfg(feature = "clippy_cargo")]
+        $item
+    };
+    (complexity, $item:item) => {
+        #[cfg(feature = "clippy_complexity")]
+        $item
+    };
+    (correctness, $item:item) => {
+        #[cfg(feature = "clippy_correctness")]
+        $item
+    };
+    (nursery, $item:item) => {
+        #[cfg(feature = "clippy_nursery")]
+        $item
+    };
+    (pedantic, $item:item) => {
+        #[cfg(feature = "clippy_pedantic")]
+        $item
+    };
+    (perf, $item:item) => {
+        #[cfg(feature = "clippy_perf")]
+        $item
+    };
+    (restriction, $item:item) => {
+        #[cfg(feature = "clippy_restriction")]
+        $item
+    };
+    (style, $item:item) => {
+        #[cfg(feature = "clippy_style")]
+        $item
+    };
+    (suspicious, $item:item) => {
+        #[cfg(feature = "clippy_suspicious")]
+        $item
+    };
+    (all, $item:item) => {
+        #[cfg(all(
+            feature = "clippy_complexity",
+            feature = "clippy_correctness",
+            feature = "clippy_perf",
+            feature = "clippy_style",
+            feature = "clippy_suspicious"
+        ))]
+        $item
     };
 }
 
 /// The accepted token stream is the same as the `$properties` part in "ALL_PARAMS" branch of
 /// [`any`]. So the expected input starts with NOT with the lint prefix, but with the lint name.
 macro_rules! check_that_standard_lint_exists {
+    // Not available (yet, or anymore) for this Rust version, hence nothing to check.
+    ($_lint_name:ident, $_default:tt, $_deprecated_msg:tt, $_since:tt, $_nightly:tt, $_until:tt, true, $_not_anymore:tt) => {};
+    ($_lint_name:ident, $_default:tt, $_deprecated_msg:tt, $_since:tt, $_nightly:tt, $_until:tt, $_not_yet:tt, true) => {};
     ($lint_name:ident, $($_:tt)+) => {
         #[allow($lint_name)]
         const _: () = ();
@@ -24,6 +111,9 @@ macro_rules! check_that_standard_lint_exists {
 /// The accepted token stream is the same as the `$lint_name` + `$properties` part in "ALL_PARAMS"
 /// branch of [`any`]. So the expected input starts with the lint prefix, then the lint name.
 macro_rules! check_that_prefixed_lint_exists {
+    // Not available (yet, or anymore) for this Rust version, hence nothing to check.
+    ($_lint_prefix:ident, $_lint_name:ident, $_default:tt, $_deprecated_msg:tt, $_since:tt, $_nightly:tt, $_until:tt, true, $_not_anymore:tt) => {};
+    ($_lint_prefix:ident, $_lint_name:ident, $_default:tt, $_deprecated_msg:tt, $_since:tt, $_nightly:tt, $_until:tt, $_not_yet:tt, true) => {};
     ($lint_prefix:ident, $lint_name:ident, $($_:tt)+) => {
         ::allow_internal::check_that_prefixed_lint_exists!($lint_prefix, $lint_name);
     };
@@ -51,17 +141,26 @@ macro_rules! any_with_bools {
     // TODO the allow_internal:: proc macro will pass $not_anymore and $not_yet to allow_prefixed::
     // The following input variations are a "private" interface of this macro: Used from other match
     // branches of this macro only.
+    //
+    // The `#[cfg(feature = ...)]` attributes below drop a whole family of generated macros (and
+    // their checks) when the respective cargo feature is off.
     (ALL_PARAMS, rustc, $($properties:tt)+) => {
         check_that_default_is_populated!($($properties)+);
+        #[cfg(feature = "rustc")]
         check_that_standard_lint_exists!($($properties)+);
 
+        #[cfg(feature = "rustc")]
         ::allow_internal::doc_and_attrib_macro_rustc!($($properties)+);
     };
     (ALL_PARAMS, rustdoc, $($properties:tt)+) => {
         check_that_default_is_underscore!($($properties)+);
+        #[cfg(feature = "rustdoc")]
         check_that_prefixed_lint_exists!(rustdoc, $($properties)+);
-        //@TODO
+
+        #[cfg(feature = "rustdoc")]
+        ::allow_internal::doc_and_attrib_macro_rustdoc!($($properties)+);
     };
+    // No `#[cfg(feature = ...)]` here: `clippy::` lints are declared within [`clippy_category`].
     (ALL_PARAMS, clippy, $($properties:tt)+) => {
         check_that_default_is_underscore!($($properties)+);
         check_that_prefixed_lint_exists!(clippy, $($properties)+);
@@ -69,7 +168,10 @@ macro_rules! any_with_bools {
         ::allow_internal::doc_and_attrib_macro_clippy!($($properties)+);
     };
 
-    ($lint_prefix:tt, $lint_name:tt, $default:tt, $deprecated_msg:tt, $since_major_minor:tt, $nightly:literal, $until_major_minor:tt, $not_yet:literal, $not_anymore:literal) => {
+    // `$not_yet` and `$not_anymore` are captured as `tt` (rather than `literal`), so that they stay
+    // matchable by `true`/`false` in `check_that_standard_lint_exists` and
+    // `check_that_prefixed_lint_exists`.
+    ($lint_prefix:tt, $lint_name:tt, $default:tt, $deprecated_msg:tt, $since_major_minor:tt, $nightly:literal, $until_major_minor:tt, $not_yet:tt, $not_anymore:tt) => {
         any_with_bools!(ALL_PARAMS,
             $lint_prefix,
             $lint_name,
@@ -82,7 +184,7 @@ macro_rules! any_with_bools {
             $not_anymore);
     };
 
-    ($lint_prefix:tt, $lint_name:tt, $default:tt, $deprecated_msg:tt, $since_major_minor:tt, $nightly:literal, _, $not_yet:literal) => {
+    ($lint_prefix:tt, $lint_name:tt, $default:tt, $deprecated_msg:tt, $since_major_minor:tt, $nightly:literal, _, $not_yet:tt) => {
         any_with_bools!(
             $lint_prefix,
             $lint_name,
@@ -95,7 +197,7 @@ macro_rules! any_with_bools {
             false // not deprecated/discontinued yet (but potentially not available yet, either)
         );
     };
-    ($lint_prefix:tt, $lint_name:tt, $default:tt, $deprecated_msg:tt, $since_major_minor:tt, $nightly:literal, $until_major_minor:tt, $not_yet:literal) => {
+    ($lint_prefix:tt, $lint_name:tt, $default:tt, $deprecated_msg:tt, $since_major_minor:tt, $nightly:literal, $until_major_minor:tt, $not_yet:tt) => {
         #[rustversion::not(since($until_major_minor))]
         any_with_bools!(
             $lint_prefix,
@@ -248,13 +350,14 @@ macro_rules! any {
         any!($lint_prefix, $lint_name, $default, $deprecated_msg, 1.45, _);
     };
     ($lint_prefix:tt, $lint_name:tt, $default:tt) => {
-        any!($lint_prefix, $lint_name, $default, _, 1.45,);
+        any!($lint_prefix, $lint_name, $default, _, 1.45, _);
     };
     ($lint_prefix:tt, $lint_name:tt) => {
-        any!($lint_prefix, $lint_name, _, _, 1.45,);
+        any!($lint_prefix, $lint_name, _, _, 1.45, _);
     };
 }
 
+#[allow(unused_macros)] // Not used by any lint declaration at the moment.
 macro_rules! any_clippy {
     ($lint_name:tt) => {
         any!(clippy, $lint_name);
@@ -285,20 +388,32 @@ macro_rules! validate_any {
     };
 }
 
+/// Generate a macro for the given `rustdoc::` lint. According to
+/// https://releases.rs/docs/1.52.0/#rustdoc `rustdoc::` lints exist since 1.52. For older Rust the
+/// generated macro passes its input through as-is.
 macro_rules! rustdoc {
     ($lint_name:tt) => {
-        //prefixed!("rustdoc", $lint_name);
+        any!(rustdoc, $lint_name, _, _, 1.52);
+    };
+    ($lint_name:tt, nightly) => {
+        #[rustversion::nightly]
+        any!(rustdoc, $lint_name, _, _, 1.52, nightly);
     };
 }
-rustdoc!(bufo);
 
+/// Declare a standard lint without an alias (only for the lint table - see `emit_lint_table` in
+/// build.rs).
+///
+/// Like the other `standard_lint*` macros, it accepts (and ignores) the lint groups that the lint is a
+/// member of, like `[unused]`. build.rs derives the members of the groups from those.
 macro_rules! standard_lint {
-    ($_:tt) => {};
+    ($_:tt $(, [$($group:tt),+])?) => {};
 }
 macro_rules! standard_lint_allowed {
     // The `const _` is to check that the lint name is valid (thanks to `#![deny(unknown_lints)]` in
     // `lib.rs`). It gets checked with `cargo check`.
-    ($lint_name:tt) => {
+    ($lint_name:tt $(, [$($group:tt),+])?) => {
+        #[cfg(feature = "rustc")]
         #[allow($lint_name)]
         const _: () = ();
         any!(rustc, $lint_name, allowed, _, 1.45);
@@ -306,15 +421,20 @@ macro_rules! standard_lint_allowed {
 }
 
 macro_rules! standard_lint_allowed_from {
-    ($lint_name:tt, $since_major_minor:tt) => {
+    ($lint_name:tt, $since_major_minor:tt $(, [$($group:tt),+])?) => {
+        #[cfg(feature = "rustc")]
         #[allow($lint_name)]
         const _: () = ();
         any!(rustc, $lint_name, allowed, _, $since_major_minor);
     };
 }
 
+/// For lints that were removed (or renamed) in `$until_major_minor`. Newer rustc warns about them,
+/// hence the check is only for the older versions.
 macro_rules! standard_lint_allowed_from_to {
-    ($lint_name:tt, $since_major_minor:tt, $until_major_minor:tt) => {
+    ($lint_name:tt, $since_major_minor:tt, $until_major_minor:tt $(, [$($group:tt),+])?) => {
+        #[rustversion::not(since($until_major_minor))]
+        #[cfg(feature = "rustc")]
         #[allow($lint_name)]
         const _: () = ();
         any!(
@@ -330,24 +450,147 @@ macro_rules! standard_lint_allowed_from_to {
 }
 
 macro_rules! standard_lint_allowed_nightly {
-    ($lint_name:tt) => {
+    // Both the check and the macro are nightly-only: The lint is unknown to stable and beta rustc.
+    ($lint_name:tt $(, [$($group:tt),+])?) => {
+        #[rustversion::nightly]
+        #[cfg(feature = "rustc")]
         #[allow($lint_name)]
         const _: () = ();
+        #[rustversion::nightly]
         any!(rustc, $lint_name, allowed, _, 1.45, nightly);
     };
 }
 
+/// Generate a macro for the given warn-by-default standard lint.
+macro_rules! standard_lint_warn {
+    ($lint_name:tt $(, [$($group:tt),+])?) => {
+        #[cfg(feature = "rustc")]
+        #[allow($lint_name)]
+        const _: () = ();
+        any!(rustc, $lint_name, warn, _, 1.45);
+    };
+}
+
+/// Like [`standard_lint_warn`], but for a lint that exists since `$since_major_minor`.
+macro_rules! standard_lint_warn_from {
+    ($lint_name:tt, $since_major_minor:tt $(, [$($group:tt),+])?) => {
+        #[rustversion::since($since_major_minor)]
+        #[cfg(feature = "rustc")]
+        #[allow($lint_name)]
+        const _: () = ();
+        any!(rustc, $lint_name, warn, _, $since_major_minor);
+    };
+}
+
+/// Generate a macro for the given deny-by-default standard lint. Its documentation carries a notice,
+/// and the consumer may require it to be acknowledged (see `acknowledge_deny` parameter).
+macro_rules! standard_lint_denied {
+    ($lint_name:tt $(, [$($group:tt),+])?) => {
+        #[cfg(feature = "rustc")]
+        #[allow($lint_name)]
+        const _: () = ();
+        any!(rustc, $lint_name, deny, _, 1.45);
+    };
+}
+
+/// Like [`standard_lint_denied`], but for a lint that exists since `$since_major_minor`.
+macro_rules! standard_lint_denied_from {
+    ($lint_name:tt, $since_major_minor:tt $(, [$($group:tt),+])?) => {
+        #[rustversion::since($since_major_minor)]
+        #[cfg(feature = "rustc")]
+        #[allow($lint_name)]
+        const _: () = ();
+        any!(rustc, $lint_name, deny, _, $since_major_minor);
+    };
+}
+
+/// Generate a macro for the given crate-level-only standard lint. Rustc honors `allow` for such a
+/// lint only as an inner attribute `#![allow(...)]` at the crate root: it ignores it at a module
+/// level (even as an inner attribute of the module), and on any item. Hence the macro only reports
+/// an error that explains the line to add at the crate root (or, with `nightly` feature, it injects
+/// that line itself, as an inner attribute at the crate root).
+macro_rules! standard_lint_crate_level {
+    ($lint_name:tt $(, [$($group:tt),+])?) => {
+        // Rustc warns that such an `allow` is ignored here - but it still reports an unknown lint.
+        #[cfg(feature = "rustc")]
+        #[allow(unused_attributes)]
+        #[allow($lint_name)]
+        const _: () = ();
+        #[cfg(feature = "rustc")]
+        generate_crate_level_guidance_macro_standard!($lint_name);
+    };
+}
+
+/// Generate a macro for the given standard (rustc) lint group. Only with `groups` cargo feature. The
+/// lint group exists since `$since_major_minor` (hence its check).
+///
+/// Its members are not given, but derived by build.rs (see [`lint_group_members`]): This forwards
+/// to the arm with the members.
+macro_rules! standard_lint_group {
+    ($group_name:tt, $since_major_minor:tt) => {
+        lint_group_members!($group_name, standard_lint_group!($group_name, $since_major_minor));
+    };
+    ($group_name:tt, $since_major_minor:tt, [$($member:tt),+ $(,)?]) => {
+        #[rustversion::since($since_major_minor)]
+        #[cfg(all(feature = "groups", feature = "rustc"))]
+        #[allow($group_name)]
+        const _: () = ();
+        #[rustversion::since($since_major_minor)]
+        #[cfg(all(feature = "groups", feature = "rustc"))]
+        generate_allow_group_macro!("", "", $group_name, $group_name, [$($member),+]);
+    };
+}
+
+/// Like [`standard_lint_group`], but for a `clippy::` lint group. `$new_macro_name` is the group name
+/// with `clippy_` prefix. The members are given only for `all` group (its members are groups).
+///
+/// For `correctness` group it also defines `CLIPPY_CORRECTNESS` (regardless of `groups` cargo
+/// feature): Clippy's deny-by-default lints (see `acknowledge_deny` parameter). Like the members of
+/// any group, they come from the lint declarations annotated with `[correctness]` (see
+/// `lint_group_members!`), so there's no other list to maintain.
+macro_rules! clippy_lint_group {
+    ($group_name:tt, $new_macro_name:tt, $since_major_minor:tt) => {
+        lint_group_members!(
+            $new_macro_name,
+            clippy_lint_group!($group_name, $new_macro_name, $since_major_minor)
+        );
+    };
+    (GROUP, $group_name:tt, $new_macro_name:tt, $since_major_minor:tt, [$($member:tt),+]) => {
+        clippy_category!(
+            $group_name,
+            #[rustversion::since($since_major_minor)]
+            #[cfg(feature = "groups")]
+            generate_allow_group_macro!(
+                "clippy::",
+                "clippy_",
+                $group_name,
+                $new_macro_name,
+                [$($member),+]
+            );
+        );
+    };
+    (correctness, $new_macro_name:tt, $since_major_minor:tt, [$($member:tt),+ $(,)?]) => {
+        /// Members of `clippy::correctness` group - Clippy's deny-by-default lints.
+        const CLIPPY_CORRECTNESS: &[&str] = &[$(stringify!($member)),+];
+        clippy_lint_group!(GROUP, correctness, $new_macro_name, $since_major_minor, [$($member),+]);
+    };
+    ($group_name:tt, $new_macro_name:tt, $since_major_minor:tt, [$($member:tt),+ $(,)?]) => {
+        clippy_lint_group!(GROUP, $group_name, $new_macro_name, $since_major_minor, [$($member),+]);
+    };
+}
+
+#[allow(unused_macros)] // Not used by any lint declaration at the moment.
 macro_rules! standard_lint_versioned {
     // We can't match major.minor.patch in macro_rules. So far all lints started at patch version
     // being 0, so we omit it as a parameter.
-    ($major_minor:tt, $lint_name:tt) => {
+    ($major_minor:tt, $lint_name:tt $(, [$($group:tt),+])?) => {
         #[rustversion::since($major_minor)]
         standard_lint!($lint_name);
     }; // @TODO initial version - deprecated (or removed?) version
 }
 
 macro_rules! standard_lint_nightly {
-    ($lint_name:tt) => {
+    ($lint_name:tt $(, [$($group:tt),+])?) => {
         #[rustversion::nightly]
         standard_lint!($lint_name);
     };
@@ -355,15 +598,16 @@ macro_rules! standard_lint_nightly {
 
 macro_rules! prefixed_lint_versioned {
     // Again,  omitting patch version as a parameter.
-    ($major_minor:tt, $lint_prefix:tt, $lint_name:tt) => {
+    ($major_minor:tt, $lint_prefix:tt, $lint_name:tt $(, [$category:tt])?) => {
         #[rustversion::since($major_minor)]
-        prefixed_lint!($lint_prefix, $lint_name);
+        prefixed_lint!($lint_prefix, $lint_name $(, [$category])?);
     }; // @TODO initial version - deprecated (or removed?) version
 }
 
+#[allow(unused_macros)] // Not used by any lint declaration at the moment.
 macro_rules! prefixed_lint_nightly {
-    ($lint_prefix:tt, $lint_name:tt) => {
+    ($lint_prefix:tt, $lint_name:tt $(, [$category:tt])?) => {
         #[rustversion::nightly]
-        prefixed_lint!($lint_prefix, $lint_name);
+        prefixed_lint!($lint_prefix, $lint_name $(, [$category])?);
     }; // @TODO initial version - deprecated (or removed?) version
 }
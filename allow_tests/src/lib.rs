@@ -12,20 +12,20 @@ fn test_unused() {
     unused();
 }
 
-/// BEWARE: If you forget the exclamation mark, like:
-///
-/// `#[deny(unused)]`
-///
-/// instead of
-///
-/// `#![deny(unused)]`
-///
-/// then:
-/// 1. such a #[deny(...)] applies only to the first item (function), and
-/// 2. even worse: Even if you do add an appropriate `#[allow::...]` in front of that first item,
-///    that `#[allow::...]` will NOT apply - because it will be "overridden" by the previous
-///    (mistaken) `#[deny(...)]`.
-/// 3. The rest of the code will not get that lint checked (of course).
+// BEWARE: If you forget the exclamation mark, like:
+//
+// `#[deny(unused)]`
+//
+// instead of
+//
+// `#![deny(unused)]`
+//
+// then:
+// 1. such a #[deny(...)] applies only to the first item (function), and
+// 2. even worse: Even if you do add an appropriate `#[allow::...]` in front of that first item,
+//    that `#[allow::...]` will NOT apply - because it will be "overridden" by the previous
+//    (mistaken) `#[deny(...)]`.
+// 3. The rest of the code will not get that lint checked (of course).
 
 // NO need to mark functions as `#[test]`, since all we check is compilation. But we do invoke it
 // from a (separate) `#[test]` function, for peace of mind.
@@ -33,9 +33,7 @@ fn test_unused() {
 //#[allow(clippy::oh_dear)]
 pub fn unused() {
     //#[allow(clippy::oh_dear2)]
-    fn f() {
-        std::hint::black_box(());
-    }
+    fn f() {}
     f();
     _unused();
 }
@@ -54,3 +52,16 @@ pub fn unused() {
 fn _unused() {}
 
 allow_prefixed::generated_proc_mac_with_doc_attrib!();
+
+/// Generate a function with an `unsafe` block, with an alias to allow `unsafe_code` applied to
+/// that function. Used by `tests/consumer_span.rs` (which is a different crate), to check that the
+/// aliases work in code generated by `macro_rules!` from other crates.
+#[macro_export]
+macro_rules! fn_with_allowed_unsafe_code {
+    ($fn_name:ident) => {
+        #[::allow_prefixed::unsafe_code]
+        pub fn $fn_name() {
+            unsafe {}
+        }
+    };
+}
@@ -0,0 +1,34 @@
+//! Audited variants of `unsafe_code` and `unsafe_op_in_unsafe_fn` aliases. See also
+//! `internal_checks/audited_unsafe`.
+#![deny(unsafe_code, unsafe_op_in_unsafe_fn)]
+
+/// Reads the first byte.
+#[allow_prefixed::audited_unsafe_code(safety = "The slice is not empty, as checked just before.")]
+fn first(bytes: &[u8]) -> u8 {
+    assert!(!bytes.is_empty());
+    unsafe { *bytes.get_unchecked(0) }
+}
+
+/// # Safety
+///
+/// `bytes` must not be empty.
+#[allow_prefixed::audited_unsafe_code(
+    reason = "A test of an unsafe function",
+    safety = "Our caller guarantees that `bytes` is not empty."
+)]
+#[allow_prefixed::audited_unsafe_op_in_unsafe_fn(
+    safety = "The same as for `audited_unsafe_code` above."
+)]
+unsafe fn first_unchecked(bytes: &[u8]) -> u8 {
+    *bytes.get_unchecked(0)
+}
+
+#[test]
+fn audited() {
+    assert_eq!(first(&[1]), 1);
+    #[allow_prefixed::audited_unsafe_code(safety = "The slice is not empty.")]
+    fn call() -> u8 {
+        unsafe { first_unchecked(&[2]) }
+    }
+    assert_eq!(call(), 2);
+}
@@ -0,0 +1,65 @@
+//! Cargo rebuilds a crate when its configuration (`allow.toml`, or `[package.metadata.allow]` in its
+//! `Cargo.toml`) or its policy (`allow-policy.toml`) changes - even though only our proc macros read
+//! them. (The aliases include those files with `include_bytes!`, so cargo knows about them.)
+use std::fs;
+use std::path::Path;
+use std::process::Command;
+
+/// Run `cargo build` on the crate in `dir`. Return whether it succeeded, and its standard error.
+fn cargo_build(dir: &Path) -> (bool, String) {
+    let output = Command::new(env!("CARGO"))
+        .arg("build")
+        .arg("--quiet")
+        .arg("--manifest-path")
+        .arg(dir.join("Cargo.toml"))
+        .output()
+        .unwrap();
+    (
+        output.status.success(),
+        String::from_utf8_lossy(&output.stderr).into_owned(),
+    )
+}
+
+#[test]
+fn rebuilt_when_configuration_changes() {
+    let dir = Path::new(env!("CARGO_TARGET_TMPDIR")).join("config_tracking");
+    let allow_prefixed = Path::new(env!("CARGO_MANIFEST_DIR")).join("../allow_prefixed");
+    fs::create_dir_all(dir.join("src")).unwrap();
+    fs::write(
+        dir.join("Cargo.toml"),
+        format!(
+            "[package]\nname = \"config_tracking\"\nversion = \"0.1.0\"\nedition = \"2021\"\n\n\
+             [dependencies]\nallow_prefixed = {{ path = {:?} }}\n\n[workspace]\n",
+            allow_prefixed.to_string_lossy()
+        ),
+    )
+    .unwrap();
+    fs::write(
+        dir.join("src/lib.rs"),
+        "#[allow_prefixed::unsafe_code]\npub fn f() {}\n",
+    )
+    .unwrap();
+    fs::write(dir.join("allow.toml"), "").unwrap();
+    // Cargo can't notice new files, only changes of the files that the last build read.
+    fs::write(dir.join("allow-policy.toml"), "").unwrap();
+    let (success, stderr) = cargo_build(&dir);
+    assert!(success, "{}", stderr);
+
+    fs::write(dir.join("allow.toml"), "require_reason = true\n").unwrap();
+    let (success, stderr) = cargo_build(&dir);
+    assert!(!success);
+    assert!(stderr.contains("reason"), "{}", stderr);
+
+    fs::write(dir.join("allow.toml"), "").unwrap();
+    let (success, stderr) = cargo_build(&dir);
+    assert!(success, "{}", stderr);
+
+    fs::write(
+        dir.join("allow-policy.toml"),
+        "[rules.no_unsafe]\nlints = [\"unsafe_code\"]\n",
+    )
+    .unwrap();
+    let (success, stderr) = cargo_build(&dir);
+    assert!(!success);
+    assert!(stderr.contains("policy rule `no_unsafe`"), "{}", stderr);
+}
@@ -0,0 +1,16 @@
+//! The injected `#[allow(...)]` is located at, and resolved as, the consumer's code.
+#![deny(unknown_lints, unsafe_code)]
+
+#[allow_prefixed::unsafe_code]
+fn local() {
+    let _ = unsafe { *[1u8].get_unchecked(0) };
+}
+
+// The alias comes from `macro_rules!` in another crate (`allow_tests`).
+allow_tests::fn_with_allowed_unsafe_code!(from_other_crate);
+
+#[test]
+fn allowed_in_local_and_in_other_crate_macro() {
+    local();
+    from_other_crate();
+}
@@ -0,0 +1,25 @@
+//! Lints that are sometimes described as crate level-only, but which rustc does honor on items (and
+//! modules), have regular aliases. See also `internal_checks/crate_level_guidance` for the lints that
+//! are crate level-only indeed.
+#![deny(elided_lifetimes_in_paths)]
+
+pub struct Borrowing<'a>(&'a u8);
+
+#[allow_prefixed::elided_lifetimes_in_paths]
+fn elided(borrowing: Borrowing) -> u8 {
+    *borrowing.0
+}
+
+#[allow_prefixed::elided_lifetimes_in_paths]
+mod module {
+    pub fn elided(borrowing: super::Borrowing) -> u8 {
+        *borrowing.0
+    }
+}
+
+#[test]
+fn allowed_on_items() {
+    let value = 1;
+    assert_eq!(elided(Borrowing(&value)), 1);
+    assert_eq!(module::elided(Borrowing(&value)), 1);
+}
@@ -0,0 +1,25 @@
+//! Aliases of crate level-only lints as inner attributes at the crate root. Nightly-only: run with
+//! `cargo +nightly test --features nightly`. (On stable, and anywhere other than at the crate root,
+//! they report how to allow the lint instead: see `internal_checks/crate_level_guidance`.)
+#![cfg(feature = "nightly")]
+#![feature(custom_inner_attributes, prelude_import)]
+#![allow(internal_features)]
+#![deny(non_ascii_idents, unused_crate_dependencies)]
+#![allow_prefixed::non_ascii_idents]
+#![allow_prefixed::unused_crate_dependencies(reason = "ui_test is for internal_checks only")]
+
+fn größe() -> usize {
+    // The implicit prelude still works in modules.
+    inner::length("ab").unwrap_or_default()
+}
+
+mod inner {
+    pub fn length(text: &str) -> Option<usize> {
+        Some(text.len())
+    }
+}
+
+#[test]
+fn allowed_at_crate_root() {
+    assert_eq!(größe(), 2);
+}
@@ -0,0 +1,50 @@
+//! Each set of cargo features of `allow_prefixed` that ../../README.md advertises (see "Compile only
+//! the lint families you use") builds. This runs `cargo check` of `allow_prefixed` once per feature
+//! set (in a separate target directory), hence it takes a while.
+use std::path::Path;
+use std::process::Command;
+
+/// The feature sets, as passed to `--features` (along with `--no-default-features`).
+const FEATURE_SETS: &[&str] = &[
+    "rustc,clippy,rustdoc",
+    "rustc",
+    "clippy",
+    "rustdoc",
+    "clippy_cargo",
+    "clippy_complexity",
+    "clippy_correctness",
+    "clippy_nursery",
+    "clippy_pedantic",
+    "clippy_perf",
+    "clippy_restriction",
+    "clippy_style",
+    "clippy_suspicious",
+    "rustc,clippy_restriction",
+    "rustc,clippy,rustdoc,groups",
+    "",
+];
+
+#[test]
+fn feature_sets_build() {
+    let manifest = Path::new(env!("CARGO_MANIFEST_DIR")).join("../allow_prefixed/Cargo.toml");
+    let target_dir = Path::new(env!("CARGO_TARGET_TMPDIR")).join("feature_sets");
+    for features in FEATURE_SETS {
+        let output = Command::new(env!("CARGO"))
+            .arg("check")
+            .arg("--manifest-path")
+            .arg(&manifest)
+            .arg("--target-dir")
+            .arg(&target_dir)
+            .arg("--no-default-features")
+            .arg("--features")
+            .arg(features)
+            .output()
+            .unwrap();
+        assert!(
+            output.status.success(),
+            "allow_prefixed doesn't build with features [{}]:\n{}",
+            features,
+            String::from_utf8_lossy(&output.stderr)
+        );
+    }
+}
@@ -0,0 +1,50 @@
+//! Suppression on a single expression or statement. See also `internal_checks/inline` and
+//! `statement_attributes.rs` (nightly).
+#![deny(overflowing_literals, unused_must_use, unused_variables)]
+
+#[must_use]
+fn must_use() -> u8 {
+    1
+}
+
+#[test]
+fn allowed_on_expression() {
+    let wrapped: u8 = allow_prefixed::expr!(overflowing_literals, 256);
+    assert_eq!(wrapped, 0);
+    // A binding named `value` in the consumer's code doesn't clash with the expansion.
+    let value = 2;
+    assert_eq!(allow_prefixed::expr!(clippy::cast_possible_truncation, value as u8), 2);
+    let reasoned: u8 = allow_prefixed::expr!(overflowing_literals(reason = "Wraps to zero"), 256);
+    assert_eq!(reasoned, 0);
+}
+
+#[test]
+fn allowed_on_places_and_temporaries() {
+    // Borrowing inside the macro doesn't move out of the array.
+    let strings = [String::from("first"), String::from("second")];
+    let first: &String = allow_prefixed::expr!(clippy::indexing_slicing, &strings[0]);
+    assert_eq!(first, "first");
+    // A temporary lives until the end of the enclosing statement.
+    let length = allow_prefixed::expr!(clippy::redundant_clone, &strings[1].clone()).len();
+    assert_eq!(length, 6);
+    // Assign with `stmt!`.
+    let mut byte = 1u8;
+    allow_prefixed::stmt!(clippy::assign_op_pattern, byte = byte + 1;);
+    assert_eq!(byte, 2);
+    let predicated: u8 = allow_prefixed::expr!(overflowing_literals(cfg(test)), 256);
+    assert_eq!(predicated, 0);
+}
+
+#[test]
+fn allowed_on_statements() {
+    allow_prefixed::stmt!(unused_must_use, {
+        must_use();
+        must_use();
+    });
+    allow_prefixed::stmt!(unused_must_use, must_use(););
+    allow_prefixed::stmt!(unused_variables, let unused = 1;);
+    // The binding stays in scope.
+    allow_prefixed::stmt!(unused_variables, let (used, unused) = (must_use(), 2));
+    assert_eq!(used, 1);
+    allow_prefixed::stmt!(unused_variables(reason = "Example"), let unused_too = 1;);
+}
@@ -0,0 +1,82 @@
+//! Curated intent aliases under `allow::intent`.
+#![deny(
+    dead_code,
+    deprecated,
+    improper_ctypes_definitions,
+    non_camel_case_types,
+    non_snake_case,
+    non_upper_case_globals,
+    unused_qualifications,
+    clippy::unwrap_used,
+    clippy::expect_used,
+    clippy::indexing_slicing,
+    clippy::missing_safety_doc
+)]
+
+mod ffi {
+    pub struct Context {
+        pub value: u8,
+    }
+
+    #[allow::intent::ffi::ctypes_definitions(reason = "C keeps the context as an opaque pointer.")]
+    pub extern "C" fn callback(context: Context) -> u8 {
+        context.value
+    }
+
+    #[allow::intent::ffi::c_type_names]
+    pub type size_t = usize;
+
+    #[allow::intent::ffi::c_fn_names]
+    pub fn SDL_Init() -> size_t {
+        1
+    }
+
+    #[allow::intent::ffi::c_static_names]
+    pub static errno: u8 = 0;
+
+    #[allow::intent::ffi::safety_in_foreign_docs]
+    pub unsafe fn foreign() {}
+}
+
+mod generated {
+    #[allow::intent::generated::unused_items]
+    fn never_called() {}
+
+    #[allow::intent::generated::qualified_paths]
+    pub fn length(text: &str) -> usize {
+        ::core::primitive::str::len(text)
+    }
+}
+
+mod legacy {
+    #[deprecated = "Use `new_api`."]
+    pub fn old_api() -> u8 {
+        1
+    }
+
+    #[allow::intent::legacy::deprecated_api]
+    pub fn still_old() -> u8 {
+        old_api()
+    }
+
+    #[allow::intent::legacy::fn_names]
+    pub fn getValue() -> u8 {
+        2
+    }
+}
+
+#[test]
+#[allow::intent::tests::unwrap]
+#[allow::intent::tests::expect]
+#[allow::intent::tests::indexing]
+fn intents() {
+    assert_eq!(ffi::callback(ffi::Context { value: 3 }), 3);
+    assert_eq!(ffi::SDL_Init(), 1);
+    assert_eq!(ffi::errno, 0);
+    unsafe { ffi::foreign() };
+    assert_eq!(generated::length("ab"), 2);
+    assert_eq!(legacy::still_old(), 1);
+    assert_eq!(legacy::getValue(), 2);
+    let parsed = ["1".parse::<u8>().ok()];
+    assert_eq!(parsed[0].unwrap(), parsed[0].expect("parsed"));
+}
@@ -0,0 +1,46 @@
+//! Intents configured in `../Cargo.toml`, under `[package.metadata.allow.intents]`. See also
+//! `internal_checks/intents`.
+#![deny(
+    improper_ctypes_definitions,
+    non_snake_case,
+    deprecated,
+    non_camel_case_types,
+    unused_variables
+)]
+
+pub struct Context {
+    pub value: u8,
+}
+
+/// Its description (`Called from C.`) serves as the reason.
+#[allow::intent(ffi_boundary)]
+pub extern "C" fn SDL_Callback(context: Context) -> u8 {
+    context.value
+}
+
+#[deprecated = "Use `new_api`."]
+fn old_api() -> u8 {
+    1
+}
+
+#[allow::intent(reviewed_legacy, reason = "Published names; migrating in 2.0.")]
+type legacy_value = u8;
+
+#[allow_prefixed::intent(reviewed_legacy, reason = "Until the migration.", cfg(test))]
+fn still_old() -> legacy_value {
+    old_api()
+}
+
+/// Intent `prototype` suppresses lint groups `unused` and `clippy::pedantic`.
+#[allow::intent(prototype)]
+fn draft(value: u8) -> u8 {
+    let unfinished = value;
+    0
+}
+
+#[test]
+fn intents() {
+    assert_eq!(SDL_Callback(Context { value: 2 }), 2);
+    assert_eq!(still_old(), 1);
+    assert_eq!(draft(1), 0);
+}
@@ -0,0 +1,16 @@
+// Audited variants of `unsafe_code` and `unsafe_op_in_unsafe_fn` aliases require a (non-empty)
+// `safety = "..."`.
+#[allow_prefixed::audited_unsafe_code(safety = "Nothing unsafe happens here.")]
+pub fn justified() {}
+
+#[allow_prefixed::audited_unsafe_code]
+pub fn bare_alias() {}
+
+#[allow_prefixed::audited_unsafe_code(reason = "A reason is not a safety justification.")]
+pub fn no_safety() {}
+
+#[allow_prefixed::audited_unsafe_op_in_unsafe_fn(safety = "")]
+pub unsafe fn empty_safety() {}
+
+#[allow_prefixed::audited_unsafe_code(safety = "One.", safety = "Two.")]
+pub fn duplicate_safety() {}
@@ -0,0 +1,12 @@
+// A crate level-only lint can't be allowed by an outer attribute. Its alias reports an error that
+// shows the line to add at the crate root.
+#[allow_prefixed::non_ascii_idents]
+pub fn f() {}
+
+// The line includes the given `reason` and `cfg`. The error points to them.
+#[allow_prefixed::unused_crate_dependencies(cfg(test), reason = "used by benchmarks only")]
+pub fn g() {}
+
+// Malformed parameters are reported as with any other alias.
+#[allow_prefixed::confusable_idents(because = "unknown parameter")]
+pub fn h() {}
@@ -0,0 +1,24 @@
+// Once this crate reaches `until_version`, the alias emits a notice (a `deprecated` warning). Here
+// it's denied, so that we can check its message.
+#![deny(deprecated)]
+
+#[allow_prefixed::unsafe_code(until_version = "0.1.0", reason = "until we have a safe wrapper")]
+pub fn f() {}
+
+// The notice goes inside associated items, since an `impl` or a `trait` block can't have other
+// items.
+pub struct Wrapper;
+
+impl Wrapper {
+    #[allow_prefixed::unsafe_code(until_version = "0.1.0", reason = "until we have a safe wrapper")]
+    pub fn method() {}
+
+    #[allow_prefixed::non_upper_case_globals(until_version = "0.1.0")]
+    pub const lowercase: u8 = 1;
+}
+
+pub trait Trait {
+    // A type alias has no place for the notice, hence rejected (regardless of the version).
+    #[allow_prefixed::dead_code(until_version = "0.1.0")]
+    type Associated;
+}
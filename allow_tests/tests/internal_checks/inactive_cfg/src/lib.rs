@@ -0,0 +1,8 @@
+// With `cfg(not(test))` the alias suppresses nothing when compiled for tests (`cargo test`). So the
+// denied lint is reported there - but not in the regular build (for doctests).
+#![deny(unsafe_code)]
+
+#[allow_prefixed::unsafe_code(cfg(not(test)), reason = "outside of tests only")]
+pub fn outside_of_tests_only() -> u8 {
+    unsafe { *[1u8].get_unchecked(0) }
+}
@@ -1,6 +1,7 @@
 #![forbid(unknown_lints)]
 
 #[macro_use]
+#[allow(unused_macros)] // We use only `prefixed_lint!` here.
 mod wrapper_macros;
 
 #[allow(unused_macros)]
@@ -9,7 +10,8 @@ macro_rules! generate_allow_attribute_macro_definition_internal {
     };
 }
 
-// @TODO:
-//standard_lint!(non_existing_lint_without_prefix);
-standard_lint!(dead_code);
-prefixed_lint!(clippy::dead_code_fix_this);
\ No newline at end of file
+// A known lint passes the check.
+prefixed_lint!(rustdoc, broken_intra_doc_links);
+// An unknown one fails it (its `#[allow(...)]` under `#![forbid(unknown_lints)]`). (rustc, unlike
+// Clippy, checks `rustdoc::` lint names, hence we don't use `clippy::` here.)
+prefixed_lint!(rustdoc, non_existing_rustdoc_lint);
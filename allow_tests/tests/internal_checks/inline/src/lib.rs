@@ -0,0 +1,30 @@
+// `expr!` and `stmt!` report malformed input.
+pub fn missing_code() -> u8 {
+    allow_prefixed::expr!(overflowing_literals)
+}
+
+// Any tool is accepted, but rustc reports a tool that is not registered.
+pub fn unknown_tool() {
+    allow_prefixed::stmt!(tool::some_lint, {});
+}
+
+pub fn not_a_lint_path() {
+    allow_prefixed::stmt!("unused_must_use", {});
+}
+
+// `expr!` results in a value (a block), not a place.
+pub fn moved_out_of_array() -> usize {
+    let strings = [String::new(), String::new()];
+    let first = &allow_prefixed::expr!(clippy::indexing_slicing, strings[0]);
+    first.len()
+}
+
+pub fn not_assignable() {
+    let mut byte = 0u8;
+    allow_prefixed::expr!(unused_assignments, byte) = 1;
+}
+
+// Only `cfg` and `reason` apply.
+pub fn unknown_parameter() -> u8 {
+    allow_prefixed::expr!(overflowing_literals(until_version = "1.0.0"), 256)
+}
@@ -0,0 +1,17 @@
+// Intents from allow.toml. Their misuse is reported at the offending token.
+#![deny(improper_ctypes_definitions, non_snake_case)]
+
+#[allow_prefixed::intent(ffi_boundary, reason = "Called from C.")]
+pub fn SDL_Init() {}
+
+#[allow_prefixed::intent(ffi_boundry, reason = "A typo.")]
+pub fn unknown_intent() {}
+
+#[allow_prefixed::intent(ffi_boundary)]
+pub fn SDL_Quit() {}
+
+#[allow_prefixed::intent(ffi_boundary, reason = "  ")]
+pub fn SDL_Delay() {}
+
+#[allow_prefixed::intent("ffi_boundary")]
+pub fn name_not_an_identifier() {}
@@ -0,0 +1,4 @@
+// `items!` requires attributes to apply.
+allow_prefixed::items! {
+    pub fn no_attributes() {}
+}
@@ -0,0 +1,27 @@
+// Each use of a lint group alias emits a notice (a `deprecated` warning) that lists the member
+// lints. Here it's denied, so that we can check its message.
+#![deny(deprecated)]
+
+#[allow_prefixed::nonstandard_style]
+pub fn NotSnakeCase() {}
+
+// The notice is placed inside associated items, since an `impl` or a `trait` block can't have
+// other items.
+pub struct Counter;
+
+impl Counter {
+    #[allow_prefixed::nonstandard_style]
+    pub fn NotSnakeCaseMethod() {}
+
+    #[allow_prefixed::nonstandard_style]
+    pub const lowercase_constant: u8 = 1;
+}
+
+// It can be silenced on the item itself.
+#[allow(deprecated)]
+#[allow_prefixed::nonstandard_style]
+pub fn SilencedNotice() {}
+
+// A type alias (which may be associated) gets no notice.
+#[allow_prefixed::nonstandard_style]
+pub type lowercase_alias = u8;
@@ -6,6 +6,26 @@ use ui_test::{CommandBuilder, Config, Match, Mode, OutputConflictHandling};
 
 fn main() -> Result<()> {
     run("incorrect_lint")?;
+    run("malformed_internal_input")?;
+    run("unknown_lint_at_consumer")?;
+    run("crate_level_guidance")?;
+    run("lint_group_notice")?;
+    run("malformed_params")?;
+    run("inactive_cfg")?;
+    run("expired_suppression")?;
+    run("obsolete_workaround")?;
+    run("suppression_metadata")?;
+    run("required_reason")?;
+    run("unacknowledged_deny")?;
+    run("audited_unsafe")?;
+    run("members")?;
+    run("inline")?;
+    run("items")?;
+    run("methods")?;
+    run("intents")?;
+    run("malformed_config")?;
+    run("policy")?;
+    run("strict")?;
     Ok(())
 }
 
@@ -23,11 +43,17 @@ fn run(sub_dir_name: &'static str) -> Result<()> {
         //trailing_args: vec!["--".into(), "--test-threads".into(), "1".into()],
         program: CommandBuilder::cmd("cargo"),
 
-        output_conflict_handling: OutputConflictHandling::Error,
-        //mode: Mode::Panic,
-        mode: Mode::Fail {
-            require_patterns: false,
+        // Run with `BLESS=1` environment variable to (re)generate `Cargo.stderr` files from the
+        // actual output.
+        output_conflict_handling: if std::env::var_os("BLESS").is_some() {
+            OutputConflictHandling::Bless
+        } else {
+            OutputConflictHandling::Error
         },
+        // Cargo exits with 101 when compilation (or a test) fails. `Mode::Fail` expects 1 (as from
+        // rustc itself), and for 101 it reports a bug in the test ("test panicked") - without
+        // comparing the output. `Mode::Panic` expects 101 and compares the output.
+        mode: Mode::Panic,
 
         edition: None,
         ..Config::default()
@@ -88,6 +114,11 @@ fn run(sub_dir_name: &'static str) -> Result<()> {
     config.stderr_filter("   [0-9]: .*", "");
     config.stderr_filter("/target/[^/]+/debug", "/target/$$TRIPLE/debug");
     config.stderr_filter("(command: )\"[^<rp][^\"]+", "$1\"$$CMD");
+    // The whole command line of rustdoc (for doctests) has paths and hashes.
+    config.stderr_filter(
+        "(process didn't exit successfully: `)[^`]*rustdoc [^`]*`",
+        "$1$$RUSTDOC`",
+    );
 
     ui_test::run_tests_generic(config, |path| path.ends_with("Cargo.toml"), |_, _| None)
 }
@@ -0,0 +1,16 @@
+// A malformed configuration (allow.toml) is reported only once: Not at each use of an alias.
+#[allow_prefixed::intent(ffi_boundary, reason = "Called from C.")]
+pub fn SDL_Init() {}
+
+#[allow_prefixed::dead_code(reason = "Reserved.")]
+fn reserved() {}
+
+#[allow_prefixed::members(dead_code = [Unused], reason = "Reserved.")]
+pub enum Modes {
+    Used,
+    Unused,
+}
+
+pub fn truncate(x: u16) -> u8 {
+    allow_prefixed::expr!(clippy::cast_possible_truncation(reason = "Checked."), x as u8)
+}
@@ -0,0 +1,26 @@
+// Malformed input of the internal proc macros gets reported at the offending token (rather than
+// "proc macro panicked").
+
+// An unsupported lint prefix.
+allow_internal::check_that_prefixed_lint_exists!(rustfmt, skip);
+
+// A missing lint name.
+allow_internal::check_that_prefixed_lint_exists!(clippy);
+
+// Not a comma after the prefix.
+allow_internal::check_that_prefixed_lint_exists!(clippy; all);
+
+// A prefix that isn't an identifier.
+allow_internal::check_that_prefixed_lint_exists!("clippy", all);
+
+// Extra tokens.
+allow_internal::check_that_prefixed_lint_exists!(clippy, all, pedantic);
+
+// A lint default that doesn't exist.
+allow_internal::doc_and_attrib_macro_rustc!(unsafe_code, sometimes, "", "1.45", false, "", false, false);
+
+// A bool that isn't.
+allow_internal::doc_and_attrib_macro_rustc!(unsafe_code, allowed, "", "1.45", maybe, "", false, false);
+
+// A missing property.
+allow_internal::doc_and_attrib_macro_clippy!(unwrap_used, _, "", "1.45", false, "", false);
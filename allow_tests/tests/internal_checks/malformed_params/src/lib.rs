@@ -0,0 +1,46 @@
+// Malformed parameters of the generated macros are reported at the offending token.
+#[allow_prefixed::unsafe_code(cfg(test = 1))]
+pub fn value_not_a_string() {}
+
+#[allow_prefixed::unsafe_code(cfg(not(unix, windows)))]
+pub fn not_with_two_predicates() {}
+
+#[allow_prefixed::unsafe_code(cfg(test), cfg(unix))]
+pub fn duplicate_cfg() {}
+
+#[allow_prefixed::unsafe_code(reason = 1)]
+pub fn reason_not_a_string() {}
+
+#[allow_prefixed::unsafe_code(because = "unknown parameter")]
+pub fn unknown_parameter() {}
+
+#[allow_prefixed::unsafe_code(document = "yes")]
+pub fn document_not_a_bool() {}
+
+// Standard lints are fine (for those without an alias), but not unknown ones.
+#[allow_prefixed::tool_lint(unsafe_cod)]
+pub fn tool_lint_unknown() {}
+
+#[allow_prefixed::tool_lint(our_lints::some_lint reason = "missing comma")]
+pub fn tool_lint_without_comma() {}
+
+// A lint group has its own alias (with its notice).
+#[allow_prefixed::tool_lint(clippy::pedantic)]
+pub fn tool_lint_group() {}
+
+// `warnings` covers all lints.
+#[allow_prefixed::tool_lint(warnings)]
+pub fn tool_lint_warnings() {}
+
+#[allow_prefixed::clippy_has_significant_drop(always, reason = "Releases a lock.")]
+pub struct ToolAttributeWithParameters;
+
+#[allow_prefixed::clippy_msrv(1.45)]
+pub fn tool_attribute_value_not_a_string() {}
+
+#[allow_prefixed::clippy_msrv("1.45", reason = "Old Rust.", reason = "Really old Rust.")]
+pub fn tool_attribute_duplicate_reason() {}
+
+// Rustfmt doesn't expand attribute macros, so this would not skip formatting.
+#[allow_prefixed::rustfmt_skip]
+pub const IDENTITY: [[u8; 2]; 2] = [[1, 0], [0, 1]];
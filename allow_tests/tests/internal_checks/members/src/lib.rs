@@ -0,0 +1,22 @@
+// `#[allow_prefixed::members(...)]` reports members that don't exist, and malformed parameters.
+#[allow_prefixed::members(dead_code = [Present, Missing])]
+pub enum Variants {
+    Present,
+}
+
+#[allow_prefixed::members(dead_code = [2])]
+pub struct Tuple(u8, u8);
+
+#[allow_prefixed::members(dead_code)]
+pub struct NoMembersGiven {
+    pub field: u8,
+}
+
+// Any tool is accepted, but rustc reports a tool that is not registered.
+#[allow_prefixed::members(tool::some_lint = [field])]
+pub struct UnknownTool {
+    pub field: u8,
+}
+
+#[allow_prefixed::members(dead_code = [field])]
+pub fn not_a_struct() {}
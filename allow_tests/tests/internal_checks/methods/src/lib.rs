@@ -0,0 +1,24 @@
+// `#[allow_prefixed::methods(...)]` suppresses lints on the named associated items only, and it
+// reports names that don't exist.
+#![deny(unsafe_code)]
+
+pub struct Buffer([u8; 2]);
+
+#[allow_prefixed::methods(unsafe_code = [first])]
+impl Buffer {
+    pub fn first(&self) -> u8 {
+        unsafe { *self.0.get_unchecked(0) }
+    }
+
+    pub fn second(&self) -> u8 {
+        unsafe { *self.0.get_unchecked(1) }
+    }
+}
+
+#[allow_prefixed::methods(unsafe_code = [missing])]
+impl Buffer {
+    pub fn present(&self) {}
+}
+
+#[allow_prefixed::methods(unsafe_code = [first])]
+pub fn not_a_block() {}
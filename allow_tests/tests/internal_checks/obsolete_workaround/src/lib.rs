@@ -0,0 +1,21 @@
+// Once rustc reaches `until_rust`, the alias emits a notice (a `deprecated` warning), and it doesn't
+// suppress the lint anymore. Here both are denied, so that we can check the messages.
+#![deny(deprecated, unsafe_code)]
+
+#[allow_prefixed::unsafe_code(until_rust = "1.45", reason = "until rustc fixes a false positive")]
+pub unsafe fn f() {}
+
+// The notice goes inside associated items, since an `impl` or a `trait` block can't have other
+// items.
+pub struct Wrapper;
+
+impl Wrapper {
+    #[allow_prefixed::unsafe_code(until_rust = "1.45", reason = "until rustc fixes a false positive")]
+    pub unsafe fn method() {}
+}
+
+pub trait Trait {
+    // A type alias has no place for the notice, hence rejected (regardless of the Rust version).
+    #[allow_prefixed::dead_code(until_rust = "1.45")]
+    type Associated;
+}
@@ -0,0 +1,33 @@
+// Suppressions forbidden by allow-policy.toml. Each is reported at the offending attribute or lint.
+#![deny(dead_code)]
+
+// Rule `no_dead_code_in_other_crates` doesn't apply to this crate.
+#[allow_prefixed::dead_code]
+fn unused() {}
+
+#[allow_prefixed::clippy_unwrap_used]
+pub fn unwrap_alias() {}
+
+#[allow_prefixed::tool_lint(clippy::unwrap_used, reason = "A reason doesn't help.")]
+pub fn unwrap_tool_lint() {}
+
+#[allow_prefixed::unsafe_op_in_unsafe_fn]
+pub unsafe fn unsafe_alias() {}
+
+#[allow_prefixed::members(clippy::unwrap_used = [value])]
+pub struct Member {
+    pub value: u8,
+}
+
+pub fn inline() -> u8 {
+    allow_prefixed::expr!(clippy::unwrap_used, Some(1).unwrap())
+}
+
+// `warnings` covers all lints, so any rule forbids it.
+pub fn all_warnings() -> u8 {
+    allow_prefixed::expr!(warnings, Some(1).unwrap())
+}
+
+// Lint group `clippy::restriction` includes `clippy::unwrap_used`.
+#[allow_prefixed::intent(anything_goes)]
+pub fn group() {}
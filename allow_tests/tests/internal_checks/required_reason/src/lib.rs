@@ -0,0 +1,51 @@
+// With `require_reason = true` in `[package.metadata.allow]` of `Cargo.toml`, each alias use has to
+// have a non-empty reason.
+#[allow_prefixed::unsafe_code(reason = "FFI")]
+pub fn with_reason() {}
+
+#[allow_prefixed::unsafe_code]
+pub fn bare_alias() {}
+
+#[allow_prefixed::unsafe_code(cfg(test))]
+pub fn no_reason() {}
+
+#[allow_prefixed::unsafe_code(reason = " ")]
+pub fn empty_reason() {}
+
+// The same applies to `members`, `methods`, `expr!` and `stmt!`.
+#[allow_prefixed::members(dead_code = [Unused], reason = "Reserved")]
+pub enum WithReason {
+    Used,
+    Unused,
+}
+
+#[allow_prefixed::members(dead_code = [Unused])]
+pub enum NoReason {
+    Used,
+    Unused,
+}
+
+pub struct Calculator;
+
+#[allow_prefixed::methods(clippy::too_many_arguments = [sum])]
+impl Calculator {
+    pub fn sum(a: u8, b: u8, c: u8, d: u8, e: u8, f: u8, g: u8, h: u8) -> u8 {
+        a + b + c + d + e + f + g + h
+    }
+}
+
+pub fn inline(x: u16) -> u8 {
+    allow_prefixed::stmt!(unused_variables, let unused = 1;);
+    allow_prefixed::stmt!(unused_variables(reason = "Example"), let unused_too = 1;);
+    allow_prefixed::expr!(clippy::cast_possible_truncation(reason = " "), x as u8)
+}
+
+// An intent's description serves as the reason.
+#[allow_prefixed::intent(described)]
+fn described() {}
+
+#[allow_prefixed::intent(undescribed)]
+fn undescribed() {}
+
+#[allow_prefixed::intent(undescribed, reason = "Reserved.")]
+fn undescribed_with_reason() {}
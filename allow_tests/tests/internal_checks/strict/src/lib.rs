@@ -0,0 +1,80 @@
+// Raw suppressions (and aliases without a reason) rejected by `strict`. Each is reported at the
+// offending attribute, with the matching aliases suggested.
+#[allow_prefixed::strict]
+pub mod raw {
+    #![allow(unused_variables)]
+
+    #[allow(dead_code, clippy::unwrap_used)]
+    fn unused() {}
+
+    #[cfg_attr(test, allow(our_lints::no_blocking_in_async))]
+    pub fn tool() {}
+
+    pub fn body() {
+        #[expect(unused_mut, reason = "Nested in a function body.")]
+        let mut value = 1;
+        let _ = value;
+    }
+
+    // Attribute macros don't apply to fields and variants, so `strict` suggests `members`.
+    pub enum Mode {
+        #[allow(dead_code)]
+        Legacy,
+        Current,
+    }
+
+    pub struct Point {
+        #[allow(clippy::pub_underscore_fields)]
+        pub _x: u8,
+    }
+
+    pub struct Pair(#[allow(missing_docs)] pub u8, pub u8);
+
+    // A lint group without its alias (cargo feature `groups` is off).
+    #[allow(clippy::pedantic)]
+    pub fn grouped() {}
+
+    // An alias is fine (it needs no reason here).
+    #[allow_prefixed::dead_code]
+    fn unused_with_alias() {}
+}
+
+#[allow_prefixed::strict(require_reason)]
+pub mod reasons {
+    #[allow_prefixed::dead_code]
+    fn unused() {}
+
+    #[allow_prefixed::dead_code(reason = "Called from C.")]
+    fn unused_with_reason() {}
+
+    #[allow_prefixed::tool_lint(clippy::unwrap_used)]
+    pub fn tool_lint() {}
+
+    #[allow_prefixed::members(dead_code = [Legacy])]
+    pub enum Mode {
+        Legacy,
+    }
+
+    pub fn inline() -> u8 {
+        allow_prefixed::stmt!(unused_must_use(reason = "Ignored on purpose."), {
+            Ok::<(), ()>(());
+        });
+        allow_prefixed::expr!(overflowing_literals, 256)
+    }
+
+    // Aliases under the modules of `allow` are covered, too.
+    #[allow::rustdoc::broken_intra_doc_links]
+    pub fn rustdoc_alias() {}
+
+    #[allow::rustdoc::broken_intra_doc_links(reason = "Links to a private item.")]
+    pub fn rustdoc_alias_with_reason() {}
+
+    #[allow::tool_attributes::clippy_msrv("1.45")]
+    pub fn tool_attribute() {}
+
+    #[allow::tool_attributes::clippy_msrv("1.45", reason = "Supports old Rust.")]
+    pub fn tool_attribute_with_reason() {}
+}
+
+#[allow_prefixed::strict(require_reasons)]
+pub mod typo {}
@@ -0,0 +1,40 @@
+// Metadata of suppressions is validated against the schema in `[package.metadata.allow]` of
+// `Cargo.toml`.
+#[allow_prefixed::unsafe_code(owner = "Security team", ticket = "PROJ-123")]
+pub fn with_metadata() {}
+
+#[allow_prefixed::unsafe_code(owner = "Security team")]
+pub fn missing_required_metadata() {}
+
+#[allow_prefixed::unsafe_code(owner = "Security team", ticket = "PROJ-123", approver = "Bob")]
+pub fn unknown_metadata_key() {}
+
+#[allow_prefixed::unsafe_code(owner = " ", ticket = "PROJ-123")]
+pub fn empty_metadata() {}
+
+#[allow_prefixed::unsafe_code(owner = "Security team", ticket = "PROJ-1", ticket = "PROJ-2")]
+pub fn duplicate_metadata() {}
+
+// `members` and `methods` check the metadata for each of their lints.
+#[allow_prefixed::members(unsafe_code = [field], owner = "Security team", ticket = "PROJ-123")]
+pub struct MembersWithMetadata {
+    pub field: u8,
+}
+
+#[allow_prefixed::members(dead_code = [field], unsafe_code = [field], owner = "Security team")]
+pub struct MembersMissingRequiredMetadata {
+    pub field: u8,
+}
+
+pub struct Methods;
+
+#[allow_prefixed::methods(unsafe_code = [method], ticket = "PROJ-123")]
+impl Methods {
+    pub fn method() {}
+}
+
+// So do `expr!` and `stmt!`.
+pub fn inline() -> u8 {
+    allow_prefixed::stmt!(unsafe_code(owner = "Security team", ticket = "PROJ-123"), {});
+    allow_prefixed::expr!(unsafe_code(owner = "Security team"), 1)
+}
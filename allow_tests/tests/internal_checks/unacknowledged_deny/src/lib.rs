@@ -0,0 +1,94 @@
+// With `require_acknowledge_deny = true` in `[package.metadata.allow]` of `Cargo.toml`, a suppression
+// of a deny-by-default lint emits a notice (a `deprecated` warning), unless it's acknowledged with
+// `acknowledge_deny = true` and a reason. Here the notices are denied, so that we can check them.
+#![deny(deprecated)]
+
+#[allow_prefixed::overflowing_literals(acknowledge_deny = true, reason = "testing wrap-around")]
+pub fn acknowledged() -> u8 {
+    256
+}
+
+#[allow_prefixed::overflowing_literals]
+pub fn unacknowledged() -> u8 {
+    256
+}
+
+#[allow_prefixed::overflowing_literals(acknowledge_deny = true)]
+pub fn acknowledged_without_reason() -> u8 {
+    256
+}
+
+#[allow_prefixed::unsafe_code(acknowledge_deny = true, reason = "not deny-by-default")]
+pub fn not_deny_by_default() {}
+
+// The same applies to `tool_lint` with a `clippy::correctness` lint.
+#[allow_prefixed::tool_lint(clippy::eq_op)]
+pub fn unacknowledged_tool_lint() -> bool {
+    1 == 1
+}
+
+pub struct Wrapper;
+
+// The notice goes inside the method (an `impl` block can't have a sibling `const _`).
+impl Wrapper {
+    #[allow_prefixed::overflowing_literals]
+    pub fn unacknowledged_method() -> u8 {
+        256
+    }
+}
+
+pub trait Wrapping {
+    // No place for the notice.
+    #[allow_prefixed::overflowing_literals]
+    fn unacknowledged_required() -> u8;
+}
+
+// `members` and `methods` acknowledge all of their lints at once. It's enough that one of them is
+// deny-by-default.
+#[allow_prefixed::members(
+    overflowing_literals = [field],
+    dead_code = [field],
+    acknowledge_deny = true,
+    reason = "testing wrap-around"
+)]
+pub struct AcknowledgedMembers {
+    pub field: u8,
+}
+
+#[allow_prefixed::members(overflowing_literals = [field])]
+pub struct UnacknowledgedMembers {
+    pub field: u8,
+}
+
+#[allow_prefixed::members(dead_code = [field], acknowledge_deny = true, reason = "nothing")]
+pub struct MembersNotDenyByDefault {
+    pub field: u8,
+}
+
+#[allow_prefixed::methods(overflowing_literals = [unacknowledged])]
+impl Wrapper {
+    pub fn unacknowledged() -> u8 {
+        256
+    }
+}
+
+// So do `expr!` and `stmt!`. Their notice goes into the block (or after the statement).
+pub fn inline() -> u8 {
+    allow_prefixed::stmt!(
+        overflowing_literals(acknowledge_deny = true, reason = "testing wrap-around"),
+        let _wrapped: u8 = 256;
+    );
+    allow_prefixed::stmt!(overflowing_literals, let _unacknowledged: u8 = 256;);
+    allow_prefixed::expr!(overflowing_literals, 256)
+}
+
+// So do intents.
+#[allow_prefixed::intent(wrapping, acknowledge_deny = true, reason = "testing wrap-around")]
+pub fn acknowledged_intent() -> u8 {
+    256
+}
+
+#[allow_prefixed::intent(wrapping)]
+pub fn unacknowledged_intent() -> u8 {
+    256
+}
@@ -0,0 +1,7 @@
+// An alias of a nightly-only lint (unknown to stable and beta rustc) is generated only on nightly.
+// Elsewhere using it fails to resolve (rather than injecting `#[allow(...)]` of an unknown lint).
+// This test runs on stable.
+#![deny(unknown_lints)]
+
+#[allow_prefixed::fuzzy_provenance_casts]
+pub fn f() {}
@@ -0,0 +1,61 @@
+//! Aliases applied to a group of items. See also `internal_checks/items`.
+#![deny(unsafe_code)]
+
+struct Wrapper<const N: usize>([u8; N]);
+
+allow_prefixed::items! {
+    #[allow_prefixed::unsafe_code(reason = "testing items!")]
+    /// The first item's own doc comment.
+    fn first() -> u8 {
+        unsafe { *[1u8].get_unchecked(0) }
+    }
+
+    // Braces of the expression don't end a `const` item, and `<` doesn't start generics there.
+    const LESS: bool = 1 < 2 && Wrapper::<{ 1 + 1 }>([0, 0]).0.len() == 2;
+
+    impl<const N: usize> Wrapper<N>
+    where
+        [u8; N]: Sized,
+    {
+        fn first(&self) -> u8 {
+            unsafe { *self.0.get_unchecked(0) }
+        }
+    }
+
+    // Not annotated: rustc ignores lint attributes on macro invocations.
+    macro_rules! generate {
+        () => {
+            fn generated() -> u8 {
+                3
+            }
+        };
+    }
+    generate!();
+
+    unsafe fn unsafe_fn() -> u8 {
+        4
+    }
+}
+
+allow_prefixed::items! {
+    #[allow_prefixed::unsafe_code];
+    #[derive(Debug)]
+    struct WithOwnAttributes;
+
+    unsafe impl Send for Wrapper<1> {}
+}
+
+const _: () = assert!(LESS);
+
+#[test]
+fn allowed_on_items() {
+    assert_eq!(first(), 1);
+    assert_eq!(Wrapper([2]).first(), 2);
+    assert_eq!(generated(), 3);
+    #[allow_prefixed::unsafe_code]
+    fn call() -> u8 {
+        unsafe { unsafe_fn() }
+    }
+    assert_eq!(call(), 4);
+    let _ = format!("{:?}", WithOwnAttributes);
+}
@@ -0,0 +1,53 @@
+//! Aliases of lint groups (with `groups` cargo feature). Their notices are `deprecated` warnings,
+//! checked in `internal_checks/lint_group_notice`. Here we silence each notice right on the item
+//! (or on its `impl` block), which works since the notice is placed inside the item.
+#![deny(unused)]
+
+#[allow(deprecated)]
+#[allow_prefixed::unused]
+fn never_used() {}
+
+#[allow(deprecated)]
+#[allow_prefixed::nonstandard_style]
+fn NotSnakeCase() {}
+
+struct Counter;
+
+#[allow(deprecated)]
+impl Counter {
+    #[allow_prefixed::nonstandard_style]
+    fn NotSnakeCaseMethod() -> u8 {
+        1
+    }
+
+    #[allow_prefixed::nonstandard_style]
+    const lowercase_constant: u8 = 2;
+
+    #[allow_prefixed::unused]
+    fn never_used_method() {}
+}
+
+trait Shape {
+    #[allow_prefixed::nonstandard_style]
+    fn NotSnakeCaseRequired(&self);
+
+    #[allow(deprecated)]
+    #[allow_prefixed::nonstandard_style]
+    fn NotSnakeCaseProvided(&self) -> u8 {
+        3
+    }
+}
+
+impl Shape for Counter {
+    #[allow_prefixed::non_snake_case] // The trait's alias doesn't apply to implementations.
+    fn NotSnakeCaseRequired(&self) {}
+}
+
+#[test]
+fn allowed_by_group() {
+    NotSnakeCase();
+    assert_eq!(Counter::NotSnakeCaseMethod(), 1);
+    assert_eq!(Counter::lowercase_constant, 2);
+    Counter.NotSnakeCaseRequired();
+    assert_eq!(Counter.NotSnakeCaseProvided(), 3);
+}
@@ -0,0 +1,38 @@
+//! Suppressing lints on individual fields and variants. See also `internal_checks/members`.
+#![deny(dead_code, non_camel_case_types)]
+
+use std::collections::HashMap;
+
+#[allow_prefixed::members(dead_code = [Unused, unused_too], non_camel_case_types = [unused_too])]
+enum Variants {
+    Used,
+    Unused,
+    /// Documented, and with an attribute.
+    #[allow(unused_variables)]
+    unused_too { value: u8 },
+}
+
+#[allow_prefixed::members(dead_code = [unused, map])]
+struct Fields<F: Fn(u8) -> u8>
+where
+    F: Clone,
+{
+    pub used: F,
+    unused: u8,
+    pub(crate) map: HashMap<u8, Vec<u8>>,
+}
+
+#[allow_prefixed::members(dead_code = [1], reason = "Only the first field is read")]
+struct Tuple(u8, u8);
+
+#[test]
+fn allowed_on_members() {
+    let _ = Variants::Used;
+    let fields = Fields {
+        used: |value| value,
+        unused: 0,
+        map: HashMap::new(),
+    };
+    assert_eq!((fields.used)(1), 1);
+    assert_eq!(Tuple(1, 2).0, 1);
+}
@@ -0,0 +1,33 @@
+//! Metadata of suppressions, validated against the schema in `[package.metadata.allow]` of this
+//! crate's `Cargo.toml`. See also `internal_checks/suppression_metadata`.
+#![deny(unsafe_code, trivial_numeric_casts)]
+
+#[allow_prefixed::unsafe_code(owner = "Security team", ticket = "PROJ-123")]
+fn with_metadata() {
+    let _ = unsafe { *[1u8].get_unchecked(0) };
+}
+
+// Metadata, mixed with other parameters, in any order.
+#[allow_prefixed::unsafe_code(
+    review_by = "2030-01-01",
+    reason = "FFI-like test",
+    cfg(test),
+    owner = "Security team"
+)]
+fn with_metadata_and_params() {
+    let _ = unsafe { *[1u8].get_unchecked(0) };
+}
+
+// Required metadata for this lint (see `Cargo.toml`).
+#[allow_prefixed::trivial_numeric_casts(owner = "Core team", ticket = "PROJ-456")]
+#[allow(clippy::unnecessary_cast)] // The cast is what `trivial_numeric_casts` reports here.
+fn with_required_metadata(value: u8) -> u8 {
+    value as u8
+}
+
+#[test]
+fn allowed_with_metadata() {
+    with_metadata();
+    with_metadata_and_params();
+    assert_eq!(with_required_metadata(1), 1);
+}
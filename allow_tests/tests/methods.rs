@@ -0,0 +1,58 @@
+//! Suppressing lints on individual associated items of `impl` and `trait` blocks. See also
+//! `internal_checks/methods`.
+#![deny(unsafe_code, trivial_casts)]
+
+pub struct Buffer<const N: usize>([u8; N]);
+
+#[allow_prefixed::methods(unsafe_code = [first, FIRST], trivial_casts = [as_slice], reason = "Example")]
+impl<const N: usize> Buffer<N>
+where
+    [u8; N]: Sized,
+{
+    /// Documented, and with an attribute.
+    #[inline]
+    pub fn first(&self) -> u8 {
+        unsafe { *self.0.get_unchecked(0) }
+    }
+
+    const FIRST: u8 = unsafe { *[255u8].as_ptr() };
+
+    pub fn as_slice(&self) -> &[u8] {
+        &self.0 as &[u8]
+    }
+
+    pub fn len(&self) -> usize {
+        N
+    }
+
+    pub fn is_empty(&self) -> bool {
+        N == 0
+    }
+}
+
+pub trait First {
+    fn first(&self) -> u8;
+}
+
+#[allow_prefixed::methods(unsafe_code = [first_unchecked])]
+pub trait FirstUnchecked: First {
+    type Item;
+
+    /// # Safety
+    ///
+    /// The receiver must not be empty.
+    unsafe fn first_unchecked(&self) -> u8;
+
+    fn first_or_zero(&self) -> u8 {
+        self.first()
+    }
+}
+
+#[test]
+fn allowed_on_methods() {
+    let buffer = Buffer([1, 2]);
+    assert_eq!(buffer.first(), 1);
+    assert_eq!(Buffer::<2>::FIRST, 255);
+    assert_eq!(buffer.as_slice(), &[1, 2]);
+    assert_eq!(buffer.len(), 2);
+}
@@ -0,0 +1,79 @@
+//! Parameters of the generated macros: `cfg(predicate)`, `reason = "..."`, `until_version = "..."`,
+//! `until_rust = "..."` and `acknowledge_deny = true`. See also `internal_checks/malformed_params`,
+//! `internal_checks/inactive_cfg`, `internal_checks/expired_suppression` and
+//! `internal_checks/obsolete_workaround`.
+#![deny(unsafe_code, deprecated)]
+
+#[allow_prefixed::unsafe_code(reason = "FFI-like test of a reason")]
+fn with_reason() {
+    let _ = unsafe { *[1u8].get_unchecked(0) };
+}
+
+// The allow applies in tests only. (This is an integration test, so `cfg(test)` is on.)
+#[allow_prefixed::unsafe_code(cfg(test))]
+fn in_tests_only() {
+    let _ = unsafe { *[1u8].get_unchecked(0) };
+}
+
+#[allow_prefixed::unsafe_code(
+    cfg(all(test, any(unix, windows, not(target_os = "none")))),
+    reason = "nested predicate"
+)]
+fn nested_predicate() {
+    let _ = unsafe { *[1u8].get_unchecked(0) };
+}
+
+// Not expired yet (this crate is at an older version), hence no notice.
+#[allow_prefixed::unsafe_code(until_version = "999.0.0", reason = "until a safe API")]
+fn not_expired_yet() {
+    let _ = unsafe { *[1u8].get_unchecked(0) };
+}
+
+struct Wrapper;
+
+impl Wrapper {
+    // Also on an associated item. (Once expired, the notice goes inside the method. See
+    // `internal_checks/expired_suppression`.)
+    #[allow_prefixed::unsafe_code(until_version = "999.0.0", reason = "until a safe API")]
+    fn not_expired_yet_method() {
+        let _ = unsafe { *[1u8].get_unchecked(0) };
+    }
+
+    // Likewise for `until_rust`. See `internal_checks/obsolete_workaround`.
+    #[allow_prefixed::unsafe_code(until_rust = "999.0", reason = "until rustc gets fixed")]
+    fn workaround_still_needed_method() {
+        let _ = unsafe { *[1u8].get_unchecked(0) };
+    }
+}
+
+// A workaround for a (hypothetical) rustc issue that isn't fixed in this Rust yet, hence the allow is
+// still injected.
+#[allow_prefixed::unsafe_code(until_rust = "999.0", reason = "until rustc gets fixed")]
+fn workaround_still_needed() {
+    let _ = unsafe { *[1u8].get_unchecked(0) };
+}
+
+// A deny-by-default lint, acknowledged (though this crate doesn't require it).
+#[allow_prefixed::overflowing_literals(acknowledge_deny = true, reason = "testing wrap-around")]
+fn acknowledged_deny() -> u8 {
+    256
+}
+
+/// Documented suppression: this documentation gets a paragraph on the suppressed lint, and why.
+#[allow_prefixed::unsafe_code(document = true, reason = "testing doc injection")]
+fn documented() {
+    let _ = unsafe { *[1u8].get_unchecked(0) };
+}
+
+#[test]
+fn allowed_with_params() {
+    with_reason();
+    in_tests_only();
+    nested_predicate();
+    not_expired_yet();
+    Wrapper::not_expired_yet_method();
+    workaround_still_needed();
+    Wrapper::workaround_still_needed_method();
+    assert_eq!(acknowledged_deny(), 0);
+    documented();
+}
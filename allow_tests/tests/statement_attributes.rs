@@ -0,0 +1,27 @@
+//! Aliases applied directly to statements and expressions. Nightly-only: run with
+//! `cargo +nightly test --features nightly`. (On stable, see `inline.rs`.)
+#![cfg(feature = "nightly")]
+#![feature(stmt_expr_attributes, proc_macro_hygiene)]
+#![deny(unused_results, unsafe_code, overflowing_literals)]
+
+fn result() -> u8 {
+    1
+}
+
+#[test]
+fn allowed_on_statements_and_expressions() {
+    // An expression statement in a block: rustc passes an expression statement to an attribute
+    // macro without its semicolon.
+    #[allow_prefixed::unused_results]
+    {
+        result();
+    }
+
+    #[allow_prefixed::unsafe_code]
+    let value = unsafe { *[2u8].get_unchecked(0) };
+    assert_eq!(value, 2);
+
+    let wrapped: u8 = #[allow_prefixed::overflowing_literals]
+    256;
+    assert_eq!(wrapped, 0);
+}
@@ -0,0 +1,41 @@
+//! `#[allow::strict]` accepts aliases (and other attributes) inside. For what it rejects, see
+//! `internal_checks/strict`.
+#![deny(dead_code, clippy::unwrap_used)]
+
+#[allow::strict]
+mod generated {
+    #[allow_prefixed::dead_code]
+    fn unused() {}
+
+    #[derive(Debug, Clone, Copy)]
+    pub struct Value(pub u8);
+
+    /// Raw `#[allow(...)]` in documentation is not an attribute.
+    #[allow_prefixed::clippy_unwrap_used]
+    pub fn parse(value: &str) -> Value {
+        Value(value.parse().unwrap())
+    }
+}
+
+#[allow_prefixed::strict(require_reason)]
+mod reviewed {
+    #[allow_prefixed::dead_code(reason = "Called only from C.")]
+    fn unused() {}
+
+    #[allow::tool_lint(clippy::unwrap_used, reason = "Tests only.")]
+    pub fn parse(value: &str) -> u8 {
+        value.parse().unwrap()
+    }
+
+    #[cfg_attr(test, allow_prefixed::clippy_expect_used(reason = "Tests only."))]
+    pub fn first(values: &[u8]) -> u8 {
+        values.first().copied().unwrap_or_default()
+    }
+}
+
+#[test]
+fn strict() {
+    assert_eq!(generated::parse("1").0, 1);
+    assert_eq!(reviewed::parse("2"), 2);
+    assert_eq!(reviewed::first(&[3]), 3);
+}
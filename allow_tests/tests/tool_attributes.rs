@@ -0,0 +1,29 @@
+//! Aliases for tool attributes other than lints, imported under names of intentions. See also
+//! `internal_checks/malformed_params`.
+use allow::tool_attributes::clippy_has_significant_drop as releases_lock_on_drop;
+use allow::tool_attributes::clippy_msrv as supports_old_rust;
+use allow_prefixed::clippy_cognitive_complexity as long_state_machine;
+
+#[supports_old_rust("1.45")]
+fn old_rust() -> u8 {
+    1
+}
+
+#[long_state_machine("50")]
+fn state_machine(state: u8) -> u8 {
+    match state {
+        0 => 1,
+        _ => 0,
+    }
+}
+
+// Like in lint aliases, `reason` only documents why.
+#[releases_lock_on_drop(reason = "Releases a lock.")]
+struct Guard;
+
+#[test]
+fn tool_attributes() {
+    assert_eq!(old_rust(), 1);
+    assert_eq!(state_machine(0), 1);
+    let _guard = Guard;
+}
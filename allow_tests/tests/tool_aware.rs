@@ -0,0 +1,48 @@
+//! Cargo features `tool_aware_rustdoc` and `tool_aware_clippy` of `allow_prefixed`, on the crate in
+//! `tool_aware_crate/` (which enables them). The aliases inject `#[allow(rustdoc::...)]` under
+//! `cfg(doc)` only, and `#[allow(clippy::...)]` under `cfg(clippy)` only:
+//! - The plain `rustc` build doesn't see them. We check the expanded code (with `-Zunpretty`, which
+//!   needs `RUSTC_BOOTSTRAP=1` on stable).
+//! - `cargo doc` and `cargo clippy` do see them (the crate denies the suppressed lints).
+use std::path::Path;
+use std::process::Command;
+
+/// Run cargo `subcommand` on `tool_aware_crate`, followed by `args`. Return its standard output.
+fn cargo(subcommand: &str, args: &[&str]) -> String {
+    let manifest = Path::new(env!("CARGO_MANIFEST_DIR")).join("tests/tool_aware_crate/Cargo.toml");
+    let target_dir = Path::new(env!("CARGO_TARGET_TMPDIR")).join("tool_aware");
+    let output = Command::new(env!("CARGO"))
+        .arg(subcommand)
+        .arg("--manifest-path")
+        .arg(&manifest)
+        .arg("--target-dir")
+        .arg(&target_dir)
+        .args(args)
+        .env("RUSTC_BOOTSTRAP", "1")
+        .output()
+        .unwrap();
+    assert!(
+        output.status.success(),
+        "cargo {} {:?} failed:\n{}",
+        subcommand,
+        args,
+        String::from_utf8_lossy(&output.stderr)
+    );
+    String::from_utf8_lossy(&output.stdout).into_owned()
+}
+
+#[test]
+fn rustc_build_has_no_tool_allows() {
+    let expanded = cargo("rustc", &["--lib", "--quiet", "--", "-Zunpretty=expanded"]);
+    // The pretty printer may put spaces inside lint paths, like `rustdoc ::broken_intra_doc_links`.
+    let expanded: String = expanded.chars().filter(|c| !c.is_whitespace()).collect();
+    assert!(expanded.contains("pubfnbroken_link"), "{}", expanded);
+    assert!(!expanded.contains("allow(rustdoc::"), "{}", expanded);
+    assert!(!expanded.contains("allow(clippy::"), "{}", expanded);
+}
+
+#[test]
+fn rustdoc_and_clippy_get_their_allows() {
+    cargo("doc", &["--quiet"]);
+    cargo("clippy", &["--quiet"]);
+}
@@ -0,0 +1,12 @@
+//! With cargo features `tool_aware_rustdoc` and `tool_aware_clippy`, the aliases inject their
+//! `#[allow(...)]` only under `cfg(doc)` or `cfg(clippy)`. See ../../tool_aware.rs.
+#![deny(rustdoc::broken_intra_doc_links, clippy::unwrap_used)]
+
+/// A link to [NonExistingItem].
+#[allow_prefixed::rustdoc_broken_intra_doc_links]
+pub fn broken_link() {}
+
+#[allow_prefixed::clippy_unwrap_used]
+pub fn unwrap(value: Option<u8>) -> u8 {
+    value.unwrap()
+}
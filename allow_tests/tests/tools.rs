@@ -0,0 +1,112 @@
+//! Lints of other tools: the generic `tool_lint` attribute, and aliases generated from the lint list
+//! file (`../tool_lints.txt`, see `../.cargo/config.toml`). Lints of a tool registered with
+//! `#![register_tool(...)]` are nightly-only: run with `cargo +nightly test --features nightly`. On
+//! stable they're behind a `cfg(...)` parameter, like Dylint lints (`cfg(dylint_lib = "...")`).
+#![cfg_attr(feature = "nightly", feature(register_tool), register_tool(our_lints))]
+#![deny(unknown_lints)]
+
+use std::fs;
+use std::path::Path;
+use std::process::Command;
+
+#[allow_prefixed::tool_lint(clippy::needless_return)]
+fn clippy_lint() -> u8 {
+    return 1;
+}
+
+#[allow_prefixed::tool_lint(rustdoc::broken_intra_doc_links, reason = "testing a reason")]
+/// Link to [`NonExisting`].
+fn rustdoc_lint() {}
+
+// A standard lint, for lints without an alias.
+#[allow_prefixed::tool_lint(unused_variables, reason = "testing a standard lint")]
+fn standard_lint() {
+    let unused = 1;
+}
+
+#[allow_prefixed::tool_lint(our_lints::no_blocking_in_async, cfg(feature = "nightly"))]
+fn generic_with_predicate() {}
+
+#[allow_prefixed::our_lints_no_panic_in_drop(cfg(feature = "nightly"), reason = "example")]
+fn generated_with_predicate() {}
+
+#[test]
+fn tool_lint() {
+    assert_eq!(clippy_lint(), 1);
+    rustdoc_lint();
+    standard_lint();
+    generic_with_predicate();
+    generated_with_predicate();
+}
+
+/// Run `cargo build` on the crate in `dir`, with lint list file `tool_lints`. Return whether it
+/// succeeded, and its standard error.
+fn cargo_build(dir: &Path, tool_lints: &Path) -> (bool, String) {
+    let output = Command::new(env!("CARGO"))
+        .arg("build")
+        .arg("--quiet")
+        .arg("--manifest-path")
+        .arg(dir.join("Cargo.toml"))
+        .env("ALLOW_PREFIXED_TOOL_LINTS", tool_lints)
+        .output()
+        .unwrap();
+    (
+        output.status.success(),
+        String::from_utf8_lossy(&output.stderr).into_owned(),
+    )
+}
+
+#[test]
+fn colliding_aliases_fail_the_build() {
+    let dir = Path::new(env!("CARGO_TARGET_TMPDIR")).join("tool_lints_collision");
+    let allow_prefixed = Path::new(env!("CARGO_MANIFEST_DIR")).join("../allow_prefixed");
+    fs::create_dir_all(dir.join("src")).unwrap();
+    fs::write(
+        dir.join("Cargo.toml"),
+        format!(
+            "[package]\nname = \"tool_lints_collision\"\nversion = \"0.1.0\"\nedition = \"2021\"\n\n\
+             [dependencies]\nallow_prefixed = {{ path = {:?} }}\n\n[workspace]\n",
+            allow_prefixed.to_string_lossy()
+        ),
+    )
+    .unwrap();
+    fs::write(dir.join("src/lib.rs"), "").unwrap();
+    // `unused_results` is the alias of a standard lint already.
+    let tool_lints = dir.join("tool_lints.txt");
+    fs::write(&tool_lints, "unused::results\n").unwrap();
+    let (success, stderr) = cargo_build(&dir, &tool_lints);
+    assert!(!success);
+    assert!(
+        stderr.contains("would have alias `unused_results`, but allow_prefixed has a macro with that name already"),
+        "{}",
+        stderr
+    );
+
+    fs::write(&tool_lints, "our::lints_x\nour_lints::x\n").unwrap();
+    let (success, stderr) = cargo_build(&dir, &tool_lints);
+    assert!(!success);
+    assert!(
+        stderr.contains("would have the same alias `our_lints_x`"),
+        "{}",
+        stderr
+    );
+}
+
+#[cfg(feature = "nightly")]
+mod registered_tool {
+    #[allow_prefixed::tool_lint(our_lints::no_blocking_in_async, document = true)]
+    fn generic() {}
+
+    #[allow_prefixed::our_lints_no_blocking_in_async(reason = "generated alias")]
+    fn generated() {}
+
+    #[allow_prefixed::our_lints_no_panic_in_drop(cfg(test))]
+    fn generated_without_url() {}
+
+    #[test]
+    fn registered_tool_lints() {
+        generic();
+        generated();
+        generated_without_url();
+    }
+}